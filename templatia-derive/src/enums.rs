@@ -0,0 +1,105 @@
+use crate::case::RenameRule;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The container options that apply to fieldless enums.
+pub(crate) struct UnitEnumOptions<'a> {
+    /// The casing rule of the variant keywords, provided via `#[templatia(rename_all = "...")]`.
+    pub(crate) rename_all: Option<RenameRule>,
+    /// The error type of parsing, provided via `#[templatia(error = "...")]`.
+    pub(crate) error: Option<&'a syn::Path>,
+    /// Whether `Display` is implemented, provided via `#[templatia(impl_display)]`.
+    pub(crate) impl_display: bool,
+    /// Whether `FromStr` is implemented, provided via `#[templatia(impl_from_str)]`.
+    pub(crate) impl_from_str: bool,
+}
+
+/// Generates the `RenderTemplate`, `ParseTemplate`, `Display` and `FromStr` implementations for a fieldless enum.
+///
+/// Each variant is rendered as its (optionally renamed) identifier and parsed back by an exact match.
+/// `Display` and `FromStr` are generated when `impl_display` and `impl_from_str` are set, like for
+/// structs, so that the enum satisfies the bounds required for placeholder fields of other
+/// templates. Parsing uses `error` as the error type when given, converted from `TemplateError`
+/// via `From`. The generated paths start with `krate`.
+pub(crate) fn generate_unit_enum_impl(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    variants: &[&syn::Ident],
+    options: UnitEnumOptions,
+    krate: &syn::Path,
+) -> TokenStream {
+    let UnitEnumOptions {
+        rename_all,
+        error,
+        impl_display,
+        impl_from_str,
+    } = options;
+    let keywords = variants
+        .iter()
+        .map(|variant| {
+            let variant = variant.to_string();
+            match rename_all {
+                Some(rule) => rule.apply_to_variant(&variant),
+                None => variant,
+            }
+        })
+        .collect::<Vec<_>>();
+    let expected = keywords.join(", ");
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let error_type = match error {
+        Some(error) => quote! { #error },
+        None => quote! { #krate::TemplateError },
+    };
+
+    let display_impl = impl_display.then(|| {
+        quote! {
+            impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(&<Self as #krate::RenderTemplate>::render_string(self))
+                }
+            }
+        }
+    });
+    let from_str_impl = impl_from_str.then(|| {
+        quote! {
+            impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+                type Err = #error_type;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    <Self as #krate::ParseTemplate>::from_str(s)
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics #krate::RenderTemplate for #name #ty_generics #where_clause {
+            fn render_string(&self) -> String {
+                match self {
+                    #(#name::#variants => #keywords.to_string(),)*
+                }
+            }
+        }
+
+        impl #impl_generics #krate::ParseTemplate for #name #ty_generics #where_clause {
+            type Error = #error_type;
+
+            fn from_str(s: &str) -> Result<Self, Self::Error> {
+                match s {
+                    #(#keywords => Ok(#name::#variants),)*
+                    _ => Err(::std::convert::From::from(#krate::TemplateError::Parse(format!(
+                        "unknown variant '{}' for {}, expected one of: {}",
+                        s,
+                        stringify!(#name),
+                        #expected,
+                    )))),
+                }
+            }
+        }
+
+        #display_impl
+
+        #from_str_impl
+    }
+}
@@ -45,3 +45,19 @@ pub(crate) fn generate_not_found_placeholder_compile_error(
 
     generate_compile_error(&msg)
 }
+
+/// Reports a `NonZero*` field that would have to be filled with `Default::default()`, which these
+/// integers don't implement. `reason` tells why the field isn't parsed from the input.
+pub(crate) fn generate_nonzero_default_compile_error(
+    field: &syn::Ident,
+    ty: &syn::Type,
+    reason: &str,
+) -> proc_macro2::TokenStream {
+    let msg = format!(
+        "{0} needs a default value {1}, but NonZero integers don't implement Default. \
+        give it one with `#[templatia(default = \"...\")]`",
+        field, reason,
+    );
+
+    syn::Error::new_spanned(ty, msg).to_compile_error()
+}
@@ -1,8 +1,294 @@
-use crate::utils::get_type_name;
+use crate::case::ValueCase;
+use crate::parser::Modifier;
+use crate::utils::{get_type_name, is_borrowed_str, is_full_path, is_integer_type, is_number_type};
+use darling::FromField;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 use syn::GenericArgument;
 
+/// Matching strategy of a placeholder capture provided via `#[templatia(match = "...")]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, darling::FromMeta)]
+pub(crate) enum MatchStrategy {
+    /// Stops at the first occurrence of the next literal.
+    #[default]
+    #[darling(rename = "lazy")]
+    Lazy,
+    /// Consumes up to the last occurrence of the next literal that the rest of the template allows.
+    #[darling(rename = "greedy")]
+    Greedy,
+}
+
+/// Resolution of a placeholder repeated in the template provided via `#[templatia(on_duplicate = "...")]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, darling::FromMeta)]
+pub(crate) enum OnDuplicate {
+    /// Fails with `TemplateError::InconsistentValues` when the occurrences differ.
+    #[default]
+    #[darling(rename = "error")]
+    Error,
+    /// Takes the value of the first occurrence.
+    #[darling(rename = "first")]
+    First,
+    /// Takes the value of the last occurrence.
+    #[darling(rename = "last")]
+    Last,
+}
+
+/// Line ending of rendered templates provided via `#[templatia(newline = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, darling::FromMeta)]
+pub(crate) enum Newline {
+    /// Renders `\n` as written in the template.
+    #[darling(rename = "lf")]
+    Lf,
+    /// Renders each `\n` of the template as `\r\n`.
+    #[darling(rename = "crlf")]
+    Crlf,
+}
+
+/// Spelling of rendered `bool` values provided via `#[templatia(bool_style = "...")]`.
+/// Parsing accepts the spellings of every style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, darling::FromMeta)]
+pub(crate) enum BoolStyle {
+    /// Renders `true` and `false`.
+    #[darling(rename = "true_false")]
+    TrueFalse,
+    /// Renders `yes` and `no`.
+    #[darling(rename = "yes_no")]
+    YesNo,
+    /// Renders `on` and `off`.
+    #[darling(rename = "on_off")]
+    OnOff,
+    /// Renders `1` and `0`.
+    #[darling(rename = "one_zero")]
+    OneZero,
+}
+
+impl BoolStyle {
+    const ALL: [BoolStyle; 4] = [
+        BoolStyle::TrueFalse,
+        BoolStyle::YesNo,
+        BoolStyle::OnOff,
+        BoolStyle::OneZero,
+    ];
+
+    /// Returns the spellings of `true` and `false` in this style.
+    fn spellings(self) -> (&'static str, &'static str) {
+        match self {
+            BoolStyle::TrueFalse => ("true", "false"),
+            BoolStyle::YesNo => ("yes", "no"),
+            BoolStyle::OnOff => ("on", "off"),
+            BoolStyle::OneZero => ("1", "0"),
+        }
+    }
+}
+
+/// Representation of rendered floats provided via `#[templatia(float = "...")]`.
+/// Both read back to the same bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, darling::FromMeta)]
+pub(crate) enum FloatStyle {
+    /// Renders the shorter of the decimal and the exponent notation.
+    #[darling(rename = "shortest")]
+    Shortest,
+    /// Renders the exponent notation like `1.5e-7`.
+    #[darling(rename = "exponent")]
+    Exponent,
+}
+
+/// Representation of rendered `Uuid` values provided via `#[templatia(uuid_format = "...")]`.
+/// Parsing accepts all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, darling::FromMeta)]
+pub(crate) enum UuidFormat {
+    /// Renders `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    #[darling(rename = "hyphenated")]
+    Hyphenated,
+    /// Renders `67e5504410b1426f9247bb680e5fe0c8`.
+    #[darling(rename = "simple")]
+    Simple,
+    /// Renders `{67e55044-10b1-426f-9247-bb680e5fe0c8}`.
+    #[darling(rename = "braced")]
+    Braced,
+    /// Renders `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    #[darling(rename = "urn")]
+    Urn,
+}
+
+impl UuidFormat {
+    /// Returns the `Uuid` method returning the adapter that renders this representation.
+    pub(crate) fn method(self) -> &'static str {
+        match self {
+            UuidFormat::Hyphenated => "hyphenated",
+            UuidFormat::Simple => "simple",
+            UuidFormat::Braced => "braced",
+            UuidFormat::Urn => "urn",
+        }
+    }
+}
+
+/// Prefixes telling the variants of a `Result<T, E>` field apart without `ok_prefix` and `err_prefix`.
+const DEFAULT_OK_PREFIX: &str = "ok:";
+const DEFAULT_ERR_PREFIX: &str = "err:";
+
+/// Mask rendered for `#[templatia(secret)]` fields without an explicit mask.
+const DEFAULT_SECRET_MASK: &str = "***";
+
+/// Per-field options provided via `#[templatia(...)]` on a struct field.
+#[derive(Debug, Default, FromField)]
+#[darling(attributes(templatia))]
+pub(crate) struct FieldOpts {
+    /// Expression used when the field is missing from the template or its capture is empty,
+    /// provided via `#[templatia(default = "...")]`.
+    #[darling(default)]
+    pub(crate) default: Option<syn::Expr>,
+    /// Placeholder name used in the template instead of the field name,
+    /// provided via `#[templatia(rename = "...")]`.
+    #[darling(default)]
+    pub(crate) rename: Option<String>,
+    /// Module providing `render(&T) -> String` and `parse(&str) -> Result<T, E>` for the field,
+    /// provided via `#[templatia(with = "...")]`.
+    #[darling(default)]
+    pub(crate) with: Option<syn::Path>,
+    /// `format!` string with a single argument used to render each value of the field,
+    /// provided via `#[templatia(format = "{:08}")]`.
+    #[darling(default)]
+    pub(crate) format: Option<String>,
+    /// Fill character and alignment of a `{field:*^10}` format spec, whose fill is stripped from
+    /// the captured text on the padded sides. Only set by the spec, which has no attribute.
+    #[darling(skip)]
+    pub(crate) fill: Option<(char, char)>,
+    /// Trims leading and trailing whitespace of the captured text before parsing,
+    /// provided via `#[templatia(trim)]`.
+    #[darling(default)]
+    pub(crate) trim: darling::util::Flag,
+    /// Renders the value in upper case and parses either case, provided via `#[templatia(uppercase)]`.
+    #[darling(default)]
+    pub(crate) uppercase: darling::util::Flag,
+    /// Renders the value in lower case and parses either case, provided via `#[templatia(lowercase)]`.
+    #[darling(default)]
+    pub(crate) lowercase: darling::util::Flag,
+    /// Delimiter between the elements of a collection field, provided via `#[templatia(separator = ";")]`.
+    #[darling(default)]
+    pub(crate) separator: Option<String>,
+    /// Delimiter between the entries of a map field, provided via `#[templatia(entry_sep = ";")]`.
+    #[darling(default)]
+    pub(crate) entry_sep: Option<String>,
+    /// Delimiter between the key and the value of a map entry, provided via `#[templatia(kv_sep = ":")]`.
+    #[darling(default)]
+    pub(crate) kv_sep: Option<String>,
+    /// Function `fn(&T) -> Result<(), String>` checking the parsed value,
+    /// provided via `#[templatia(validate = "...")]`.
+    #[darling(default)]
+    pub(crate) validate: Option<syn::Path>,
+    /// Mask rendered instead of the value, provided via `#[templatia(secret)]` for the default `***`
+    /// or `#[templatia(secret = "...")]` for a custom mask.
+    #[darling(default)]
+    pub(crate) secret: Option<darling::util::Override<String>>,
+    /// Alternative keys accepted in the input in place of the placeholder name,
+    /// provided via `#[templatia(alias = "...")]` (repeatable).
+    #[darling(multiple)]
+    pub(crate) alias: Vec<String>,
+    /// Parses an empty capture of an `Option` string field as `None`, provided via `#[templatia(empty_str_is_none)]`.
+    #[darling(default)]
+    pub(crate) empty_str_is_none: darling::util::Flag,
+    /// Parses an empty capture of an `Option` string field as `Some("")`, provided via `#[templatia(empty_str_is_some)]`.
+    #[darling(default)]
+    pub(crate) empty_str_is_some: darling::util::Flag,
+    /// Number of characters the placeholder consumes, provided via `#[templatia(width = N)]`.
+    #[darling(default)]
+    pub(crate) width: Option<usize>,
+    /// Matching strategy of the capture, provided via `#[templatia(match = "lazy" | "greedy")]`.
+    #[darling(default, rename = "match")]
+    pub(crate) match_strategy: MatchStrategy,
+    /// Character escaping the following literal inside the value, provided via `#[templatia(escape = '\\')]`.
+    #[darling(default)]
+    pub(crate) escape: Option<char>,
+    /// Renders the value in double quotes and parses a quoted token, provided via `#[templatia(quoted)]`.
+    #[darling(default)]
+    pub(crate) quoted: darling::util::Flag,
+    /// Token rendered for `None` and parsed back to `None`, provided via `#[templatia(render_none = "null")]`.
+    #[darling(default)]
+    pub(crate) render_none: Option<String>,
+    /// Tokens parsed as `None`, provided via `#[templatia(none_literals = ["null", "~"])]`.
+    #[darling(default)]
+    pub(crate) none_literals: Option<Vec<syn::LitStr>>,
+    /// `chrono` format string or `time` format description used to render and parse a date or time field,
+    /// provided via `#[templatia(datetime_format = "%Y-%m-%d")]`.
+    #[darling(default)]
+    pub(crate) datetime_format: Option<String>,
+    /// Representation of a `Uuid` field, provided via `#[templatia(uuid_format = "simple")]`.
+    #[darling(default)]
+    pub(crate) uuid_format: Option<UuidFormat>,
+    /// Renders and parses a `u64` field as a byte size like `512KiB`, provided via `#[templatia(bytesize)]`.
+    #[darling(default)]
+    pub(crate) bytesize: darling::util::Flag,
+    /// Base of an integer field, provided via `#[templatia(radix = 16)]`.
+    #[darling(default)]
+    pub(crate) radix: Option<u32>,
+    /// Spellings parsed as `true`, the first of which is rendered, provided via `#[templatia(true_values = [...])]`.
+    #[darling(default)]
+    pub(crate) true_values: Option<Vec<syn::LitStr>>,
+    /// Spellings parsed as `false`, the first of which is rendered, provided via `#[templatia(false_values = [...])]`.
+    #[darling(default)]
+    pub(crate) false_values: Option<Vec<syn::LitStr>>,
+    /// Spelling of the rendered value, provided via `#[templatia(bool_style = "yes_no")]`.
+    #[darling(default)]
+    pub(crate) bool_style: Option<BoolStyle>,
+    /// Representation of a float value, provided via `#[templatia(float = "shortest")]`.
+    #[darling(default)]
+    pub(crate) float: Option<FloatStyle>,
+    /// Renders nothing for the placeholder while still parsing it, provided via `#[templatia(skip_render)]`.
+    #[darling(default)]
+    pub(crate) skip_render: darling::util::Flag,
+    /// Renders the placeholder but discards its capture and uses the default when parsing,
+    /// provided via `#[templatia(skip_parse)]`.
+    #[darling(default)]
+    pub(crate) skip_parse: darling::util::Flag,
+    /// Captures all remaining input for the last placeholder of the template, provided via `#[templatia(rest)]`.
+    #[darling(default)]
+    pub(crate) rest: darling::util::Flag,
+    /// Stops the capture at the end of the current line, provided via `#[templatia(until_eol)]`.
+    #[darling(default)]
+    pub(crate) until_eol: darling::util::Flag,
+    /// Smallest accepted value of a numeric field, provided via `#[templatia(min = 1)]`.
+    #[darling(default)]
+    pub(crate) min: Option<syn::Expr>,
+    /// Largest accepted value of a numeric field, provided via `#[templatia(max = 65535)]`.
+    #[darling(default)]
+    pub(crate) max: Option<syn::Expr>,
+    /// Smallest accepted number of characters of a string field, provided via `#[templatia(min_len = 1)]`.
+    #[darling(default)]
+    pub(crate) min_len: Option<usize>,
+    /// Largest accepted number of characters of a string field, provided via `#[templatia(max_len = 64)]`.
+    #[darling(default)]
+    pub(crate) max_len: Option<usize>,
+    /// Text rendered before the value of an `Ok` result, provided via `#[templatia(ok_prefix = "ok:")]`.
+    #[darling(default)]
+    pub(crate) ok_prefix: Option<String>,
+    /// Text rendered before the value of an `Err` result, provided via `#[templatia(err_prefix = "err:")]`.
+    #[darling(default)]
+    pub(crate) err_prefix: Option<String>,
+}
+
+impl FieldOpts {
+    /// Sets the option a `{field:modifier}` in the template stands for, in place of the attribute.
+    fn apply_modifier(&mut self, modifier: Modifier) {
+        match modifier {
+            Modifier::Trim => self.trim = darling::util::Flag::present(),
+            Modifier::Uppercase => self.uppercase = darling::util::Flag::present(),
+            Modifier::Lowercase => self.lowercase = darling::util::Flag::present(),
+            Modifier::Quoted => self.quoted = darling::util::Flag::present(),
+            Modifier::Rest => self.rest = darling::util::Flag::present(),
+            Modifier::UntilEol => self.until_eol = darling::util::Flag::present(),
+            Modifier::Width(width) => self.width = Some(width),
+            Modifier::Format { spec, padded, fill } => {
+                self.format = Some(format!("{{:{}}}", spec));
+                if padded {
+                    self.trim = darling::util::Flag::present();
+                }
+                self.fill = fill;
+            }
+        }
+    }
+}
+
 pub(crate) enum FieldKind<'a> {
     Primitive(&'a syn::Type),
     Option(&'a syn::Type),
@@ -10,12 +296,85 @@ pub(crate) enum FieldKind<'a> {
     Vec(&'a syn::Type),
     HashSet(&'a syn::Type),
     BTreeSet(&'a syn::Type),
+    VecDeque(&'a syn::Type),
+    LinkedList(&'a syn::Type),
+    BinaryHeap(&'a syn::Type),
+    IndexSet(&'a syn::Type),
+    SmallVec(&'a syn::Type),
+    ArrayVec(&'a syn::Type),
     HashMap(&'a syn::Type, &'a syn::Type),
     BTreeMap(&'a syn::Type, &'a syn::Type),
+    IndexMap(&'a syn::Type, &'a syn::Type),
     Tuple,
     Unknown,
 }
 
+impl FieldKind<'_> {
+    /// Returns the type name of the values the field holds, alone or in an `Option` or a collection.
+    fn value_type_name(&self) -> Option<String> {
+        self.value_type().map(get_type_name)
+    }
+
+    /// Returns the type of the values the field holds, alone or in an `Option` or a collection.
+    fn value_type(&self) -> Option<&syn::Type> {
+        match self {
+            FieldKind::Primitive(ty)
+            | FieldKind::Option(ty)
+            | FieldKind::Vec(ty)
+            | FieldKind::HashSet(ty)
+            | FieldKind::BTreeSet(ty)
+            | FieldKind::VecDeque(ty)
+            | FieldKind::LinkedList(ty)
+            | FieldKind::BinaryHeap(ty)
+            | FieldKind::IndexSet(ty)
+            | FieldKind::SmallVec(ty)
+            | FieldKind::ArrayVec(ty) => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns true when the field holds `bool` values, alone or in an `Option` or a collection.
+    pub(crate) fn is_bool(&self) -> bool {
+        self.value_type_name().is_some_and(|name| name == "bool")
+    }
+
+    /// Returns true when the field holds `f32` or `f64` values, alone or in an `Option` or a collection.
+    pub(crate) fn is_float(&self) -> bool {
+        self.value_type_name()
+            .is_some_and(|name| name == "f32" || name == "f64")
+    }
+
+    /// Returns true when the field holds `url::Url` values, written with that path, and the `url`
+    /// feature is enabled.
+    pub(crate) fn is_url(&self) -> bool {
+        cfg!(feature = "url")
+            && self
+                .value_type()
+                .is_some_and(|ty| is_full_path(ty, &["url", "Url"]))
+    }
+
+    /// Returns the method rendering the `PathBuf` or `OsString` values the field holds.
+    ///
+    /// These types don't implement `Display`, so their values are rendered lossily through this method.
+    pub(crate) fn lossy_display(&self) -> Option<&'static str> {
+        match self.value_type_name()?.as_str() {
+            "PathBuf" => Some("display"),
+            "OsString" => Some("to_string_lossy"),
+            _ => None,
+        }
+    }
+
+    /// Returns the element type of an ordered sequence (`Vec`, `VecDeque`, or `LinkedList`).
+    ///
+    /// An included sequence renders and parses each of these elements through its own template.
+    pub(crate) fn sequence_element(&self) -> Option<&syn::Type> {
+        match self {
+            FieldKind::Vec(ty) | FieldKind::VecDeque(ty) | FieldKind::LinkedList(ty) => Some(ty),
+            _ => None,
+        }
+    }
+}
+
 impl Display for FieldKind<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -30,6 +389,12 @@ impl Display for FieldKind<'_> {
             FieldKind::Vec(ty) => write!(f, "Vec<{}>", get_type_name(ty)),
             FieldKind::HashSet(ty) => write!(f, "HashSet<{}>", get_type_name(ty)),
             FieldKind::BTreeSet(ty) => write!(f, "BTreeSet<{}>", get_type_name(ty)),
+            FieldKind::VecDeque(ty) => write!(f, "VecDeque<{}>", get_type_name(ty)),
+            FieldKind::LinkedList(ty) => write!(f, "LinkedList<{}>", get_type_name(ty)),
+            FieldKind::BinaryHeap(ty) => write!(f, "BinaryHeap<{}>", get_type_name(ty)),
+            FieldKind::IndexSet(ty) => write!(f, "IndexSet<{}>", get_type_name(ty)),
+            FieldKind::SmallVec(ty) => write!(f, "SmallVec<[{}; N]>", get_type_name(ty)),
+            FieldKind::ArrayVec(ty) => write!(f, "ArrayVec<{}, N>", get_type_name(ty)),
             FieldKind::HashMap(k_ty, v_ty) => write!(
                 f,
                 "HashMap<{}, {}>",
@@ -42,6 +407,12 @@ impl Display for FieldKind<'_> {
                 get_type_name(k_ty),
                 get_type_name(v_ty)
             ),
+            FieldKind::IndexMap(k_ty, v_ty) => write!(
+                f,
+                "IndexMap<{}, {}>",
+                get_type_name(k_ty),
+                get_type_name(v_ty)
+            ),
             FieldKind::Tuple => write!(f, "(<tuple>)"),
             FieldKind::Unknown => write!(f, "<unknown>"),
         }
@@ -51,18 +422,1065 @@ impl Display for FieldKind<'_> {
 pub(crate) struct Fields<'a> {
     fields: &'a [syn::Field],
     idents_type: HashMap<&'a syn::Ident, FieldKind<'a>>,
+    idents_opts: HashMap<&'a syn::Ident, FieldOpts>,
+    /// Field names keyed by the placeholder names that refer to them.
+    placeholder_fields: HashMap<String, String>,
+    /// Field names in declaration order, referred to by positional placeholders like `{0}`.
+    positional_fields: Vec<String>,
+    /// Delimiter of collection elements used when a field has no `separator` of its own.
+    default_separator: String,
+    /// Delimiter of map entries used when a field has no `entry_sep` of its own, if given.
+    default_entry_sep: Option<String>,
+    /// Delimiter of map keys and values used when a field has no `kv_sep` of its own.
+    default_kv_sep: String,
+    /// Token rendered for `None` when a field has no `render_none` of its own.
+    default_render_none: Option<String>,
+    /// Tokens parsed as `None` when a field has no `none_literals` of its own.
+    default_none_literals: Vec<String>,
+    /// Spelling of `bool` fields without a `bool_style` of their own.
+    default_bool_style: Option<BoolStyle>,
+    /// Representation of float fields without a `float` of their own.
+    default_float: Option<FloatStyle>,
+    /// Whether spaces and tabs around literals and collection separators are matched loosely when parsing.
+    loose_whitespace: bool,
+    /// Whether the input after the end of the template is ignored when parsing.
+    allow_trailing: bool,
+    /// Whether whitespace and line breaks around the input are ignored when parsing.
+    trim_input: bool,
+    /// Line ending of the rendered template, which also makes parsing accept both line endings.
+    newline: Option<Newline>,
+    /// Whether empty captures of `Option` fields are parsed as values rather than taken as `None`.
+    strict: bool,
+    /// Whether empty captures of `Option` string fields are parsed as `Some("")`.
+    empty_str_option_not_none: bool,
+    /// How the occurrences of a repeated placeholder are resolved when parsing.
+    on_duplicate: OnDuplicate,
+    /// Path of the templatia crate used in the generated code.
+    crate_path: syn::Path,
+}
+
+/// Container-level defaults of per-field options, set with the same attributes on the struct.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct FieldDefaults<'o> {
+    /// The delimiter of collection elements, which is `,` when absent.
+    pub(crate) separator: Option<&'o str>,
+    /// The delimiter of map entries, which is the `separator` when absent.
+    pub(crate) entry_sep: Option<&'o str>,
+    /// The delimiter of map keys and values, which is `=` when absent.
+    pub(crate) kv_sep: Option<&'o str>,
+    /// The token rendered for `None`, which is an empty string when absent.
+    pub(crate) render_none: Option<&'o str>,
+    /// The tokens parsed as `None` in addition to the `render_none` token.
+    pub(crate) none_literals: Option<&'o [syn::LitStr]>,
+    /// The spelling of `bool` fields, which is `true` and `false` when absent.
+    pub(crate) bool_style: Option<BoolStyle>,
+    /// The representation of float fields, which is their `Display` output when absent.
+    pub(crate) float: Option<FloatStyle>,
+    /// Whether spaces and tabs around literals and collection separators are matched loosely when parsing.
+    pub(crate) loose_whitespace: bool,
+    /// Whether the input after the end of the template is ignored when parsing.
+    pub(crate) allow_trailing: bool,
+    /// Whether whitespace and line breaks around the input are ignored when parsing.
+    pub(crate) trim_input: bool,
+    /// The line ending of the rendered template, which also makes parsing accept both line endings.
+    pub(crate) newline: Option<Newline>,
+    /// Whether empty captures of `Option` fields are parsed as values rather than taken as `None`.
+    pub(crate) strict: bool,
+    /// Whether empty captures of `Option` string fields are parsed as `Some("")`.
+    pub(crate) empty_str_option_not_none: bool,
+    /// How the occurrences of a repeated placeholder are resolved when parsing.
+    pub(crate) on_duplicate: OnDuplicate,
+    /// The modifiers written in the templates, paired with the placeholder name they follow.
+    pub(crate) modifiers: &'o [(&'o str, Modifier)],
 }
 
 impl<'a> Fields<'a> {
-    pub(crate) fn new(fields: &'a [syn::Field]) -> Self {
+    /// Analyzes the field types and parses the per-field `#[templatia(...)]` options.
+    ///
+    /// # Parameters
+    /// - fields: The fields used by the template.
+    /// - defaults: The container-level defaults of per-field options.
+    /// - crate_path: The path of the templatia crate used in the generated code.
+    ///
+    /// # Errors
+    /// - A per-field attribute is unknown or malformed.
+    /// - A separator is empty.
+    /// - A modifier makes an invalid combination with the attributes of its field.
+    pub(crate) fn new(
+        fields: &'a [syn::Field],
+        defaults: FieldDefaults<'_>,
+        crate_path: syn::Path,
+    ) -> Result<Self, darling::Error> {
         let idents_type = analyze_fields(fields);
 
-        Self {
+        let mut errors = darling::Error::accumulator();
+        if defaults.separator == Some("") {
+            errors.push(darling::Error::custom("separator must not be empty"));
+        }
+        if defaults.entry_sep == Some("") || defaults.kv_sep == Some("") {
+            errors.push(darling::Error::custom(
+                "entry_sep and kv_sep must not be empty",
+            ));
+        }
+        let mut idents_opts = HashMap::new();
+        for (position, field) in fields.iter().enumerate() {
+            if let Some(ident) = field.ident.as_ref()
+                && let Some(mut opts) = errors.handle(FieldOpts::from_field(field))
+            {
+                // Modifiers are applied before the checks, so they are validated like the attributes.
+                let placeholder = opts.rename.clone().unwrap_or_else(|| ident.to_string());
+                let position = position.to_string();
+                for (_, modifier) in defaults
+                    .modifiers
+                    .iter()
+                    .filter(|(name, _)| *name == placeholder || *name == position)
+                {
+                    opts.apply_modifier(modifier.clone());
+                }
+                if let Some(format) = &opts.format
+                    && !is_single_argument_format(format)
+                {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "format \"{}\" must contain exactly one `{{}}` or `{{:spec}}` argument",
+                            format
+                        ))
+                        .with_span(ident),
+                    );
+                }
+                if opts.uppercase.is_present() && opts.lowercase.is_present() {
+                    errors.push(
+                        darling::Error::custom("uppercase and lowercase can't be used together")
+                            .with_span(ident),
+                    );
+                }
+                if opts.with.is_some()
+                    && (opts.uppercase.is_present() || opts.lowercase.is_present())
+                {
+                    errors.push(
+                        darling::Error::custom(
+                            "uppercase and lowercase can't be combined with `with`; apply the case in the module instead",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                if opts.empty_str_is_none.is_present() || opts.empty_str_is_some.is_present() {
+                    if opts.empty_str_is_none.is_present() && opts.empty_str_is_some.is_present() {
+                        errors.push(
+                            darling::Error::custom(
+                                "empty_str_is_none and empty_str_is_some can't be used together",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                    if !matches!(idents_type.get(ident), Some(FieldKind::Option(_))) {
+                        errors.push(
+                            darling::Error::custom(
+                                "empty_str_is_none and empty_str_is_some can be used only on Option<T> fields",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if defaults.strict && opts.empty_str_is_none.is_present() {
+                    errors.push(
+                        darling::Error::custom(
+                            "empty_str_is_none can't be used in strict mode, which parses empty captures as values",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                if opts.width == Some(0) {
+                    errors.push(darling::Error::custom("width must be positive").with_span(ident));
+                }
+                if opts.width.is_some() && opts.match_strategy == MatchStrategy::Greedy {
+                    errors.push(
+                        darling::Error::custom("width can't be combined with greedy matching")
+                            .with_span(ident),
+                    );
+                }
+                // Quoted and escaped values are decoded from the captured text, which needs an owned string value.
+                let decoding = if opts.quoted.is_present() {
+                    Some("quoted")
+                } else if opts.escape.is_some() {
+                    Some("escape")
+                } else {
+                    None
+                };
+                if let Some(decoding) = decoding {
+                    let unsupported = match idents_type.get(ident) {
+                        Some(FieldKind::Primitive(ty) | FieldKind::Option(ty))
+                            if is_borrowed_str(ty) =>
+                        {
+                            Some("borrowed &str fields")
+                        }
+                        Some(FieldKind::Primitive(_) | FieldKind::Option(_)) => None,
+                        // The elements of an escaped collection are split on the unescaped separators,
+                        // and the entries of an escaped map on the unescaped key-value delimiters too.
+                        Some(
+                            FieldKind::Vec(_)
+                            | FieldKind::HashSet(_)
+                            | FieldKind::BTreeSet(_)
+                            | FieldKind::VecDeque(_)
+                            | FieldKind::LinkedList(_)
+                            | FieldKind::BinaryHeap(_)
+                            | FieldKind::IndexSet(_)
+                            | FieldKind::SmallVec(_)
+                            | FieldKind::ArrayVec(_)
+                            | FieldKind::HashMap(..)
+                            | FieldKind::BTreeMap(..)
+                            | FieldKind::IndexMap(..),
+                        ) if opts.escape.is_some() && !opts.quoted.is_present() => None,
+                        Some(
+                            FieldKind::HashMap(..)
+                            | FieldKind::BTreeMap(..)
+                            | FieldKind::IndexMap(..),
+                        ) => Some("map fields"),
+                        _ => Some("collection fields"),
+                    }
+                    .or(opts.with.as_ref().map(|_| "`with`"))
+                    .or(opts.width.map(|_| "width"))
+                    .or((opts.match_strategy == MatchStrategy::Greedy).then_some("greedy matching"))
+                    .or((opts.quoted.is_present() && opts.escape.is_some()).then_some("escape"));
+                    if let Some(unsupported) = unsupported {
+                        errors.push(
+                            darling::Error::custom(format!(
+                                "{} can't be used with {}",
+                                decoding, unsupported
+                            ))
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.width.is_some() && opts.with.is_some() {
+                    errors.push(
+                        darling::Error::custom("width can't be combined with `with`")
+                            .with_span(ident),
+                    );
+                }
+                if let Some(radix) = opts.radix {
+                    if ![2, 8, 16].contains(&radix) {
+                        errors.push(
+                            darling::Error::custom("radix must be 2, 8, or 16").with_span(ident),
+                        );
+                    }
+                    let is_integer = match idents_type.get(ident) {
+                        Some(
+                            FieldKind::Primitive(ty)
+                            | FieldKind::Option(ty)
+                            | FieldKind::Vec(ty)
+                            | FieldKind::HashSet(ty)
+                            | FieldKind::BTreeSet(ty)
+                            | FieldKind::VecDeque(ty)
+                            | FieldKind::LinkedList(ty)
+                            | FieldKind::BinaryHeap(ty)
+                            | FieldKind::IndexSet(ty)
+                            | FieldKind::SmallVec(ty)
+                            | FieldKind::ArrayVec(ty),
+                        ) => is_integer_type(ty),
+                        _ => false,
+                    };
+                    if !is_integer || opts.with.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "radix can be used only on primitive integer fields without `with`",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.datetime_format.is_some() {
+                    if !cfg!(any(feature = "chrono", feature = "time")) {
+                        errors.push(
+                            darling::Error::custom(
+                                "datetime_format requires the chrono or time feature of templatia",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if !matches!(
+                        idents_type.get(ident),
+                        Some(
+                            FieldKind::Primitive(_)
+                                | FieldKind::Option(_)
+                                | FieldKind::Vec(_)
+                                | FieldKind::HashSet(_)
+                                | FieldKind::BTreeSet(_)
+                                | FieldKind::VecDeque(_)
+                                | FieldKind::LinkedList(_)
+                                | FieldKind::BinaryHeap(_)
+                                | FieldKind::IndexSet(_)
+                                | FieldKind::SmallVec(_)
+                                | FieldKind::ArrayVec(_)
+                        )
+                    ) || opts.with.is_some()
+                        || opts.format.is_some()
+                        || opts.radix.is_some()
+                    {
+                        errors.push(
+                            darling::Error::custom(
+                                "datetime_format can be used only on date and time fields without `with`, format, or radix",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.uuid_format.is_some() {
+                    let is_uuid = idents_type
+                        .get(ident)
+                        .and_then(FieldKind::value_type_name)
+                        .is_some_and(|name| name == "Uuid")
+                        && !matches!(idents_type.get(ident), Some(FieldKind::Result(..)));
+                    if !cfg!(feature = "uuid") {
+                        errors.push(
+                            darling::Error::custom(
+                                "uuid_format requires the uuid feature of templatia",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if !is_uuid || opts.with.is_some() || opts.format.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "uuid_format can be used only on Uuid fields without `with` or format",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.bytesize.is_present() {
+                    let is_u64 = idents_type
+                        .get(ident)
+                        .and_then(FieldKind::value_type_name)
+                        .is_some_and(|name| name == "u64")
+                        && !matches!(idents_type.get(ident), Some(FieldKind::Result(..)));
+                    if !is_u64
+                        || opts.with.is_some()
+                        || opts.format.is_some()
+                        || opts.radix.is_some()
+                        || opts.datetime_format.is_some()
+                    {
+                        errors.push(
+                            darling::Error::custom(
+                                "bytesize can be used only on u64 fields without `with`, format, radix, or datetime_format",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                let is_bool = idents_type.get(ident).is_some_and(FieldKind::is_bool);
+                if opts.bool_style.is_some() {
+                    if !is_bool || opts.with.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "bool_style can be used only on bool fields without `with`",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if opts.true_values.is_some() || opts.false_values.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "bool_style can't be combined with true_values or false_values",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.float.is_some() {
+                    let is_float = idents_type.get(ident).is_some_and(FieldKind::is_float);
+                    if !is_float || opts.with.is_some() || opts.format.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "float can be used only on f32 and f64 fields without `with` or `format`",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.true_values.is_some() || opts.false_values.is_some() {
+                    let values = opts
+                        .true_values
+                        .iter()
+                        .chain(&opts.false_values)
+                        .flatten()
+                        .map(syn::LitStr::value);
+                    let mut seen = HashSet::new();
+                    if !is_bool || opts.with.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "true_values and false_values can be used only on bool fields without `with`",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if opts.true_values.as_ref().is_some_and(Vec::is_empty)
+                        || opts.false_values.as_ref().is_some_and(Vec::is_empty)
+                        || !values
+                            .into_iter()
+                            .all(|value| !value.is_empty() && seen.insert(value))
+                    {
+                        errors.push(
+                            darling::Error::custom(
+                                "true_values and false_values must be non-empty and distinct",
+                            )
+                            .with_span(ident),
+                        );
+                    }
+                }
+                if opts.render_none.is_some()
+                    && !matches!(idents_type.get(ident), Some(FieldKind::Option(_)))
+                {
+                    errors.push(
+                        darling::Error::custom("render_none can be used only on Option<T> fields")
+                            .with_span(ident),
+                    );
+                }
+                if let Some(none_literals) = &opts.none_literals {
+                    if !matches!(idents_type.get(ident), Some(FieldKind::Option(_))) {
+                        errors.push(
+                            darling::Error::custom(
+                                "none_literals can be used only on Option<T> fields",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if none_literals.iter().any(|lit| lit.value().is_empty()) {
+                        errors.push(
+                            darling::Error::custom("none_literals must not contain empty tokens")
+                                .with_span(ident),
+                        );
+                    }
+                }
+                if opts.rest.is_present()
+                    && (opts.width.is_some()
+                        || opts.quoted.is_present()
+                        || opts.escape.is_some()
+                        || opts.match_strategy == MatchStrategy::Greedy)
+                {
+                    errors.push(
+                        darling::Error::custom(
+                            "rest can't be combined with width, quoted, escape, or greedy matching",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                if opts.until_eol.is_present()
+                    && (opts.rest.is_present()
+                        || opts.width.is_some()
+                        || opts.quoted.is_present()
+                        || opts.escape.is_some()
+                        || opts.match_strategy == MatchStrategy::Greedy)
+                {
+                    errors.push(
+                        darling::Error::custom(
+                            "until_eol can't be combined with rest, width, quoted, escape, or greedy matching",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                // The bounds are checked on each value, which is the element of `Option` and collections.
+                let value_type = match idents_type.get(ident) {
+                    Some(
+                        FieldKind::Primitive(ty)
+                        | FieldKind::Option(ty)
+                        | FieldKind::Vec(ty)
+                        | FieldKind::HashSet(ty)
+                        | FieldKind::BTreeSet(ty)
+                        | FieldKind::VecDeque(ty)
+                        | FieldKind::LinkedList(ty)
+                        | FieldKind::BinaryHeap(ty)
+                        | FieldKind::IndexSet(ty)
+                        | FieldKind::SmallVec(ty)
+                        | FieldKind::ArrayVec(ty),
+                    ) if opts.with.is_none() => Some(*ty),
+                    _ => None,
+                };
+                if (opts.min.is_some() || opts.max.is_some())
+                    && !value_type.is_some_and(is_number_type)
+                {
+                    errors.push(
+                        darling::Error::custom(
+                            "min and max can be used only on integer and float fields without `with`",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                if (opts.min_len.is_some() || opts.max_len.is_some())
+                    && !value_type
+                        .is_some_and(|ty| get_type_name(ty) == "String" || is_borrowed_str(ty))
+                {
+                    errors.push(
+                        darling::Error::custom(
+                            "min_len and max_len can be used only on string fields without `with`",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                if let (Some(min_len), Some(max_len)) = (opts.min_len, opts.max_len)
+                    && min_len > max_len
+                {
+                    errors.push(
+                        darling::Error::custom("min_len must not be greater than max_len")
+                            .with_span(ident),
+                    );
+                }
+                if opts.skip_render.is_present() && opts.skip_parse.is_present() {
+                    errors.push(
+                        darling::Error::custom("skip_render and skip_parse can't be used together")
+                            .with_span(ident),
+                    );
+                }
+                if opts.skip_parse.is_present() && opts.validate.is_some() {
+                    errors.push(
+                        darling::Error::custom(
+                            "validate can't be combined with skip_parse because the field isn't parsed",
+                        )
+                        .with_span(ident),
+                    );
+                }
+                if opts.separator.as_deref() == Some("") {
+                    errors.push(
+                        darling::Error::custom("separator must not be empty").with_span(ident),
+                    );
+                }
+                if opts.ok_prefix.is_some() || opts.err_prefix.is_some() {
+                    if !matches!(idents_type.get(ident), Some(FieldKind::Result(..)))
+                        || opts.with.is_some()
+                    {
+                        errors.push(
+                            darling::Error::custom(
+                                "ok_prefix and err_prefix can be used only on Result<T, E> fields without `with`",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if opts.ok_prefix.as_deref().unwrap_or(DEFAULT_OK_PREFIX)
+                        == opts.err_prefix.as_deref().unwrap_or(DEFAULT_ERR_PREFIX)
+                    {
+                        errors.push(
+                            darling::Error::custom("ok_prefix and err_prefix must differ")
+                                .with_span(ident),
+                        );
+                    }
+                }
+                if opts.entry_sep.is_some() || opts.kv_sep.is_some() {
+                    let is_map = matches!(
+                        idents_type.get(ident),
+                        Some(
+                            FieldKind::HashMap(..)
+                                | FieldKind::BTreeMap(..)
+                                | FieldKind::IndexMap(..)
+                        )
+                    );
+                    if !is_map || opts.with.is_some() {
+                        errors.push(
+                            darling::Error::custom(
+                                "entry_sep and kv_sep can be used only on HashMap and BTreeMap fields without `with`",
+                            )
+                            .with_span(ident),
+                        );
+                    } else if opts.entry_sep.as_deref() == Some("")
+                        || opts.kv_sep.as_deref() == Some("")
+                    {
+                        errors.push(
+                            darling::Error::custom("entry_sep and kv_sep must not be empty")
+                                .with_span(ident),
+                        );
+                    }
+                }
+                idents_opts.insert(ident, opts);
+            }
+        }
+
+        let mut placeholder_fields = HashMap::new();
+        for field in fields {
+            let Some(ident) = field.ident.as_ref() else {
+                continue;
+            };
+            let placeholder = idents_opts
+                .get(ident)
+                .and_then(|opts| opts.rename.clone())
+                .unwrap_or_else(|| ident.to_string());
+            if placeholder_fields
+                .insert(placeholder.clone(), ident.to_string())
+                .is_some()
+            {
+                errors.push(
+                    darling::Error::custom(format!(
+                        "placeholder name \"{}\" is used by more than one field",
+                        placeholder
+                    ))
+                    .with_span(ident),
+                );
+            }
+        }
+        errors.finish()?;
+
+        Ok(Self {
             fields,
             idents_type,
+            idents_opts,
+            placeholder_fields,
+            positional_fields: fields
+                .iter()
+                .filter_map(|field| field.ident.as_ref().map(|ident| ident.to_string()))
+                .collect(),
+            default_separator: defaults.separator.unwrap_or(",").to_string(),
+            default_entry_sep: defaults.entry_sep.map(str::to_string),
+            default_kv_sep: defaults.kv_sep.unwrap_or("=").to_string(),
+            default_render_none: defaults.render_none.map(str::to_string),
+            default_bool_style: defaults.bool_style,
+            default_float: defaults.float,
+            default_none_literals: defaults
+                .none_literals
+                .unwrap_or_default()
+                .iter()
+                .map(syn::LitStr::value)
+                .collect(),
+            loose_whitespace: defaults.loose_whitespace,
+            allow_trailing: defaults.allow_trailing,
+            trim_input: defaults.trim_input,
+            newline: defaults.newline,
+            strict: defaults.strict,
+            empty_str_option_not_none: defaults.empty_str_option_not_none,
+            on_duplicate: defaults.on_duplicate,
+            crate_path,
+        })
+    }
+
+    /// Returns true when `#[templatia(loose_whitespace)]` is given on the container.
+    pub(crate) fn is_loose_whitespace(&self) -> bool {
+        self.loose_whitespace
+    }
+
+    /// Returns true when `#[templatia(allow_trailing)]` is given on the container.
+    pub(crate) fn is_allow_trailing(&self) -> bool {
+        self.allow_trailing
+    }
+
+    /// Returns true when `#[templatia(trim_input)]` is given on the container.
+    pub(crate) fn is_trim_input(&self) -> bool {
+        self.trim_input
+    }
+
+    /// Returns the text of a template literal as rendered, with `\n` replaced by `\r\n` for
+    /// `#[templatia(newline = "crlf")]`.
+    pub(crate) fn render_literal(&self, literal: &str) -> String {
+        match self.newline {
+            Some(Newline::Crlf) => literal.replace("\r\n", "\n").replace('\n', "\r\n"),
+            _ => literal.to_string(),
+        }
+    }
+
+    /// Returns true when a line break of the template matches both `\n` and `\r\n` when parsing,
+    /// which is the case when `#[templatia(newline = "...")]` is given.
+    pub(crate) fn is_any_newline(&self) -> bool {
+        self.newline.is_some()
+    }
+
+    /// Returns the characters that may be left at the end of the input when parsing,
+    /// or `None` when the input must end right after the template.
+    pub(crate) fn end_blanks(&self) -> Option<&'static str> {
+        if self.trim_input {
+            Some(" \t\r\n")
+        } else if self.loose_whitespace {
+            Some(" \t")
+        } else {
+            None
+        }
+    }
+
+    /// Returns the path of the templatia crate, which is `::templatia` unless `#[templatia(crate = "...")]` is given.
+    pub(crate) fn crate_path(&self) -> &syn::Path {
+        &self.crate_path
+    }
+
+    /// Returns the mask rendered instead of the value of a `#[templatia(secret)]` field, if any.
+    pub(crate) fn get_secret(&self, ident: &syn::Ident) -> Option<&str> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.secret.as_ref())
+            .map(|secret| match secret {
+                darling::util::Override::Explicit(mask) => mask.as_str(),
+                darling::util::Override::Inherit => DEFAULT_SECRET_MASK,
+            })
+    }
+
+    /// Returns true when `#[templatia(strict)]` is given on the container.
+    pub(crate) fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns how the occurrences of a repeated placeholder are resolved, set with
+    /// `#[templatia(on_duplicate = "...")]` on the container.
+    pub(crate) fn on_duplicate(&self) -> OnDuplicate {
+        self.on_duplicate
+    }
+
+    /// Returns the per-field policy for empty captures of `Option` fields,
+    /// `Some(true)` when they are `None` and `Some(false)` when they are `Some("")`.
+    pub(crate) fn empty_str_as_none(&self, ident: &syn::Ident) -> Option<bool> {
+        let opts = self.idents_opts.get(ident)?;
+        if opts.empty_str_is_none.is_present() {
+            Some(true)
+        } else if opts.empty_str_is_some.is_present() {
+            Some(false)
+        } else {
+            None
         }
     }
 
+    /// Returns whether an empty capture of the `Option` field is parsed as `None`, following the
+    /// per-field policy, `empty_str_option_not_none`, and `strict` like the generated parser.
+    pub(crate) fn is_empty_capture_none(&self, ident: &syn::Ident) -> bool {
+        let is_string_type = match self.get_field_kind(ident) {
+            Some(FieldKind::Option(ty)) => {
+                matches!(get_type_name(ty).to_lowercase().as_str(), "string" | "str")
+            }
+            _ => return false,
+        };
+        let empty_str_as_none = self
+            .empty_str_as_none(ident)
+            .unwrap_or(!self.empty_str_option_not_none && !self.strict);
+        empty_str_as_none || (!self.strict && !is_string_type)
+    }
+
+    /// Returns the alternative keys given with `#[templatia(alias = "...")]`.
+    pub(crate) fn get_aliases(&self, ident: &syn::Ident) -> &[String] {
+        self.idents_opts
+            .get(ident)
+            .map(|opts| opts.alias.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the validation function given with `#[templatia(validate = "...")]`, if any.
+    pub(crate) fn get_validate(&self, ident: &syn::Ident) -> Option<&syn::Path> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.validate.as_ref())
+    }
+
+    /// Returns the delimiter between the elements of a collection field.
+    ///
+    /// # Notes
+    /// - The entries of a map field are delimited by its `entry_sep`, falling back to the `separator`.
+    pub(crate) fn get_separator(&self, ident: &syn::Ident) -> &str {
+        let is_map = matches!(
+            self.idents_type.get(ident),
+            Some(FieldKind::HashMap(..) | FieldKind::BTreeMap(..) | FieldKind::IndexMap(..))
+        );
+        // Only map fields can have their own `entry_sep`.
+        let own = self
+            .idents_opts
+            .get(ident)
+            .and_then(|opts| opts.entry_sep.as_deref().or(opts.separator.as_deref()));
+        own.or(is_map
+            .then_some(self.default_entry_sep.as_deref())
+            .flatten())
+            .unwrap_or(&self.default_separator)
+    }
+
+    /// Returns the prefixes rendered before the `Ok` and the `Err` value of a `Result<T, E>` field.
+    pub(crate) fn get_result_prefixes(&self, ident: &syn::Ident) -> (&str, &str) {
+        let opts = self.idents_opts.get(ident);
+        (
+            opts.and_then(|opts| opts.ok_prefix.as_deref())
+                .unwrap_or(DEFAULT_OK_PREFIX),
+            opts.and_then(|opts| opts.err_prefix.as_deref())
+                .unwrap_or(DEFAULT_ERR_PREFIX),
+        )
+    }
+
+    /// Returns the delimiter between the key and the value of a map entry.
+    pub(crate) fn get_kv_separator(&self, ident: &syn::Ident) -> &str {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.kv_sep.as_deref())
+            .unwrap_or(&self.default_kv_sep)
+    }
+
+    /// Returns the format string used to render the field, if any.
+    ///
+    /// This is the `#[templatia(format = "...")]` value, or a format like `{:4x}` derived from
+    /// `#[templatia(width = N)]` and `#[templatia(radix = N)]`.
+    pub(crate) fn get_format(&self, ident: &syn::Ident) -> Option<String> {
+        let opts = self.idents_opts.get(ident)?;
+        if let Some(format) = &opts.format {
+            return Some(format.clone());
+        }
+        let radix = match (opts.radix, self.get_float(ident)) {
+            (Some(2), _) => "b",
+            (Some(8), _) => "o",
+            (Some(16), _) => "x",
+            (_, Some(FloatStyle::Exponent)) => "e",
+            _ => "",
+        };
+        match (opts.width, radix) {
+            (None, "") => None,
+            (width, radix) => Some(format!(
+                "{{:{}{}}}",
+                width.map(|width| width.to_string()).unwrap_or_default(),
+                radix
+            )),
+        }
+    }
+
+    /// Returns the representation of a float field given with `#[templatia(float = "...")]` on the
+    /// field or on the struct. The struct-level one doesn't reach fields with `format` or `with`.
+    pub(crate) fn get_float(&self, ident: &syn::Ident) -> Option<FloatStyle> {
+        let opts = self.idents_opts.get(ident)?;
+        if opts.float.is_some() {
+            return opts.float;
+        }
+        let is_float = self.idents_type.get(ident).is_some_and(FieldKind::is_float);
+        self.default_float
+            .filter(|_| is_float && opts.format.is_none() && opts.with.is_none())
+    }
+
+    /// Returns the format string given with `#[templatia(format = "...")]` itself, if any.
+    pub(crate) fn get_explicit_format(&self, ident: &syn::Ident) -> Option<&str> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.format.as_deref())
+    }
+
+    /// Returns the spellings of `true` and `false` when `true_values`, `false_values`, or `bool_style`
+    /// is given. The side that isn't given keeps its standard spelling, and a style lists its own
+    /// spellings first followed by those of the other styles.
+    pub(crate) fn get_bool_literals(
+        &self,
+        ident: &syn::Ident,
+    ) -> Option<(Vec<String>, Vec<String>)> {
+        let opts = self.idents_opts.get(ident)?;
+        if opts.true_values.is_none() && opts.false_values.is_none() {
+            // The struct-level style only reaches the bool fields that parse with `FromStr`.
+            let style = match opts.bool_style {
+                Some(style) => style,
+                None if opts.with.is_none()
+                    && self.idents_type.get(ident).is_some_and(FieldKind::is_bool) =>
+                {
+                    self.default_bool_style?
+                }
+                None => return None,
+            };
+            let styles = std::iter::once(style)
+                .chain(BoolStyle::ALL.into_iter().filter(|other| *other != style))
+                .map(BoolStyle::spellings);
+            return Some(
+                styles
+                    .map(|(true_value, false_value)| {
+                        (true_value.to_string(), false_value.to_string())
+                    })
+                    .unzip(),
+            );
+        }
+        let values = |values: &Option<Vec<syn::LitStr>>, standard: &str| match values {
+            Some(values) => values.iter().map(syn::LitStr::value).collect(),
+            None => vec![standard.to_string()],
+        };
+        let true_values = values(&opts.true_values, "true");
+        let false_values = values(&opts.false_values, "false");
+        Some((true_values, false_values))
+    }
+
+    /// Returns the base given with `#[templatia(radix = N)]`, if any.
+    pub(crate) fn get_radix(&self, ident: &syn::Ident) -> Option<u32> {
+        self.idents_opts.get(ident).and_then(|opts| opts.radix)
+    }
+
+    /// Returns the `chrono` format string or the `time` format description given with `#[templatia(datetime_format = "...")]`, if any.
+    pub(crate) fn get_datetime_format(&self, ident: &syn::Ident) -> Option<&str> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.datetime_format.as_deref())
+    }
+
+    /// Returns the representation given with `#[templatia(uuid_format = "...")]`, if any.
+    pub(crate) fn get_uuid_format(&self, ident: &syn::Ident) -> Option<UuidFormat> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.uuid_format)
+    }
+
+    /// Returns true when the field is rendered and parsed as a byte size.
+    pub(crate) fn is_bytesize(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.bytesize.is_present())
+    }
+
+    /// Returns the escape character given with `#[templatia(escape = '...')]`, if any.
+    pub(crate) fn get_escape(&self, ident: &syn::Ident) -> Option<char> {
+        self.idents_opts.get(ident).and_then(|opts| opts.escape)
+    }
+
+    /// Returns true when the field is rendered and parsed as a quoted token.
+    pub(crate) fn is_quoted(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.quoted.is_present())
+    }
+
+    /// Returns true when the placeholder of the field captures all remaining input, given with `#[templatia(rest)]`.
+    pub(crate) fn is_rest(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.rest.is_present())
+    }
+
+    /// Returns the bounds given with `#[templatia(min = ..., max = ...)]` as `(min, max)`.
+    pub(crate) fn get_range(&self, ident: &syn::Ident) -> (Option<&syn::Expr>, Option<&syn::Expr>) {
+        match self.idents_opts.get(ident) {
+            Some(opts) => (opts.min.as_ref(), opts.max.as_ref()),
+            None => (None, None),
+        }
+    }
+
+    /// Returns the bounds given with `#[templatia(min_len = ..., max_len = ...)]` as `(min_len, max_len)`.
+    pub(crate) fn get_len_range(&self, ident: &syn::Ident) -> (Option<usize>, Option<usize>) {
+        match self.idents_opts.get(ident) {
+            Some(opts) => (opts.min_len, opts.max_len),
+            None => (None, None),
+        }
+    }
+
+    /// Returns true when the capture of the field ends at a line break, given with `#[templatia(until_eol)]`.
+    pub(crate) fn is_until_eol(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.until_eol.is_present())
+    }
+
+    /// Returns true when the placeholder of the field renders nothing, given with `#[templatia(skip_render)]`.
+    pub(crate) fn is_skip_render(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.skip_render.is_present())
+    }
+
+    /// Returns true when the capture of the field is discarded on parse, given with `#[templatia(skip_parse)]`.
+    pub(crate) fn is_skip_parse(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.skip_parse.is_present())
+    }
+
+    /// Returns the token rendered for `None` of an `Option` field, if any.
+    pub(crate) fn get_render_none(&self, ident: &syn::Ident) -> Option<&str> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.render_none.as_deref())
+            .or(self.default_render_none.as_deref())
+    }
+
+    /// Returns the tokens parsed back to `None` of an `Option` field, which are the `render_none`
+    /// token followed by the `none_literals`.
+    pub(crate) fn get_none_tokens(&self, ident: &syn::Ident) -> Vec<String> {
+        let none_literals = match self
+            .idents_opts
+            .get(ident)
+            .and_then(|opts| opts.none_literals.as_ref())
+        {
+            Some(none_literals) => none_literals.iter().map(syn::LitStr::value).collect(),
+            None => self.default_none_literals.clone(),
+        };
+        self.get_render_none(ident)
+            .map(str::to_string)
+            .into_iter()
+            .chain(none_literals)
+            .collect()
+    }
+
+    /// Returns the matching strategy given with `#[templatia(match = "...")]`, lazy by default.
+    pub(crate) fn get_match_strategy(&self, ident: &syn::Ident) -> MatchStrategy {
+        self.idents_opts
+            .get(ident)
+            .map(|opts| opts.match_strategy)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of characters given with `#[templatia(width = N)]`, if any.
+    pub(crate) fn get_width(&self, ident: &syn::Ident) -> Option<usize> {
+        self.idents_opts.get(ident).and_then(|opts| opts.width)
+    }
+
+    /// Returns the module given with `#[templatia(with = "...")]`, if any.
+    pub(crate) fn get_with(&self, ident: &syn::Ident) -> Option<&syn::Path> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.with.as_ref())
+    }
+
+    /// Returns the fill character and alignment of a `{field:*^10}` format spec, if any.
+    pub(crate) fn get_fill(&self, ident: &syn::Ident) -> Option<(char, char)> {
+        self.idents_opts.get(ident).and_then(|opts| opts.fill)
+    }
+
+    /// Returns true when the captured text of the field is trimmed before parsing.
+    pub(crate) fn is_trim(&self, ident: &syn::Ident) -> bool {
+        self.idents_opts
+            .get(ident)
+            .is_some_and(|opts| opts.trim.is_present())
+    }
+
+    /// Returns the letter case given with `#[templatia(uppercase)]` or `#[templatia(lowercase)]`, if any.
+    pub(crate) fn get_case(&self, ident: &syn::Ident) -> Option<ValueCase> {
+        let opts = self.idents_opts.get(ident)?;
+        if opts.uppercase.is_present() {
+            Some(ValueCase::Upper)
+        } else if opts.lowercase.is_present() {
+            Some(ValueCase::Lower)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the placeholder name of the field, which is the `rename` value or the field name.
+    pub(crate) fn placeholder_name(&self, ident: &syn::Ident) -> String {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.rename.clone())
+            .unwrap_or_else(|| ident.to_string())
+    }
+
+    /// Resolves a placeholder name written in a template to the name of the field it refers to.
+    ///
+    /// # Returns
+    /// - The field name. A number like `0` refers to the field at that position in declaration order.
+    ///   Unknown names are returned as is so that the existence check reports them.
+    ///
+    /// # Errors
+    /// - The name is the original name of a renamed field.
+    /// - The name is a position beyond the last field.
+    pub(crate) fn resolve_placeholder<'s>(&'s self, name: &'s str) -> Result<&'s str, String> {
+        if let Some(field) = self.placeholder_fields.get(name) {
+            return Ok(field);
+        }
+        if let Ok(position) = name.parse::<usize>() {
+            return self
+                .positional_fields
+                .get(position)
+                .map(String::as_str)
+                .ok_or_else(|| {
+                    format!(
+                        "positional placeholder {{{}}} is out of range because the struct has {} fields",
+                        name,
+                        self.positional_fields.len()
+                    )
+                });
+        }
+        match self.idents().into_iter().find(|ident| *ident == name) {
+            Some(ident) => Err(format!(
+                "field \"{}\" is renamed, so it must be referenced as {{{}}} in the template",
+                name,
+                self.placeholder_name(ident)
+            )),
+            None => Ok(name),
+        }
+    }
+
+    /// Returns the expression given with `#[templatia(default = "...")]`, if any.
+    pub(crate) fn get_default(&self, ident: &syn::Ident) -> Option<&syn::Expr> {
+        self.idents_opts
+            .get(ident)
+            .and_then(|opts| opts.default.as_ref())
+    }
+
     pub(crate) fn get_type_kind_by_name(&'_ self, name: &str) -> Option<&FieldKind<'_>> {
         let name = proc_macro2::Ident::new(name, proc_macro2::Span::call_site());
         self.idents_type.get(&name)
@@ -88,6 +1506,13 @@ impl<'a> Fields<'a> {
         self.idents_type.get(ident)
     }
 
+    pub(crate) fn get_field_type(&self, ident: &syn::Ident) -> Option<&syn::Type> {
+        self.fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(ident))
+            .map(|field| &field.ty)
+    }
+
     pub(crate) fn idents(&self) -> HashSet<&syn::Ident> {
         self.fields
             .iter()
@@ -125,6 +1550,17 @@ impl<'a> Fields<'a> {
             .collect()
     }
 
+    /// Returns the fields missing from the template that have a default expression.
+    pub(crate) fn missing_placeholders_with_default(
+        &self,
+        placeholder_names: &HashSet<String>,
+    ) -> Vec<(&syn::Ident, &syn::Expr)> {
+        self.missing_placeholders(placeholder_names)
+            .into_iter()
+            .filter_map(|ident| self.get_default(ident).map(|expr| (ident, expr)))
+            .collect()
+    }
+
     pub(crate) fn missing_placeholders_sep_opt(
         &self,
         placeholder_names: &HashSet<String>,
@@ -136,6 +1572,10 @@ impl<'a> Fields<'a> {
         let missing_placeholders = self.missing_placeholders(placeholder_names);
 
         for missing_placeholder in missing_placeholders {
+            // Fields with a default expression are constructed from it instead.
+            if self.get_default(missing_placeholder).is_some() {
+                continue;
+            }
             if option_fields.contains_key(missing_placeholder) {
                 missing_placeholders_sep_opt.push(missing_placeholder);
             } else {
@@ -150,6 +1590,22 @@ impl<'a> Fields<'a> {
     }
 }
 
+/// Returns true when the format string has exactly one positional argument like `{}` or `{:08}`.
+fn is_single_argument_format(format: &str) -> bool {
+    let unescaped = format.replace("{{", "").replace("}}", "");
+    let mut arguments = unescaped.match_indices('{');
+    match (arguments.next(), arguments.next()) {
+        (Some((start, _)), None) => {
+            let argument = &unescaped[start..];
+            argument.find('}').is_some_and(|end| {
+                let inner = &argument[1..end];
+                inner.is_empty() || inner.starts_with(':')
+            })
+        }
+        _ => false,
+    }
+}
+
 fn analyze_fields(fields: &'_ [syn::Field]) -> HashMap<&'_ syn::Ident, FieldKind<'_>> {
     let mut result = HashMap::new();
 
@@ -211,6 +1667,89 @@ fn analyze_fields(fields: &'_ [syn::Field]) -> HashMap<&'_ syn::Ident, FieldKind
                                         continue;
                                     }
                                 }
+                                "VecDeque" => {
+                                    if args.args.len() == 1
+                                        && let Some(GenericArgument::Type(ty)) = args.args.first()
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::VecDeque(ty),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                "LinkedList" => {
+                                    if args.args.len() == 1
+                                        && let Some(GenericArgument::Type(ty)) = args.args.first()
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::LinkedList(ty),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                "BinaryHeap" => {
+                                    if args.args.len() == 1
+                                        && let Some(GenericArgument::Type(ty)) = args.args.first()
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::BinaryHeap(ty),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                // The `indexmap` types are re-exported by templatia only with the feature.
+                                "IndexSet" if cfg!(feature = "indexmap") => {
+                                    if args.args.len() == 1
+                                        && let Some(GenericArgument::Type(ty)) = args.args.first()
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::IndexSet(ty),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                "IndexMap" if cfg!(feature = "indexmap") => {
+                                    if args.args.len() == 2
+                                        && let (
+                                            Some(GenericArgument::Type(key_ty)),
+                                            Some(GenericArgument::Type(value_ty)),
+                                        ) = (args.args.first(), args.args.last())
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::IndexMap(key_ty, value_ty),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                "SmallVec" if cfg!(feature = "smallvec") => {
+                                    // The inline capacity is part of the array type, as in `SmallVec<[T; 4]>`.
+                                    if args.args.len() == 1
+                                        && let Some(GenericArgument::Type(syn::Type::Array(array))) =
+                                            args.args.first()
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::SmallVec(&array.elem),
+                                        );
+                                        continue;
+                                    }
+                                }
+                                "ArrayVec" if cfg!(feature = "arrayvec") => {
+                                    if args.args.len() == 2
+                                        && let Some(GenericArgument::Type(ty)) = args.args.first()
+                                    {
+                                        result.insert(
+                                            field.ident.as_ref().unwrap(),
+                                            FieldKind::ArrayVec(ty),
+                                        );
+                                        continue;
+                                    }
+                                }
                                 "HashMap" => {
                                     if args.args.len() == 2
                                         && let (
@@ -253,6 +1792,23 @@ fn analyze_fields(fields: &'_ [syn::Field]) -> HashMap<&'_ syn::Ident, FieldKind
                                         continue;
                                     }
                                 }
+                                // A `chrono` date and time is a single value regardless of its time zone.
+                                "DateTime" if cfg!(feature = "chrono") => {
+                                    result.insert(
+                                        field.ident.as_ref().unwrap(),
+                                        FieldKind::Primitive(&field.ty),
+                                    );
+                                    continue;
+                                }
+                                // `NonZero<T>` behaves like the `NonZeroU16`-style aliases,
+                                // so it is treated as a single value rather than a container.
+                                "NonZero" => {
+                                    result.insert(
+                                        field.ident.as_ref().unwrap(),
+                                        FieldKind::Primitive(&field.ty),
+                                    );
+                                    continue;
+                                }
                                 _ => {}
                             }
                             result.insert(field.ident.as_ref().unwrap(), FieldKind::Unknown);
@@ -269,6 +1825,13 @@ fn analyze_fields(fields: &'_ [syn::Field]) -> HashMap<&'_ syn::Ident, FieldKind
                     }
                 }
             }
+            // `&str` borrows from the parsed input, so it is a single value like `String`.
+            ty @ syn::Type::Reference(_) if is_borrowed_str(ty) => {
+                result.insert(
+                    field.ident.as_ref().unwrap(),
+                    FieldKind::Primitive(&field.ty),
+                );
+            }
             syn::Type::Tuple(_) => {
                 result.insert(field.ident.as_ref().unwrap(), FieldKind::Tuple);
             }
@@ -0,0 +1,414 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The type the generated impl blocks are for.
+pub(crate) struct ImplTarget<'a> {
+    /// The identifier of the type.
+    pub(crate) name: &'a syn::Ident,
+    /// The generics of the type, without the bounds added for the fields.
+    pub(crate) generics: &'a syn::Generics,
+    /// The path of the templatia crate.
+    pub(crate) krate: &'a syn::Path,
+}
+
+/// Generates the inherent `from_str_lossy` of `#[templatia(lossy)]`.
+///
+/// # Parameters
+/// - target: The type the method is for.
+/// - where_clause: The where clause of the parsing impls.
+/// - lossy_parser: The parser emitting its errors while it goes on with default values.
+/// - filter_lines: The statements dropping the lines that aren't parsed from `s`, if any.
+/// - error_mapper: The closure mapping the errors of the parser to `TemplateError`.
+/// - field_defaults: The `field: value` initializers used when the literals don't match.
+/// - validate: The function checking the whole value, provided via `#[templatia(validate = "...")]`.
+pub(crate) fn generate_lossy_impl(
+    target: &ImplTarget,
+    where_clause: &TokenStream,
+    lossy_parser: &TokenStream,
+    filter_lines: Option<&TokenStream>,
+    error_mapper: &TokenStream,
+    field_defaults: &[TokenStream],
+    validate: Option<&syn::Path>,
+) -> TokenStream {
+    let ImplTarget {
+        name,
+        generics,
+        krate,
+    } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let validate_lossy = validate.map(|validate| {
+        quote! {
+            let mut errors: Vec<#krate::TemplateError> = errors;
+            if let Err(message) = #validate(&value) {
+                errors.push(#krate::TemplateError::InvalidValue {
+                    message: message.to_string(),
+                });
+            }
+        }
+    });
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Parses the input like `from_str`, but goes on when values fail to parse.
+            ///
+            /// # Parameters
+            /// - s: The input to parse.
+            ///
+            /// # Returns
+            /// The parsed value and all problems found. A value that fails to parse is replaced by
+            /// its default, and when the input doesn't match the template, all fields take their defaults.
+            pub fn from_str_lossy(s: &str) -> (Self, Vec<#krate::TemplateError>) {
+                use #krate::__private::chumsky;
+                use #krate::__private::chumsky::Parser;
+                use #krate::__private::chumsky::prelude::*;
+
+                #filter_lines
+                let to_template_error = #error_mapper;
+                let (value, errs) = (#lossy_parser).parse(s).into_output_errors();
+                let mut errors = errs
+                    .iter()
+                    .map(|err| {
+                        to_template_error(err)
+                            .unwrap_or_else(|| #krate::TemplateError::Parse(err.to_string()))
+                    })
+                    .collect::<Vec<_>>();
+                let value = value.unwrap_or_else(|| {
+                    // No value was told apart when the literals don't match, so the errors of the
+                    // values the parser tried on the way are left out.
+                    errors.retain(|error| {
+                        matches!(
+                            error,
+                            #krate::TemplateError::UnexpectedInput { .. }
+                                | #krate::TemplateError::Parse(_)
+                        )
+                    });
+                    Self { #(#field_defaults,)* }
+                });
+                #validate_lossy
+                (value, errors)
+            }
+        }
+    }
+}
+
+/// Generates the inherent `render_localized` of the localized templates.
+///
+/// # Parameters
+/// - target: The type the method is for.
+/// - where_clause: The where clause of the rendering impls.
+/// - localized_renders: The `locale => format!(...)` match arms of the localized templates.
+/// - default_render: The `format!(...)` expression of the default template, used for other locales.
+/// - is_union: Whether the type is a union, whose method is unsafe like its `render_string`.
+pub(crate) fn generate_localized_impl(
+    target: &ImplTarget,
+    where_clause: &TokenStream,
+    localized_renders: &[TokenStream],
+    default_render: &TokenStream,
+    is_union: bool,
+) -> TokenStream {
+    let ImplTarget { name, generics, .. } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let render = quote! {
+        match locale {
+            #(#localized_renders,)*
+            _ => #default_render,
+        }
+    };
+    // Reading a union field is unsafe as in the `render_string` of unions.
+    if is_union {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Renders the template of the given locale, or the default template for other locales.
+                ///
+                /// # Parameters
+                /// - locale: The locale key of `#[templatia(template(...))]`, such as `"en"`.
+                ///
+                /// # Returns
+                /// The rendered string.
+                ///
+                /// # Safety
+                /// The active field must be initialized, since it is read without a check.
+                pub unsafe fn render_localized(&self, locale: &str) -> String {
+                    // SAFETY: The caller guarantees that the active field is initialized.
+                    unsafe { #render }
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Renders the template of the given locale, or the default template for other locales.
+                ///
+                /// # Parameters
+                /// - locale: The locale key of `#[templatia(template(...))]`, such as `"en"`.
+                ///
+                /// # Returns
+                /// The rendered string.
+                pub fn render_localized(&self, locale: &str) -> String {
+                    #render
+                }
+            }
+        }
+    }
+}
+
+/// Generates the inherent `find` of `#[templatia(find)]`.
+///
+/// # Parameters
+/// - target: The type the method is for.
+/// - where_clause: The where clause of the parsing impls.
+/// - find_parser: The parser of the template that ignores the text after it.
+/// - find_literal: The literal every match starts with, which narrows the positions tried.
+/// - validate: The function checking the whole value, provided via `#[templatia(validate = "...")]`.
+pub(crate) fn generate_find_impl(
+    target: &ImplTarget,
+    where_clause: &TokenStream,
+    find_parser: &TokenStream,
+    find_literal: Option<&str>,
+    validate: Option<&syn::Path>,
+) -> TokenStream {
+    let ImplTarget {
+        name,
+        generics,
+        krate,
+    } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let starts = match find_literal {
+        Some(lit) => quote! {
+            haystack.match_indices(#lit).map(|(start, _)| start).collect::<Vec<_>>()
+        },
+        None => quote! {
+            haystack
+                .char_indices()
+                .map(|(start, _)| start)
+                .chain(::std::iter::once(haystack.len()))
+                .collect::<Vec<_>>()
+        },
+    };
+    let accept = match validate {
+        Some(validate) => quote! { #validate(&value).is_ok() },
+        None => quote! { true },
+    };
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Finds the first part of a larger text that matches the template and parses it.
+            ///
+            /// # Parameters
+            /// - haystack: The text to search, such as a log line or a document.
+            ///
+            /// # Returns
+            /// The value parsed from the earliest position where the template matches, or `None`.
+            pub fn find(haystack: &str) -> Option<Self> {
+                use #krate::__private::chumsky;
+                use #krate::__private::chumsky::Parser;
+                use #krate::__private::chumsky::prelude::*;
+
+                for start in #starts {
+                    let s = &haystack[start..];
+                    if let Ok(value) = (#find_parser).parse(s).into_result() {
+                        if #accept {
+                            return Some(value);
+                        }
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Generates the inherent `placeholders` listing the placeholder names of the template.
+///
+/// # Parameters
+/// - target: The type the method is for.
+/// - placeholder_list: The names in order of their first appearance.
+pub(crate) fn generate_placeholders_impl(
+    target: &ImplTarget,
+    placeholder_list: &[String],
+) -> TokenStream {
+    let ImplTarget { name, generics, .. } = target;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the placeholder names of the template in order of their first appearance.
+            ///
+            /// # Returns
+            /// The names as written in the template, which are the `rename` of renamed fields.
+            /// Each name is listed once, even when the template repeats it.
+            pub fn placeholders() -> &'static [&'static str] {
+                &[#(#placeholder_list),*]
+            }
+        }
+    }
+}
+
+/// Generates the `RenderTemplate` impl, or for a union the inherent unsafe `render_string`, since
+/// reading the active field of a union can't be checked by the safe methods of the trait.
+///
+/// # Parameters
+/// - target: The type the impl is for.
+/// - where_clause: The where clause of the rendering impls.
+/// - format_string: The `format!` string of the template.
+/// - format_args: The arguments of the format string.
+/// - is_union: Whether the type is a union.
+pub(crate) fn generate_render_impl(
+    target: &ImplTarget,
+    where_clause: &TokenStream,
+    format_string: &str,
+    format_args: &[TokenStream],
+    is_union: bool,
+) -> TokenStream {
+    let ImplTarget {
+        name,
+        generics,
+        krate,
+    } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    if is_union {
+        return quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Renders the template with the value of the active field.
+                ///
+                /// # Returns
+                /// The rendered string.
+                ///
+                /// # Safety
+                /// The active field must be initialized, since it is read without a check.
+                pub unsafe fn render_string(&self) -> String {
+                    // SAFETY: The caller guarantees that the active field is initialized.
+                    unsafe { format!(#format_string, #(#format_args),*) }
+                }
+            }
+        };
+    }
+
+    // `render_string` goes through `render_to` so that the template is expanded once.
+    quote! {
+        impl #impl_generics #krate::RenderTemplate for #name #ty_generics #where_clause {
+            fn render_to(&self, w: &mut (impl ::std::fmt::Write + ?Sized)) -> ::std::fmt::Result {
+                ::std::write!(w, #format_string, #(#format_args),*)
+            }
+
+            fn render_string(&self) -> String {
+                let mut rendered = String::new();
+                #krate::RenderTemplate::render_to(self, &mut rendered)
+                    .expect("a Display implementation returned an error unexpectedly");
+                rendered
+            }
+        }
+    }
+}
+
+/// Generates the `ParseTemplate` impl, or the `BorrowedTemplate<'templatia_src>` impl for types
+/// borrowing `&str` from the input, whose `from_str` can't take an input of an unrelated lifetime.
+///
+/// # Parameters
+/// - target: The type the impl is for.
+/// - where_clause: The where clause of the parsing impls.
+/// - borrows_input: Whether a field of the template borrows `&str` from the input.
+/// - error_type: The type of the parse errors.
+/// - parse_body: The body of `from_str`, parsing `s`.
+pub(crate) fn generate_parse_impl(
+    target: &ImplTarget,
+    where_clause: &syn::WhereClause,
+    borrows_input: bool,
+    error_type: &TokenStream,
+    parse_body: &TokenStream,
+) -> TokenStream {
+    let ImplTarget {
+        name,
+        generics,
+        krate,
+    } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    if !borrows_input {
+        let where_clause = (!where_clause.predicates.is_empty()).then_some(where_clause);
+        return quote! {
+            impl #impl_generics #krate::ParseTemplate for #name #ty_generics #where_clause {
+                type Error = #error_type;
+
+                fn from_str(s: &str) -> Result<Self, Self::Error> {
+                    #parse_body
+                }
+            }
+        };
+    }
+
+    // The input outlives every lifetime of the type, so the borrowed fields can point into it.
+    let mut generics = (*generics).clone();
+    let source_lifetime: syn::Lifetime = syn::parse_quote! { 'templatia_src };
+    let type_lifetimes = generics
+        .lifetimes()
+        .map(|param| param.lifetime.clone())
+        .collect::<Vec<_>>();
+    generics
+        .params
+        .insert(0, syn::parse_quote! { #source_lifetime });
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let mut where_clause = where_clause.clone();
+    for lifetime in type_lifetimes {
+        where_clause
+            .predicates
+            .push(syn::parse_quote! { #source_lifetime: #lifetime });
+    }
+    let where_clause = (!where_clause.predicates.is_empty()).then_some(where_clause);
+    quote! {
+        impl #impl_generics #krate::BorrowedTemplate<#source_lifetime> for #name #ty_generics #where_clause {
+            type Error = #error_type;
+
+            fn from_str(s: &#source_lifetime str) -> Result<Self, Self::Error> {
+                #parse_body
+            }
+        }
+    }
+}
+
+/// Generates the `Display` impl of `#[templatia(impl_display)]`, which delegates to `render_to`.
+///
+/// # Parameters
+/// - target: The type the impl is for.
+/// - where_clause: The where clause of the rendering impls.
+pub(crate) fn generate_display_impl(
+    target: &ImplTarget,
+    where_clause: &TokenStream,
+) -> TokenStream {
+    let ImplTarget {
+        name,
+        generics,
+        krate,
+    } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #krate::RenderTemplate::render_to(self, f)
+            }
+        }
+    }
+}
+
+/// Generates the `FromStr` impl of `#[templatia(impl_from_str)]`, which delegates to
+/// `ParseTemplate::from_str`.
+///
+/// # Parameters
+/// - target: The type the impl is for.
+/// - where_clause: The where clause of the parsing impls.
+pub(crate) fn generate_from_str_impl(
+    target: &ImplTarget,
+    where_clause: &TokenStream,
+) -> TokenStream {
+    let ImplTarget {
+        name,
+        generics,
+        krate,
+    } = target;
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = <Self as #krate::ParseTemplate>::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <Self as #krate::ParseTemplate>::from_str(s)
+            }
+        }
+    }
+}
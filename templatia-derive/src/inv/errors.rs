@@ -0,0 +1,161 @@
+use quote::quote;
+
+/// The errors the generated parsers raise themselves, each turned into a `TemplateError` variant.
+///
+/// Chumsky carries custom errors as messages, so a raised error is written as its marker followed
+/// by its values, separated by `::`, and mapped back by the closure of [`generate_error_mapper`].
+/// Colons inside the values are replaced by the escape marker of the type while they are carried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CustomError {
+    /// `TemplateError::InconsistentValues`: a repeated placeholder with differing values.
+    InconsistentValues,
+    /// `TemplateError::ParseToType`: a captured value that doesn't parse as the field type.
+    ParseToType,
+    /// `TemplateError::ZeroValue`: a zero captured for a `NonZero` field.
+    ZeroValue,
+    /// `TemplateError::Include`: an error of the template of a `{>field}` include.
+    Include,
+    /// `TemplateError::Validation`: a value rejected by the `validate` function of its field.
+    Validation,
+    /// `TemplateError::OutOfRange`: a value outside the `min`/`max` or length range of its field.
+    OutOfRange,
+    /// `TemplateError::UnexpectedInput`: a literal of the template missing from the input.
+    UnexpectedInput,
+}
+
+impl CustomError {
+    /// All custom errors, in the order the mapper tries them.
+    const ALL: [Self; 7] = [
+        Self::InconsistentValues,
+        Self::ParseToType,
+        Self::ZeroValue,
+        Self::Include,
+        Self::Validation,
+        Self::OutOfRange,
+        Self::UnexpectedInput,
+    ];
+
+    /// Returns the marker starting the messages of the error.
+    fn marker(self) -> &'static str {
+        match self {
+            Self::InconsistentValues => "__templatia_conflict__",
+            Self::ParseToType => "__templatia_parse_type__",
+            Self::ZeroValue => "__templatia_zero_value__",
+            Self::Include => "__templatia_include__",
+            Self::Validation => "__templatia_validation__",
+            Self::OutOfRange => "__templatia_out_of_range__",
+            Self::UnexpectedInput => "__templatia_parse_literal__",
+        }
+    }
+
+    /// Returns the name of the `TemplateError` variant and its fields, in the order of the values.
+    fn variant(self) -> (&'static str, &'static [&'static str]) {
+        match self {
+            Self::InconsistentValues => (
+                "InconsistentValues",
+                &["placeholder", "first_value", "second_value"],
+            ),
+            Self::ParseToType => ("ParseToType", &["placeholder", "value", "type_name"]),
+            Self::ZeroValue => ("ZeroValue", &["placeholder", "value", "type_name"]),
+            Self::Include => ("Include", &["placeholder", "message"]),
+            Self::Validation => ("Validation", &["placeholder", "message"]),
+            Self::OutOfRange => ("OutOfRange", &["placeholder", "value", "constraint"]),
+            Self::UnexpectedInput => (
+                "UnexpectedInput",
+                &["expected_next_literal", "remaining_text"],
+            ),
+        }
+    }
+
+    /// Generates the expression creating the chumsky error that carries this error.
+    ///
+    /// # Parameters
+    /// - span: The expression of the span the error covers.
+    /// - values: The expressions of the values, one for each field of the `TemplateError` variant.
+    ///   They must produce values with a `replace` method, such as `&str` or `String`.
+    /// - colon_escaper: The method call escaping colons in the values.
+    ///
+    /// # Returns
+    /// An expression of type `chumsky::error::Rich<char>`, using the `chumsky` path in scope.
+    pub(crate) fn raise(
+        self,
+        span: proc_macro2::TokenStream,
+        values: &[proc_macro2::TokenStream],
+        colon_escaper: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let (_, fields) = self.variant();
+        debug_assert_eq!(values.len(), fields.len());
+        let format_string = format!("{}:{}", self.marker(), vec!["{}"; values.len()].join("::"));
+        quote! {
+            chumsky::error::Rich::<char>::custom(
+                #span,
+                format!(#format_string, #((#values).#colon_escaper,)*)
+            )
+        }
+    }
+
+    /// Generates the statement returning the `TemplateError` of a message carrying this error.
+    ///
+    /// # Parameters
+    /// - krate: The path of the templatia crate.
+    /// - replace_escaped_to_colon: The method call restoring the colons in the values.
+    fn generate_mapping(
+        self,
+        krate: &syn::Path,
+        replace_escaped_to_colon: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let marker = format!("{}:", self.marker());
+        let (variant, fields) = self.variant();
+        let variant = syn::Ident::new(variant, proc_macro2::Span::call_site());
+        let count = fields.len();
+        let fields = fields
+            .iter()
+            .map(|field| syn::Ident::new(field, proc_macro2::Span::call_site()));
+        let values = (0..count).map(|index| {
+            // The expected literal may be written quoted, which the error leaves out.
+            let value = if self == Self::UnexpectedInput && index == 0 {
+                quote! { values[#index].trim_matches('"') }
+            } else {
+                quote! { values[#index] }
+            };
+            quote! { #value.#replace_escaped_to_colon.to_string() }
+        });
+        quote! {
+            if let Some(rest) = m.strip_prefix(#marker) {
+                let values = rest.splitn(#count, "::").collect::<Vec<_>>();
+                if values.len() == #count {
+                    return Some(#krate::TemplateError::#variant {
+                        #(#fields: #values,)*
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Generates the closure mapping the chumsky errors raised as [`CustomError`] to `TemplateError`.
+///
+/// # Parameters
+/// - krate: The path of the templatia crate.
+/// - replace_escaped_to_colon: The method call restoring the colons escaped in the values.
+///
+/// # Returns
+/// A closure of type `Fn(&chumsky::error::Rich<char>) -> Option<TemplateError>`, which returns
+/// `None` for the errors of chumsky itself.
+pub(crate) fn generate_error_mapper(
+    krate: &syn::Path,
+    replace_escaped_to_colon: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let mappings = CustomError::ALL
+        .iter()
+        .map(|error| error.generate_mapping(krate, replace_escaped_to_colon));
+    quote! {
+        |err: &chumsky::error::Rich<char>| -> Option<#krate::TemplateError> {
+            if let #krate::__private::chumsky::error::RichReason::Custom(msg) = err.reason() {
+                let m = msg.to_string();
+                #(#mappings)*
+            }
+            None
+        }
+    }
+}
@@ -1,20 +1,44 @@
-use crate::error::generate_not_found_placeholder_compile_error;
-use crate::fields::{FieldKind, Fields};
+use crate::error::{
+    generate_nonzero_default_compile_error, generate_not_found_placeholder_compile_error,
+};
+use crate::fields::{FieldKind, Fields, OnDuplicate};
+use crate::inv::errors::CustomError;
 use crate::inv::parser::generate_parser_from_segments;
 use crate::inv::validator::validate_template_safety;
-use crate::parser::TemplateSegments;
+use crate::parser::{TemplateSegments, field_segments};
+use crate::render::{include_value, lossy_display};
+use crate::utils::{boxed_inner, is_nonzero_type, option_inner};
 use quote::quote;
 use std::collections::{HashMap, HashSet};
 
+/// Options of a generated parser that differ between the templates of a type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ParserOptions {
+    /// Whether fields missing from the template are filled with their defaults.
+    pub(crate) allow_missing_placeholders: bool,
+    /// Whether an empty capture of an `Option` field is parsed as `None`.
+    pub(crate) empty_str_as_none: bool,
+    /// Whether errors are emitted while parsing goes on with default values, for `#[templatia(lossy)]`.
+    pub(crate) lossy: bool,
+    /// Whether the template must reach the end of the input, which is false for `#[templatia(find)]`.
+    pub(crate) anchored: bool,
+}
+
 pub(crate) fn generate_str_parser(
     struct_name: &syn::Ident,
     fields: &Fields,
     placeholder_names: &HashSet<String>,
     segments: &[TemplateSegments],
-    allow_missing_placeholders: bool,
-    empty_str_as_none: bool,
+    options: ParserOptions,
     escaped_colon_marker: &str,
 ) -> proc_macro2::TokenStream {
+    let ParserOptions {
+        allow_missing_placeholders,
+        empty_str_as_none,
+        lossy,
+        anchored,
+    } = options;
+    let krate = fields.crate_path();
     for name in placeholder_names {
         if !fields.field_names().contains(name) {
             return generate_not_found_placeholder_compile_error(
@@ -29,23 +53,63 @@ pub(crate) fn generate_str_parser(
     }
 
     let replace_colon = quote! { replace(":", #escaped_colon_marker) };
-    let generated_full_parser =
-        generate_parser_from_segments(segments, fields, empty_str_as_none, &replace_colon);
+    let generated_full_parser = generate_parser_from_segments(
+        segments,
+        fields,
+        empty_str_as_none,
+        &replace_colon,
+        lossy,
+        anchored,
+    );
 
-    let field_names = segments
+    let field_segments = field_segments(segments);
+    let field_names = field_segments
         .iter()
-        .filter_map(|segment| match segment {
-            TemplateSegments::Placeholder(name) => {
-                Some(syn::Ident::new(name, proc_macro2::Span::call_site()))
-            }
+        .filter_map(|(segment, _)| {
+            segment
+                .field_name()
+                .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()))
+        })
+        .collect::<Vec<_>>();
+
+    let include_names = field_segments
+        .iter()
+        .filter_map(|(segment, _)| match segment {
+            TemplateSegments::Include(name) => Some(*name),
             _ => None,
         })
+        .collect::<HashSet<_>>();
+
+    // The parser folds the captured values into a flat tuple of Option slots, one slot per placeholder
+    // occurrence in the template order. The template can have a duplicate key, so the binding of each
+    // slot and the vec for the duplication checks are generated.
+    let (slot_bindings, dup_checks) = generate_slot_bindings(&field_names);
+    // SAFETY: The parser fills every slot outside optional groups in order before reaching the end of the input,
+    // so those slots are Some when the parser succeeds. Slots of an absent group are None.
+    let slot_unwraps = slot_bindings
+        .iter()
+        .zip(&field_segments)
+        .map(|(binding, (_, in_group))| {
+            if *in_group {
+                quote! { let #binding = #binding.flatten(); }
+            } else {
+                quote! { let #binding = #binding.unwrap(); }
+            }
+        })
         .collect::<Vec<_>>();
 
-    // The parser joined the left side so the parse result has a nested tuple adding left like
-    // (((#first, #second), #third), #forth)..., and getting it by pattern matching, generate the tuple.
-    // And also, the template can have a duplicate key so the vec for the duplication checks is also returned.
-    let (tuple_pattern, dup_checks) = generate_tuple_pattern(&field_names);
+    // With `on_duplicate = "last"`, the binding of the first occurrence is replaced by the later ones in order.
+    // Placeholders in optional groups can't repeat, so every occurrence here holds a value.
+    let dup_resolutions = dup_checks
+        .iter()
+        .filter(|_| fields.on_duplicate() == OnDuplicate::Last)
+        .map(|(base, dup, _)| quote! { let #base = #dup; })
+        .collect::<Vec<_>>();
+    let dup_checks = if fields.on_duplicate() == OnDuplicate::Error {
+        dup_checks
+    } else {
+        Vec::new()
+    };
 
     // Unique field names included in the template
     let unique_field_names_in_placeholder = placeholder_names
@@ -55,6 +119,11 @@ pub(crate) fn generate_str_parser(
 
     let (missing_placeholders_option, missing_placeholders_non_option) =
         fields.missing_placeholders_sep_opt(placeholder_names);
+    let (missing_placeholders_default, missing_placeholders_default_exprs): (Vec<_>, Vec<_>) =
+        fields
+            .missing_placeholders_with_default(placeholder_names)
+            .into_iter()
+            .unzip();
 
     // Even if the template has no all fields without allow_missing_placeholders,
     // it is passed if the missing_placeholders are Option<T> type
@@ -76,6 +145,19 @@ pub(crate) fn generate_str_parser(
         return error.to_compile_error();
     }
 
+    // The missing fields are filled with `Default::default()`, which `NonZero*` integers lack.
+    for ident in &missing_placeholders_non_option {
+        if let Some(FieldKind::Primitive(ty)) = fields.get_field_kind(ident)
+            && is_nonzero_type(ty)
+        {
+            return generate_nonzero_default_compile_error(
+                ident,
+                ty,
+                "because it is missing from the template",
+            );
+        }
+    }
+
     let struct_constructor = quote! {
         #struct_name {
             // #(#Awesome,)* will be expanded to #Awesome, #Awesome, #Awesome <- This is the correct behavior.
@@ -87,6 +169,7 @@ pub(crate) fn generate_str_parser(
             #(#unique_field_names_in_placeholder,)*
             #(#missing_placeholders_non_option: Default::default(),)*
             #(#missing_placeholders_option: None,)*
+            #(#missing_placeholders_default: #missing_placeholders_default_exprs,)*
         }
     };
 
@@ -97,100 +180,257 @@ pub(crate) fn generate_str_parser(
     // all duplicate placeholders must be checked.
     // If there are N duplicate placeholders, this comparison approach is O(N).
     // Using dynamic comparison does not appear to reduce this complexity.
-    let dup_conditions = dup_checks
-        .iter()
-        .map(|(base, dup, _)| quote! { #dup != #base });
+    let dup_conditions = dup_checks.iter().map(|(base, dup, name)| {
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        // `BinaryHeap` has no `PartialEq`, so heaps are compared by their sorted values.
+        if let Some(FieldKind::BinaryHeap(_)) = fields.get_field_kind(&ident) {
+            return quote! {
+                {
+                    let mut base = #base.iter().collect::<Vec<_>>();
+                    let mut dup = #dup.iter().collect::<Vec<_>>();
+                    base.sort();
+                    dup.sort();
+                    dup != base
+                }
+            };
+        }
+        quote! { #dup != #base }
+    });
     let dup_names = dup_checks.iter().map(|(_, _, name)| {
+        let name = fields.placeholder_name(&syn::Ident::new(name, proc_macro2::Span::call_site()));
         quote! { #name }
     });
 
     let dup_bases = dup_checks.iter().map(|(base, _, name)| {
         let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        let display = lossy_display(fields, &ident);
+        if include_names.contains(name.as_str()) {
+            return include_value(fields, &ident, quote! { #base });
+        }
+        if let Some(with) = fields.get_with(&ident) {
+            return quote! { #with::render(&#base) };
+        }
+        if let Some(FieldKind::Option(ty)) = fields.get_field_kind(&ident)
+            && boxed_inner(ty).is_some()
+        {
+            return quote! {
+                #base
+                    .as_ref()
+                    .map(|v| #krate::RenderTemplate::render_string(&**v))
+                    .unwrap_or_default()
+            };
+        }
         match fields.get_field_kind(&ident) {
             Some(FieldKind::Option(_)) => quote! {
                 #base
                     .as_ref()
-                    .map(|v| v.to_string())
+                    .map(|v| v #display .to_string())
                     .unwrap_or_default()
             },
-            Some(FieldKind::Vec(_)) | Some(FieldKind::BTreeSet(_)) => quote! {
+            Some(FieldKind::Vec(ty))
+            | Some(FieldKind::VecDeque(ty))
+            | Some(FieldKind::LinkedList(ty))
+            | Some(FieldKind::SmallVec(ty))
+            | Some(FieldKind::ArrayVec(ty))
+                if option_inner(ty).is_some() =>
+            {
+                quote! {
+                    #base
+                        .iter()
+                        .map(|v| v.as_ref().map(|v| v #display .to_string()).unwrap_or_default())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }
+            }
+            Some(FieldKind::Vec(_))
+            | Some(FieldKind::BTreeSet(_))
+            | Some(FieldKind::VecDeque(_))
+            | Some(FieldKind::LinkedList(_))
+            | Some(FieldKind::IndexSet(_))
+            | Some(FieldKind::SmallVec(_))
+            | Some(FieldKind::ArrayVec(_)) => quote! {
                 #base
                     .iter()
-                    .map(|v| v.to_string())
+                    .map(|v| v #display .to_string())
                     .collect::<Vec<_>>()
                     .join(",")
             },
-            Some(FieldKind::HashSet(_)) => quote! {
+            Some(FieldKind::HashSet(_)) | Some(FieldKind::BinaryHeap(_)) => quote! {
                 #base
                     .iter()
-                    .map(|v| v.to_string())
+                    .map(|v| v #display .to_string())
                     .collect::<::std::collections::BTreeSet<_>>()
                     .into_iter()
                     .collect::<Vec<_>>()
                     .join(",")
             },
-            _ => quote! { #base },
+            Some(FieldKind::Result(..)) => {
+                let (ok_prefix, err_prefix) = fields.get_result_prefixes(&ident);
+                quote! {
+                    match &#base {
+                        Ok(v) => format!("{}{}", #ok_prefix, v),
+                        Err(e) => format!("{}{}", #err_prefix, e),
+                    }
+                }
+            }
+            Some(FieldKind::HashMap(..))
+            | Some(FieldKind::BTreeMap(..))
+            | Some(FieldKind::IndexMap(..)) => quote! {
+                #base
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect::<::std::collections::BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+                    .join(",")
+            },
+            _ => quote! { #base #display },
         }
     });
     let dup_dups = dup_checks.iter().map(|(_, dup, name)| {
         let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        let display = lossy_display(fields, &ident);
+        if include_names.contains(name.as_str()) {
+            return include_value(fields, &ident, quote! { #dup });
+        }
+        if let Some(with) = fields.get_with(&ident) {
+            return quote! { #with::render(&#dup) };
+        }
+        if let Some(FieldKind::Option(ty)) = fields.get_field_kind(&ident)
+            && boxed_inner(ty).is_some()
+        {
+            return quote! {
+                #dup
+                    .as_ref()
+                    .map(|v| #krate::RenderTemplate::render_string(&**v))
+                    .unwrap_or_default()
+            };
+        }
 
         match fields.get_field_kind(&ident) {
             Some(FieldKind::Option(_)) => quote! {
                 #dup
                     .as_ref()
-                    .map(|v| v.to_string())
+                    .map(|v| v #display .to_string())
                     .unwrap_or_default()
             },
-            Some(FieldKind::Vec(_)) | Some(FieldKind::BTreeSet(_)) => quote! {
+            Some(FieldKind::Vec(ty))
+            | Some(FieldKind::VecDeque(ty))
+            | Some(FieldKind::LinkedList(ty))
+            | Some(FieldKind::SmallVec(ty))
+            | Some(FieldKind::ArrayVec(ty))
+                if option_inner(ty).is_some() =>
+            {
+                quote! {
+                    #dup
+                        .iter()
+                        .map(|v| v.as_ref().map(|v| v #display .to_string()).unwrap_or_default())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                }
+            }
+            Some(FieldKind::Vec(_))
+            | Some(FieldKind::BTreeSet(_))
+            | Some(FieldKind::VecDeque(_))
+            | Some(FieldKind::LinkedList(_))
+            | Some(FieldKind::IndexSet(_))
+            | Some(FieldKind::SmallVec(_))
+            | Some(FieldKind::ArrayVec(_)) => quote! {
                 #dup
                     .iter()
-                    .map(|v| v.to_string())
+                    .map(|v| v #display .to_string())
                     .collect::<Vec<_>>()
                     .join(",")
             },
-            Some(FieldKind::HashSet(_)) => quote! {
+            Some(FieldKind::HashSet(_)) | Some(FieldKind::BinaryHeap(_)) => quote! {
                 #dup
                     .iter()
-                    .map(|v| v.to_string())
+                    .map(|v| v #display .to_string())
                     .collect::<::std::collections::BTreeSet<_>>()
                     .into_iter()
                     .collect::<Vec<_>>()
                     .join(",")
             },
-            _ => quote! { #dup },
+            Some(FieldKind::Result(..)) => {
+                let (ok_prefix, err_prefix) = fields.get_result_prefixes(&ident);
+                quote! {
+                    match &#dup {
+                        Ok(v) => format!("{}{}", #ok_prefix, v),
+                        Err(e) => format!("{}{}", #err_prefix, e),
+                    }
+                }
+            }
+            Some(FieldKind::HashMap(..))
+            | Some(FieldKind::BTreeMap(..))
+            | Some(FieldKind::IndexMap(..)) => quote! {
+                #dup
+                    .iter()
+                    .map(|(k, v)| format!("{}={}", k, v))
+                    .collect::<::std::collections::BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+                    .join(",")
+            },
+            _ => quote! { #dup #display },
         }
     });
 
-    let final_parser = quote! {
-        #generated_full_parser
-            .try_map(|#tuple_pattern, span| {
-            #(
-                if #dup_conditions {
-                    return Err(::templatia::__private::chumsky::error::Rich::custom(
-                        span,
-                        format!(
-                            "__templatia_conflict__:{}::{}::{}",
-                            #dup_names.#replace_colon,
-                            #dup_bases.to_string().#replace_colon,
-                            #dup_dups.to_string().#replace_colon,
-                        )
-                    ));
-                }
-            )*
-            Ok(#struct_constructor)
-        })
+    // In the lossy mode, conflicts are emitted and the first occurrence is kept instead of failing.
+    let report_conflict = if lossy {
+        quote! { emitter.emit }
+    } else {
+        quote! { return Err }
     };
-
-    final_parser
+    let conflict_errors = dup_names
+        .zip(dup_bases)
+        .zip(dup_dups)
+        .map(|((name, base), dup)| {
+            CustomError::InconsistentValues.raise(
+                quote! { span },
+                &[
+                    quote! { #name },
+                    quote! { #base.to_string() },
+                    quote! { #dup.to_string() },
+                ],
+                &replace_colon,
+            )
+        });
+    let conflict_checks = quote! {
+        #(
+            if #dup_conditions {
+                #report_conflict(#conflict_errors);
+            }
+        )*
+    };
+    if lossy {
+        quote! {
+            #generated_full_parser
+                .validate(|state, extra, emitter| {
+                let span = extra.span();
+                let (#(#slot_bindings,)*) = state;
+                #(#slot_unwraps)*
+                #(#dup_resolutions)*
+                #conflict_checks
+                #struct_constructor
+            })
+        }
+    } else {
+        quote! {
+            #generated_full_parser
+                .try_map(|state, span| {
+                let (#(#slot_bindings,)*) = state;
+                #(#slot_unwraps)*
+                #(#dup_resolutions)*
+                #conflict_checks
+                Ok(#struct_constructor)
+            })
+        }
+    }
 }
 
-fn generate_tuple_pattern(
+fn generate_slot_bindings(
     field_names: &[syn::Ident],
-) -> (
-    proc_macro2::TokenStream,
-    Vec<(syn::Ident, syn::Ident, String)>,
-) {
+) -> (Vec<syn::Ident>, Vec<(syn::Ident, syn::Ident, String)>) {
     let mut first_binds: HashMap<String, syn::Ident> = HashMap::new();
     let mut dup_checks: Vec<(syn::Ident, syn::Ident, String)> = Vec::new();
 
@@ -217,28 +457,7 @@ fn generate_tuple_pattern(
         }
     };
 
-    let tuple_pat = if !field_names.is_empty() {
-        let mut pattern_iter = field_names.iter();
-        if field_names.len() > 1 {
-            // SAFETY: In this branch, the condition is field_names.len() > 1, so the first, second must be success.
-            let first = key_generator(pattern_iter.next().unwrap());
-            let second = key_generator(pattern_iter.next().unwrap());
-
-            let mut current_pattern = quote! { (#first, #second) };
-
-            for next_field in pattern_iter {
-                let next_field = key_generator(next_field);
-                current_pattern = quote! { (#current_pattern, #next_field) };
-            }
-            current_pattern
-        } else {
-            // SAFETY: In this branch, the field_names is not empty and not len() > 1 so the len() must be 1.
-            let first = pattern_iter.next().unwrap();
-            quote! { #first }
-        }
-    } else {
-        quote! { _ }
-    };
+    let slot_bindings = field_names.iter().map(&mut key_generator).collect();
 
-    (tuple_pat, dup_checks)
+    (slot_bindings, dup_checks)
 }
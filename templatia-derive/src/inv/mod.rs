@@ -1,3 +1,4 @@
+pub(crate) mod errors;
 pub mod generator;
 pub(crate) mod parser;
 mod validator;
@@ -1,185 +1,1392 @@
 use crate::error::generate_unsupported_compile_error;
-use crate::fields::{FieldKind, Fields};
-use crate::parser::TemplateSegments;
-use crate::utils::get_type_name;
+use crate::fields::{FieldKind, Fields, MatchStrategy};
+use crate::inv::errors::CustomError;
+use crate::parser::{Filter, PlaceholderSpec, RangeBounds, TemplateSegments, field_segments};
+use crate::utils::{
+    boxed_inner, get_type_name, is_borrowed_str, is_full_path, is_integer_type, is_nonzero_type,
+    option_inner,
+};
 use quote::quote;
 use std::collections::HashMap;
 
+/// The number of segments chained before the parser is boxed.
+/// Boxing erases the combinator type so its nesting depth doesn't grow with the template width.
+const BOX_INTERVAL: usize = 8;
+
+/// How the text of a placeholder is captured from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Capture {
+    /// Up to the first stop literal, which is the default.
+    Lazy,
+    /// Up to the occurrence of the stop literal that leaves the given number of its occurrences,
+    /// including that one, in the rest of the input, for `#[templatia(match = "greedy")]`.
+    Greedy(usize),
+    /// Exactly the given number of characters, for `#[templatia(width = N)]`.
+    FixedWidth(usize),
+    /// Up to the first stop literal that isn't preceded by the escape character,
+    /// for `#[templatia(escape = '...')]`.
+    Escaped(char),
+    /// A double-quoted token with backslash escapes regardless of the stop literals,
+    /// for `#[templatia(quoted)]`. The flag tells whether the token may be absent for `Option` fields.
+    Quoted(bool),
+    /// All remaining input including newlines and literals, for `#[templatia(rest)]`.
+    Rest,
+    /// Up to the first stop literal or line break, for `#[templatia(until_eol)]`.
+    UntilEol,
+    /// ASCII digits after an optional sign, for a placeholder with an integer type hint like `{count:u32}`.
+    /// The flag tells whether a `-` sign is accepted.
+    Digits(bool),
+}
+
+impl Capture {
+    /// Returns the capture of a field parsed out of the text already captured for it,
+    /// where a greedy match has no literal to look for.
+    fn within_capture(self) -> Self {
+        match self {
+            Capture::Greedy(_) => Capture::Lazy,
+            capture => capture,
+        }
+    }
+}
+
+/// The literals that can terminate a placeholder capture.
+struct Stops {
+    /// The spellings of the literals, including the alias variants.
+    literals: Vec<String>,
+    /// How the literals are matched.
+    style: LiteralStyle,
+    /// The characters that may be left at the end of the input, which end a capture without literals.
+    end_blanks: Option<&'static str>,
+}
+
+impl Stops {
+    /// Collects the stop literals of a placeholder followed by `rest`. See [`stop_literals`].
+    fn new(rest: &[TemplateSegments], following: &[String], fields: &Fields) -> Self {
+        Self {
+            literals: stop_literals(rest, following, fields),
+            style: LiteralStyle::of(fields),
+            end_blanks: fields.end_blanks(),
+        }
+    }
+
+    /// Takes no stop literals, for a field parsed out of the text already captured for it.
+    fn within_capture(fields: &Fields) -> Self {
+        Self {
+            literals: Vec::new(),
+            style: LiteralStyle::of(fields),
+            end_blanks: fields.end_blanks(),
+        }
+    }
+
+    /// Generates the parser matching any of the stop literals, or `None` when there are none.
+    /// Without literals, it matches the blanks allowed at the end of the input instead if any.
+    fn matcher(&self) -> Option<proc_macro2::TokenStream> {
+        if let Some(end_blanks) = self.end_blanks.filter(|_| self.literals.is_empty()) {
+            return Some(quote! {
+                one_of::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#end_blanks)
+                    .repeated()
+                    .then(end())
+                    .to_slice()
+            });
+        }
+        let (first, rest) = self.literals.split_first()?;
+        let first = literal_matcher(first, self.style);
+        let rest = rest.iter().map(|lit| literal_matcher(lit, self.style));
+        Some(quote! { #first #(.or(#rest))* })
+    }
+
+    /// Generates the parser matching the first stop literal, or `None` when there are none.
+    fn first_matcher(&self) -> Option<proc_macro2::TokenStream> {
+        self.literals
+            .first()
+            .map(|lit| literal_matcher(lit, self.style))
+    }
+}
+
+/// How the literals of the template are matched against the input.
+#[derive(Debug, Clone, Copy)]
+struct LiteralStyle {
+    /// Whether runs of spaces and tabs in and around the literals are matched loosely.
+    loose: bool,
+    /// Whether a line break matches both `\n` and `\r\n`.
+    any_newline: bool,
+}
+
+impl LiteralStyle {
+    /// Takes the style from the container options of the fields.
+    fn of(fields: &Fields) -> Self {
+        Self {
+            loose: fields.is_loose_whitespace(),
+            any_newline: fields.is_any_newline(),
+        }
+    }
+
+    /// Generates the parser of a line break.
+    fn newline(self) -> proc_macro2::TokenStream {
+        if self.any_newline {
+            quote! { just("\r\n").or(just("\n")) }
+        } else {
+            quote! { just("\n") }
+        }
+    }
+}
+
+/// Generates the parser of a literal producing the matched slice.
+///
+/// In the loose mode of `#[templatia(loose_whitespace)]`, any run of spaces and tabs may appear
+/// around the literal, and each run of spaces and tabs in the literal matches any run. A run may be
+/// empty unless it keeps words apart, such as the runs of `" and "` or the one in `"max size"`.
+/// With `#[templatia(newline = "...")]`, each line break matches both `\n` and `\r\n`.
+fn literal_matcher(lit: &str, style: LiteralStyle) -> proc_macro2::TokenStream {
+    let lit_owned;
+    let lit = if style.any_newline {
+        lit_owned = lit.replace("\r\n", "\n");
+        lit_owned.as_str()
+    } else {
+        lit
+    };
+    let splits_lines = style.any_newline && lit.contains('\n');
+    if !(style.loose || splits_lines) {
+        return quote! {
+            just::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#lit)
+        };
+    }
+    let newline = style.newline();
+    if !style.loose {
+        let mut parser =
+            quote! { empty::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>() };
+        for (index, line) in lit.split('\n').enumerate() {
+            if index > 0 {
+                parser = quote! { #parser.then_ignore(#newline) };
+            }
+            if !line.is_empty() {
+                parser = quote! { #parser.then_ignore(just(#line)) };
+            }
+        }
+        return quote! { #parser.to_slice() };
+    }
+
+    let is_blank = |c: char| c == ' ' || c == '\t';
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let spaces = |at_least: usize| {
+        quote! {
+            one_of::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(" \t")
+                .repeated()
+                .at_least(#at_least)
+        }
+    };
+
+    let mut parser = quote! { empty::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>() };
+    let mut rest = lit;
+    let mut previous: Option<char> = None;
+    while !rest.is_empty() {
+        let blank_len = rest.len() - rest.trim_start_matches(is_blank).len();
+        let (blank, after_blank) = rest.split_at(blank_len);
+        // A run is required where it keeps words apart: between two words in the literal, or between
+        // a word at the edge of the literal and the value next to it. Other runs may be empty.
+        let next = after_blank.chars().next();
+        let required = match (previous, next) {
+            (None, _) => is_word(next),
+            (_, None) => is_word(previous),
+            _ => is_word(previous) && is_word(next),
+        };
+        let at_least = usize::from(!blank.is_empty() && required);
+        let run = spaces(at_least);
+        parser = quote! { #parser.then_ignore(#run) };
+
+        let word_len = after_blank.len() - after_blank.trim_start_matches(|c| !is_blank(c)).len();
+        let (word, after_word) = after_blank.split_at(word_len);
+        // Line breaks are matched on their own so that the next line may be indented.
+        for (index, line) in word.split('\n').enumerate() {
+            if index > 0 {
+                let run = spaces(0);
+                parser = quote! { #parser.then_ignore(#newline).then_ignore(#run) };
+            }
+            if !line.is_empty() {
+                parser = quote! { #parser.then_ignore(just(#line)) };
+            }
+        }
+        if !word.is_empty() {
+            previous = word.chars().last();
+        }
+        rest = after_word;
+    }
+    if lit.is_empty() || !lit.ends_with(is_blank) {
+        let run = spaces(0);
+        parser = quote! { #parser.then_ignore(#run) };
+    }
+
+    quote! { #parser.to_slice() }
+}
+
+/// Generates a parser that folds the captured values into a flat tuple of `Option` slots.
+///
+/// # Parameters
+/// - segments: The template segments to parse.
+/// - fields: The fields of the struct.
+/// - empty_str_as_none: Whether an empty string is parsed as `None` for `Option<T>` fields.
+/// - colon_escaper: The method call escaping colons in error messages.
+/// - lossy: Whether a value failing to parse is reported and replaced by its default for `#[templatia(lossy)]`.
+/// - anchored: Whether the template must reach the end of the input. Otherwise the rest is ignored.
+///
+/// # Returns
+/// A parser producing `(Option<T0>, Option<T1>, ...)` where each slot corresponds to a placeholder
+/// occurrence in the template, in order, including the placeholders inside optional groups.
+/// All slots outside optional groups are `Some` when the parser succeeds.
+///
+/// # Notes
+/// - Chaining `then` for every placeholder produces nested tuples like `(((a, b), c), d)` whose type
+///   depth grows with the number of placeholders. Folding into a flat state keeps the output type flat
+///   and the periodic boxing keeps the combinator type shallow, so wide structs compile quickly.
 pub(crate) fn generate_parser_from_segments(
     segments: &[TemplateSegments],
     fields: &Fields,
     empty_str_as_none: bool,
     colon_escaper: &proc_macro2::TokenStream,
+    lossy: bool,
+    anchored: bool,
+) -> proc_macro2::TokenStream {
+    let parser = generate_sequence_parser(
+        segments,
+        &[],
+        fields,
+        empty_str_as_none,
+        colon_escaper,
+        lossy,
+    );
+
+    // The blanks allowed at the end of the input are allowed at its start as well, where the
+    // template may begin with a placeholder that has no literal to skip them.
+    let parser = match fields.end_blanks() {
+        Some(end_blanks) => quote! { one_of(#end_blanks).repeated().ignore_then(#parser) },
+        None => parser,
+    };
+    if fields.is_allow_trailing() || !anchored {
+        return quote! { #parser.then_ignore(any().repeated()) };
+    }
+    // Input left after the template is reported as an empty literal that wasn't found.
+    let trailing_input = CustomError::UnexpectedInput.raise(
+        quote! { e.span().clone() },
+        &[quote! { "" }, quote! { &s[e.span().start..] }],
+        colon_escaper,
+    );
+    let end = quote! { end().map_err(|e: chumsky::error::Rich<char>| #trailing_input) };
+    if let Some(end_blanks) = fields.end_blanks() {
+        return quote! { #parser.then_ignore(one_of(#end_blanks).repeated()).then_ignore(#end) };
+    }
+    quote! { #parser.then_ignore(#end) }
+}
+
+/// Generates the folding parser of a sequence of segments without requiring the end of input.
+///
+/// `following` holds the literals that may come right after this sequence, which terminate
+/// a placeholder at the end of the sequence (e.g., the literal after an optional group).
+fn generate_sequence_parser(
+    segments: &[TemplateSegments],
+    following: &[String],
+    fields: &Fields,
+    empty_str_as_none: bool,
+    colon_escaper: &proc_macro2::TokenStream,
+    lossy: bool,
 ) -> proc_macro2::TokenStream {
-    let mut peekable_segments = segments.iter().peekable();
-    let mut parser = quote! { ::templatia::__private::chumsky::prelude::empty() };
+    // SAFETY: The placeholders are always in the fields because in the first of the generate_str_parser,
+    // the placeholders are checked if they are in the fields.
+    let slot_types = field_segments(segments)
+        .into_iter()
+        .filter_map(|(segment, _)| segment.field_name())
+        .map(|name| {
+            let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+            fields.get_field_type(&ident).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let mut parser = quote! {
+        empty::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>()
+            .map(|_| (#(None::<#slot_types>,)*))
+    };
 
-    let mut is_first_segment = true;
-    let mut is_passed_first_placeholder = false;
-    let mut latest_segment_was_literal = false;
+    let mut slot_index = 0usize;
+    let mut chained_segments = 0usize;
 
     let mut literals_counters = HashMap::new();
     let mut last_literal_parsed: &str = "";
     let mut last_literal_count: i32 = -1;
 
-    while let Some(segment) = peekable_segments.next() {
+    for (index, segment) in segments.iter().enumerate() {
+        let rest = &segments[index + 1..];
         match segment {
-            TemplateSegments::Literal(lit) => {
+            TemplateSegments::Literal(_) | TemplateSegments::Alternation(_) => {
+                let (lit, spellings) = literal_spellings(segment, rest.first(), fields);
                 let count = *literals_counters
                     .entry(lit)
                     .and_modify(|count| *count += 1)
                     .or_insert(1);
 
-                parser = if is_first_segment {
-                    quote! {
-                        just::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#lit)
-                            .ignored()
-                    }
-                } else {
-                    quote! { #parser.then_ignore(just(#lit)) }
-                };
-
+                let style = LiteralStyle::of(fields);
+                // SAFETY: A literal has at least its own spelling, and an alternation has at least two.
+                let (first, others) = spellings.split_first().unwrap();
+                let literal = literal_matcher(first, style);
+                let aliases = others.iter().map(|alias| literal_matcher(alias, style));
+                let unexpected_input = CustomError::UnexpectedInput.raise(
+                    quote! { e.span().clone() },
+                    &[quote! { #lit }, quote! { &s[start..] }],
+                    colon_escaper,
+                );
                 parser = quote! {
-                    #parser
-                        .map_err(|e| {
+                    #parser.then_ignore(
+                        #literal #(.or(#aliases))*.map_err(|e: chumsky::error::Rich<char>| {
                             let start = match e.found() {
                                 Some(_) => {
                                     e.span().start
                                 },
                                 None => {
                                     if #last_literal_count > 0 {
-                                        let found_lit = s.match_indices(#last_literal_parsed).collect::<Vec<_>>();
-                                        // SAFETY: In this branch, the last_literal_count is always 1 or more. So, the #last_literal_count - 1 is always converted to usize.
-                                        // Also, the last_literal_parsed and last_literal_count indicate **last**, so in this branch executed,
-                                        // the last literal is parsed, so the index(last_literal_count - 1) is always less than the length of the s.match_indices(#last_literal_parsed).collect::<Vec<_>>().
-                                        // Therefore, the following code never causes an out-of-range panic.
-                                        let (last_indices, _) = found_lit[(#last_literal_count - 1) as usize];
-                                        last_indices + #last_literal_parsed.len()
+                                        // The input ended before this literal, so the remaining text starts
+                                        // right after the previous literal. Fall back to the start if it can't be found.
+                                        s.match_indices(#last_literal_parsed)
+                                            .nth((#last_literal_count - 1) as usize)
+                                            .map(|(last_indices, _)| last_indices + #last_literal_parsed.len())
+                                            .unwrap_or(0usize)
                                     } else {
                                         0usize
                                     }
                                 }
                             };
 
-                            chumsky::error::Rich::<char>::custom(
-                                e.span().clone(),
-                                // SAFETY: The start is 0 or index from the s. Therefore, this isn't an out of range.
-                                format!("__templatia_parse_literal__:{}::{}",
-                                    #lit.#colon_escaper,
-                                    &s[start..].#colon_escaper,
-                                )
-                            )
+                            // SAFETY: The start is 0 or index from the s. Therefore, this isn't an out of range.
+                            #unexpected_input
                         })
+                    )
                 };
 
-                latest_segment_was_literal = true;
                 last_literal_parsed = lit;
                 last_literal_count = count;
             }
-            TemplateSegments::Placeholder(placeholder) => {
+            TemplateSegments::Placeholder(placeholder, _)
+            | TemplateSegments::Include(placeholder) => {
                 let name_ident = syn::Ident::new(placeholder, proc_macro2::Span::call_site());
+                // Errors report the name written in the template, which differs from the field name when renamed.
+                let placeholder_name = fields.placeholder_name(&name_ident);
+
+                let build = |stops: &Stops, capture: Capture| {
+                    if let TemplateSegments::Include(_) = segment {
+                        // SAFETY: The included field is always in the fields because in the first of the generate_str_parser,
+                        // the placeholder is checked if it is in the fields.
+                        let field_type = fields.get_field_type(&name_ident).unwrap();
+                        // An included sequence is split on its separator, and each element is parsed by its template.
+                        let element = fields
+                            .get_field_kind(&name_ident)
+                            .and_then(FieldKind::sequence_element)
+                            .map(|ty| (ty, fields.get_separator(&name_ident)));
+
+                        generate_include_parser(
+                            &placeholder_name,
+                            field_type,
+                            element,
+                            fields.crate_path(),
+                            stops,
+                            colon_escaper,
+                        )
+                    } else {
+                        // SAFETY: The placeholder is always in the fields because in the first of the generate_str_parser,
+                        // the placeholder is checked if it is in the fields.
+                        let field_kind = fields.get_field_kind(&name_ident).unwrap();
+
+                        if fields.is_skip_parse(&name_ident) {
+                            generate_skip_parser(&name_ident, field_kind, stops, capture, fields)
+                        } else {
+                            // With filters or a heredoc terminator, the value is parsed out of the captured text
+                            // once the filters are undone.
+                            let (filters, terminator, separator, range) = match segment {
+                                TemplateSegments::Placeholder(_, spec) => (
+                                    spec.filters(),
+                                    spec.terminator,
+                                    spec.separator,
+                                    spec.range(),
+                                ),
+                                _ => (Vec::new(), None, None, None),
+                            };
+                            let nested = !filters.is_empty() || terminator.is_some();
+                            let within_capture = Stops::within_capture(fields);
+                            let (value_stops, value_capture) = if nested {
+                                (&within_capture, capture.within_capture())
+                            } else {
+                                (stops, capture)
+                            };
+                            let field_parser = match fields.get_with(&name_ident) {
+                                Some(with) => generate_with_parser(
+                                    &placeholder_name,
+                                    &field_kind.to_string(),
+                                    with,
+                                    value_stops,
+                                    value_capture,
+                                    fields.is_trim(&name_ident),
+                                    colon_escaper,
+                                ),
+                                None => generate_field_parser(
+                                    &name_ident,
+                                    separator.unwrap_or_else(|| fields.get_separator(&name_ident)),
+                                    value_stops,
+                                    value_capture,
+                                    fields,
+                                    empty_str_as_none,
+                                    colon_escaper,
+                                ),
+                            };
+                            let field_parser = if nested {
+                                let captured = match terminator {
+                                    Some(terminator) => generate_heredoc_parser(terminator),
+                                    None => generate_str_parser(stops, capture),
+                                };
+                                generate_filtered_parser(
+                                    captured,
+                                    &filters,
+                                    fields.crate_path(),
+                                    field_parser,
+                                )
+                            } else {
+                                field_parser
+                            };
+
+                            let field_parser = generate_range_parser(
+                                &name_ident,
+                                field_kind,
+                                range,
+                                fields,
+                                field_parser,
+                                colon_escaper,
+                            );
+
+                            let field_parser = match fields.get_default(&name_ident) {
+                                Some(default) => {
+                                    generate_default_parser(stops, default, field_parser)
+                                }
+                                None => field_parser,
+                            };
 
-                // SAFETY: The placeholder is always in the fields because in the first of the generate_str_parser,
-                // the placeholder is checked if it is in the fields.
-                let field_kind = fields.get_field_kind(&name_ident).unwrap();
+                            match fields.get_validate(&name_ident) {
+                                Some(validate) => generate_validate_parser(
+                                    &placeholder_name,
+                                    validate,
+                                    field_parser,
+                                    colon_escaper,
+                                ),
+                                None => field_parser,
+                            }
+                        }
+                    }
+                };
+                let stops = Stops::new(rest, following, fields);
+                let capture = match (segment, scans_digits(segment, fields)) {
+                    (TemplateSegments::Include(_), _) => Capture::Lazy,
+                    (_, Some(signed)) => Capture::Digits(signed),
+                    _ => capture_of(&name_ident, rest, fields),
+                };
+                let field_parser = if lossy && !fields.is_skip_parse(&name_ident) {
+                    // The whole capture is taken first, and then the field is parsed out of it on its own.
+                    let inner = build(&Stops::within_capture(fields), capture.within_capture());
+                    generate_lossy_parser(&stops, capture, &name_ident, fields, inner)
+                } else {
+                    build(&stops, capture)
+                };
+                let field_parser = match segment {
+                    TemplateSegments::Placeholder(
+                        _,
+                        PlaceholderSpec {
+                            default: Some(default),
+                            ..
+                        },
+                    ) => {
+                        generate_inline_default_parser(
+                            &stops,
+                            &placeholder_name,
+                            default,
+                            // SAFETY: The placeholder is always in the fields, as checked at the start of generate_str_parser.
+                            fields.get_field_kind(&name_ident).unwrap(),
+                            fields.crate_path(),
+                            field_parser,
+                            colon_escaper,
+                        )
+                    }
+                    _ => field_parser,
+                };
 
-                let field_parser = generate_field_parser(
-                    &name_ident,
-                    field_kind,
-                    peekable_segments.peek().cloned(),
+                let slot = syn::Index::from(slot_index);
+                parser = quote! {
+                    #parser
+                        .then(#field_parser)
+                        .map(|(mut state, value)| {
+                            state.#slot = Some(value);
+                            state
+                        })
+                };
+                slot_index += 1;
+            }
+            TemplateSegments::Group(inner) => {
+                let group_parser = generate_sequence_parser(
+                    inner,
+                    &stop_literals(rest, following, fields),
+                    fields,
                     empty_str_as_none,
                     colon_escaper,
+                    lossy,
                 );
+                let assigns = (0..field_segments(inner).len())
+                    .map(|inner_index| {
+                        let outer = syn::Index::from(slot_index + inner_index);
+                        let inner = syn::Index::from(inner_index);
+                        quote! { state.#outer = group.#inner; }
+                    })
+                    .collect::<Vec<_>>();
+                slot_index += field_segments(inner).len();
 
-                if is_first_segment {
-                    parser = field_parser;
-                } else if !is_passed_first_placeholder && latest_segment_was_literal {
-                    parser = quote! { #parser.ignore_then(#field_parser) };
+                // An absent group leaves its slots as None, which become None fields.
+                parser = quote! {
+                    #parser
+                        .then(#group_parser.or_not())
+                        .map(|(mut state, group)| {
+                            if let Some(group) = group {
+                                #(#assigns)*
+                            }
+                            state
+                        })
+                };
+            }
+            TemplateSegments::Block(name, body) => {
+                let block_parser = generate_block_parser(name, body, fields, colon_escaper);
+                let slot = syn::Index::from(slot_index);
+                parser = quote! {
+                    #parser
+                        .then(#block_parser)
+                        .map(|(mut state, value)| {
+                            state.#slot = Some(value);
+                            state
+                        })
+                };
+                slot_index += 1;
+            }
+            // The current value is matched first, which may contain the following literal. The environment
+            // may differ from the one the input was rendered with, so otherwise any text is skipped.
+            TemplateSegments::Env(variable) => {
+                let skipped =
+                    generate_str_parser(&Stops::new(rest, following, fields), Capture::Lazy);
+                parser = quote! {
+                    #parser.then_ignore({
+                        let value = ::std::env::var(#variable).unwrap_or_default();
+                        any::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>()
+                            .repeated()
+                            .exactly(value.chars().count())
+                            .to_slice()
+                            .filter(move |s: &&str| !value.is_empty() && *s == value)
+                            .or(#skipped)
+                    })
+                };
+            }
+            // A lookahead only checks the text ahead, which the next segment parses again.
+            TemplateSegments::Lookahead(text, negated) => {
+                let matcher = literal_matcher(text, LiteralStyle::of(fields));
+                let unexpected_input = CustomError::UnexpectedInput.raise(
+                    quote! { e.span().clone() },
+                    &[quote! { #text }, quote! { &s[e.span().start..] }],
+                    colon_escaper,
+                );
+                parser = if *negated {
+                    quote! { #parser.then_ignore(#matcher.not()) }
                 } else {
-                    parser = quote! { #parser.then(#field_parser) };
+                    quote! {
+                        #parser.then_ignore(#matcher.rewind().map_err(|e: chumsky::error::Rich<char>| {
+                            #unexpected_input
+                        }))
+                    }
+                };
+            }
+            // The unknown `key = value` lines were taken out of the input before parsing.
+            TemplateSegments::Extras(_) => {
+                let slot = syn::Index::from(slot_index);
+                parser = quote! {
+                    #parser.map(|mut state| {
+                        state.#slot = Some(__templatia_extras.iter().cloned().collect());
+                        state
+                    })
+                };
+                slot_index += 1;
+            }
+        }
+
+        chained_segments += 1;
+        if chained_segments == BOX_INTERVAL {
+            parser = quote! { #parser.boxed() };
+            chained_segments = 0;
+        }
+    }
+
+    parser
+}
+
+/// Generates the parser of a `{#field}...{/field}` block producing the `Vec` of its elements.
+///
+/// Each element matches the body, whose placeholders capture up to the following literal of the
+/// body. The captures are parsed with `FromStr` into the fields of the element, whose types are
+/// inferred from the struct expression. The elements repeat until the body no longer matches.
+fn generate_block_parser(
+    name: &str,
+    body: &[TemplateSegments],
+    fields: &Fields,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let krate = fields.crate_path();
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    // SAFETY: The validator checked that the block field is a `Vec` of a path type.
+    let Some(FieldKind::Vec(syn::Type::Path(element_type))) = fields.get_field_kind(&ident) else {
+        unreachable!()
+    };
+    // A type in expression position needs the turbofish for its generic arguments.
+    let mut element_path = element_type.path.clone();
+    for segment in &mut element_path.segments {
+        if let syn::PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            arguments.colon2_token = Some(Default::default());
+        }
+    }
+
+    let style = LiteralStyle::of(fields);
+    let trim = if style.loose {
+        quote! { .map(|value: &str| value.trim_matches([' ', '\t'])) }
+    } else {
+        quote! {}
+    };
+    let mut element_parser = quote! {
+        empty::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>().map(|_| Vec::<&str>::new())
+    };
+    for (index, segment) in body.iter().enumerate() {
+        match segment {
+            TemplateSegments::Literal(lit) => {
+                let literal = literal_matcher(lit, style);
+                element_parser = quote! { #element_parser.then_ignore(#literal) };
+            }
+            _ => {
+                // SAFETY: The validator checked that every placeholder of the body is followed by a literal.
+                let Some(TemplateSegments::Literal(stop)) = body.get(index + 1) else {
+                    unreachable!()
+                };
+                let stops = Stops {
+                    literals: vec![stop.to_string()],
+                    style,
+                    end_blanks: None,
+                };
+                let captured = generate_str_parser(&stops, Capture::Lazy);
+                element_parser = quote! {
+                    #element_parser
+                        .then(#captured #trim)
+                        .map(|(mut values, value)| {
+                            values.push(value);
+                            values
+                        })
+                };
+            }
+        }
+    }
+
+    let element_fields = body
+        .iter()
+        .filter_map(TemplateSegments::field_name)
+        .enumerate()
+        .map(|(index, field)| {
+            let field_ident = syn::Ident::new(field, proc_macro2::Span::call_site());
+            // Errors name the element field after the block field, like `servers.port`.
+            let placeholder = format!("{}.{}", fields.placeholder_name(&ident), field);
+            let type_error = CustomError::ParseToType.raise(
+                quote! { span },
+                &[
+                    quote! { #placeholder },
+                    quote! { values[#index] },
+                    quote! { type_name },
+                ],
+                colon_escaper,
+            );
+            quote! {
+                #field_ident: match #krate::__private::parse_inferred(values[#index]) {
+                    Ok(value) => value,
+                    Err(type_name) => {
+                        return Err(#type_error);
+                    }
                 }
+            }
+        });
 
-                is_passed_first_placeholder = true;
-                latest_segment_was_literal = false;
+    quote! {
+        #element_parser
+            .try_map(|values: Vec<&str>, span| {
+                Ok(#element_path { #(#element_fields,)* })
+            })
+            .repeated()
+            .collect::<Vec<_>>()
+    }
+}
+
+/// Generates the parser of a field for `#[templatia(lossy)]`, which takes the whole capture and parses
+/// the field out of it with `inner`. When that fails, the errors are emitted with the span of the
+/// capture and the `default` expression or `Default::default()` is produced, so parsing goes on.
+fn generate_lossy_parser(
+    stops: &Stops,
+    capture: Capture,
+    field_name: &syn::Ident,
+    fields: &Fields,
+    inner: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let captured = generate_str_parser(stops, capture);
+    let default = match fields.get_default(field_name) {
+        Some(default) => quote! { #default },
+        None => quote! { ::std::default::Default::default() },
+    };
+
+    quote! {
+        #captured.validate(|s: &str, extra, emitter| {
+            match (#inner).then_ignore(end()).parse(s).into_result() {
+                Ok(value) => value,
+                Err(errs) => {
+                    for err in errs {
+                        emitter.emit(chumsky::error::Rich::custom(extra.span(), err.reason().to_string()));
+                    }
+                    #default
+                }
             }
+        })
+    }
+}
+
+/// Generates the parser of a placeholder with `{field|filter}` filters or a heredoc terminator, which
+/// takes the text matched by `captured`, undoes the invertible filters in reverse order, and parses the
+/// field out of the result with `inner`. `trim` trims the capture and `url_encode` decodes it, while
+/// the case filters leave it as is.
+fn generate_filtered_parser(
+    captured: proc_macro2::TokenStream,
+    filters: &[Filter],
+    krate: &syn::Path,
+    inner: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let undo = filters.iter().rev().filter_map(|filter| match filter {
+        Filter::Trim => Some(quote! { let value = value.trim().to_string(); }),
+        Filter::UrlEncode => Some(quote! { let value = #krate::__private::url_decode(&value); }),
+        Filter::Upper | Filter::Lower => None,
+    });
+
+    quote! {
+        #captured.try_map(|s: &str, span| {
+            let value = s.to_string();
+            #(#undo)*
+            (#inner)
+                .then_ignore(end())
+                .parse(value.as_str())
+                .into_result()
+                .map_err(|errs| {
+                    let reason = errs
+                        .into_iter()
+                        .next()
+                        .map(|err| err.reason().to_string())
+                        .unwrap_or_default();
+                    chumsky::error::Rich::custom(span, reason)
+                })
+        })
+    }
+}
+
+/// Generates the parser of a `{field<<END}` heredoc, which captures any text including line breaks
+/// up to a line holding only the terminator, and consumes the line break and the terminator.
+/// The terminator line ends at a line break or at the end of the input.
+fn generate_heredoc_parser(terminator: &str) -> proc_macro2::TokenStream {
+    let terminator_line = format!("\n{}", terminator);
+    quote! {
+        {
+            let terminator = just::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#terminator_line)
+                .then(just("\r").or_not())
+                .then(just("\n").ignored().or(end()).rewind());
+            terminator
+                .clone()
+                .not()
+                .ignore_then(any())
+                .repeated()
+                .to_slice()
+                .then_ignore(terminator.ignored())
         }
-        is_first_segment = false;
     }
+}
 
-    quote! { #parser.then_ignore(end()) }
+/// Generates the parser of a `#[templatia(skip_parse)]` field, which consumes the capture like
+/// the field would and produces the `default` expression or `Default::default()` instead of parsing it.
+fn generate_skip_parser(
+    field_name: &syn::Ident,
+    field_kind: &FieldKind,
+    stops: &Stops,
+    capture: Capture,
+    fields: &Fields,
+) -> proc_macro2::TokenStream {
+    let skipped = match field_kind {
+        FieldKind::Primitive(ty) if fields.get_with(field_name).is_none() => {
+            generate_parser(ty, stops, capture, None)
+        }
+        _ => generate_str_parser(stops, capture),
+    };
+    let default = match fields.get_default(field_name) {
+        Some(default) => quote! { #default },
+        None => quote! { ::std::default::Default::default() },
+    };
+
+    quote! { #skipped.map(|_| #default) }
 }
 
-fn generate_field_parser(
+/// Wraps a field parser so that an empty capture produces the `#[templatia(default = "...")]` expression.
+///
+/// The capture is empty when the input continues with one of the stop literals, or ends when
+/// the placeholder is the last segment.
+fn generate_default_parser(
+    stops: &Stops,
+    default: &syn::Expr,
+    field_parser: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let empty_capture = match stops.matcher() {
+        Some(matcher) => quote! {
+            #matcher
+                .rewind()
+                .ignored()
+        },
+        None => quote! {
+            end::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>()
+        },
+    };
+
+    quote! {
+        #empty_capture
+            .map(|_| #default)
+            .or(#field_parser)
+    }
+}
+
+/// Wraps a field parser so that an empty capture parses the inline default of `{field=default}`
+/// with `FromStr`, the same way as `#[templatia(default = "...")]` but with the default written as text.
+/// A default that doesn't parse as the field type is reported as a `TemplateError::ParseToType`.
+fn generate_inline_default_parser(
+    stops: &Stops,
+    placeholder_name: &str,
+    default: &str,
+    field_kind: &FieldKind,
+    krate: &syn::Path,
+    field_parser: proc_macro2::TokenStream,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let empty_capture = match stops.matcher() {
+        Some(matcher) => quote! {
+            #matcher
+                .rewind()
+                .ignored()
+        },
+        None => quote! {
+            end::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>()
+        },
+    };
+    let (ty, wrap) = match field_kind {
+        FieldKind::Option(ty) => (ty, quote! { Some(value) }),
+        FieldKind::Primitive(ty) => (ty, quote! { value }),
+        // The validator rejects inline defaults on the other kinds.
+        _ => return field_parser,
+    };
+
+    let type_error = CustomError::ParseToType.raise(
+        quote! { span },
+        &[
+            quote! { #placeholder_name },
+            quote! { #default },
+            quote! { type_name },
+        ],
+        colon_escaper,
+    );
+    quote! {
+        #empty_capture
+            .try_map(|_, span| match #krate::__private::parse_inferred::<#ty>(#default) {
+                Ok(value) => Ok(#wrap),
+                Err(type_name) => Err(#type_error),
+            })
+            .or(#field_parser)
+    }
+}
+
+/// Wraps a field parser so that each parsed value is checked against `#[templatia(min = ..., max = ...)]`
+/// and `#[templatia(min_len = ..., max_len = ...)]`. Violations are reported as `TemplateError::OutOfRange`.
+/// Returns the parser unchanged when the field has no bounds.
+fn generate_range_parser(
     field_name: &syn::Ident,
-    field_type: &FieldKind,
-    next_segment: Option<&TemplateSegments>,
-    empty_str_as_none: bool,
+    field_kind: &FieldKind,
+    range: Option<RangeBounds>,
+    fields: &Fields,
+    field_parser: proc_macro2::TokenStream,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (min, max) = fields.get_range(field_name);
+    let (min_len, max_len) = fields.get_len_range(field_name);
+    let ty = match field_kind {
+        FieldKind::Primitive(ty)
+        | FieldKind::Option(ty)
+        | FieldKind::Vec(ty)
+        | FieldKind::HashSet(ty)
+        | FieldKind::BTreeSet(ty)
+        | FieldKind::VecDeque(ty)
+        | FieldKind::LinkedList(ty)
+        | FieldKind::BinaryHeap(ty)
+        | FieldKind::IndexSet(ty)
+        | FieldKind::SmallVec(ty)
+        | FieldKind::ArrayVec(ty) => ty,
+        _ => return field_parser,
+    };
+
+    // Each check returns the constraint the value violates.
+    let mut checks = Vec::new();
+    if let Some(min) = min {
+        checks.push(quote! {
+            let min: #ty = #min;
+            if *value < min {
+                return Some(format!("must be at least {}", min));
+            }
+        });
+    }
+    if let Some(max) = max {
+        checks.push(quote! {
+            let max: #ty = #max;
+            if *value > max {
+                return Some(format!("must be at most {}", max));
+            }
+        });
+    }
+    if let Some(RangeBounds {
+        min,
+        max,
+        inclusive,
+    }) = range
+    {
+        // Integer literals are also accepted as the bounds of float fields.
+        let bound = |text: &str| {
+            // SAFETY: The bounds were checked to be number literals when the placeholder was parsed.
+            let bound: proc_macro2::TokenStream = text.parse().unwrap();
+            if matches!(get_type_name(ty).as_str(), "f32" | "f64") {
+                quote! { (#bound) as #ty }
+            } else {
+                bound
+            }
+        };
+        if let Some(min) = min.map(bound) {
+            checks.push(quote! {
+                let min: #ty = #min;
+                if *value < min {
+                    return Some(format!("must be at least {}", min));
+                }
+            });
+        }
+        match max.map(bound) {
+            Some(max) if inclusive => checks.push(quote! {
+                let max: #ty = #max;
+                if *value > max {
+                    return Some(format!("must be at most {}", max));
+                }
+            }),
+            Some(max) => checks.push(quote! {
+                let max: #ty = #max;
+                if *value >= max {
+                    return Some(format!("must be less than {}", max));
+                }
+            }),
+            None => {}
+        }
+    }
+    if let Some(min_len) = min_len {
+        checks.push(quote! {
+            if value.chars().count() < #min_len {
+                return Some(format!("must have at least {} characters", #min_len));
+            }
+        });
+    }
+    if let Some(max_len) = max_len {
+        checks.push(quote! {
+            if value.chars().count() > #max_len {
+                return Some(format!("must have at most {} characters", #max_len));
+            }
+        });
+    }
+    if checks.is_empty() {
+        return field_parser;
+    }
+
+    let violation = match field_kind {
+        FieldKind::Primitive(_) => quote! { check(&value).map(|constraint| (&value, constraint)) },
+        FieldKind::Option(_) => quote! {
+            value.as_ref().and_then(|value| check(value).map(|constraint| (value, constraint)))
+        },
+        _ => quote! {
+            value.iter().find_map(|value| check(value).map(|constraint| (value, constraint)))
+        },
+    };
+    let placeholder = fields.placeholder_name(field_name);
+    let out_of_range = CustomError::OutOfRange.raise(
+        quote! { span },
+        &[
+            quote! { #placeholder },
+            quote! { text },
+            quote! { constraint },
+        ],
+        colon_escaper,
+    );
+
+    quote! {
+        #field_parser
+            .try_map(|value, span| {
+                let check = |value: &#ty| -> Option<String> {
+                    #(#checks)*
+                    None
+                };
+                let violation = #violation.map(|(value, constraint)| (value.to_string(), constraint));
+                match violation {
+                    None => Ok(value),
+                    Some((text, constraint)) => Err(#out_of_range),
+                }
+            })
+    }
+}
+
+/// Wraps a field parser so that the parsed value is checked by the `#[templatia(validate = "...")]` function.
+/// Rejections are reported as `TemplateError::Validation`.
+fn generate_validate_parser(
+    placeholder: &str,
+    validate: &syn::Path,
+    field_parser: proc_macro2::TokenStream,
     colon_escaper: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
-    let next_literal = match next_segment {
-        Some(TemplateSegments::Literal(lit)) => Some(*lit),
-        _ => None,
+    let validation_error = CustomError::Validation.raise(
+        quote! { span },
+        &[quote! { #placeholder }, quote! { message }],
+        colon_escaper,
+    );
+    quote! {
+        #field_parser
+            .try_map(|value, span| {
+                match #validate(&value) {
+                    Ok(()) => Ok(value),
+                    Err(message) => Err(#validation_error),
+                }
+            })
+    }
+}
+
+/// Returns how the placeholder of the field followed by `rest` is captured.
+///
+/// A greedy capture counts the occurrences of its stop literal in the literals after the placeholder,
+/// so that it stops at the last occurrence that still leaves enough of them for the rest of the template.
+/// Literals inside optional groups are not counted since the groups may be absent.
+/// Returns whether a placeholder is captured as digits because of an integer type hint like `{count:u32}`,
+/// and if so whether the type is signed. Fields with their own capture, radix, format, or conversion
+/// options are captured as usual, since their text may contain other characters.
+pub(super) fn scans_digits(segment: &TemplateSegments, fields: &Fields) -> Option<bool> {
+    let TemplateSegments::Placeholder(
+        name,
+        PlaceholderSpec {
+            type_hint: Some(_), ..
+        },
+    ) = segment
+    else {
+        return None;
     };
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let ty = match fields.get_field_kind(&ident)? {
+        FieldKind::Primitive(ty) | FieldKind::Option(ty) if is_integer_type(ty) => ty,
+        _ => return None,
+    };
+    let plain = fields.get_with(&ident).is_none()
+        && fields.get_radix(&ident).is_none()
+        && !fields.is_bytesize(&ident)
+        && fields.get_explicit_format(&ident).is_none()
+        && fields.get_width(&ident).is_none()
+        && fields.get_escape(&ident).is_none()
+        && !fields.is_quoted(&ident)
+        && !fields.is_rest(&ident)
+        && !fields.is_until_eol(&ident)
+        && !fields.is_trim(&ident)
+        && fields.get_match_strategy(&ident) == MatchStrategy::Lazy;
+    plain.then(|| get_type_name(ty).starts_with('i'))
+}
+
+fn capture_of(ident: &syn::Ident, rest: &[TemplateSegments], fields: &Fields) -> Capture {
+    if fields.is_rest(ident) {
+        return Capture::Rest;
+    }
+    if fields.is_until_eol(ident) {
+        return Capture::UntilEol;
+    }
+    if let Some(width) = fields.get_width(ident) {
+        return Capture::FixedWidth(width);
+    }
+    if let Some(escape) = fields.get_escape(ident) {
+        return Capture::Escaped(escape);
+    }
+    if fields.is_quoted(ident) {
+        let optional = matches!(fields.get_field_kind(ident), Some(FieldKind::Option(_)));
+        return Capture::Quoted(optional);
+    }
+    match (fields.get_match_strategy(ident), rest.first()) {
+        (MatchStrategy::Greedy, Some(TemplateSegments::Literal(stop))) => {
+            let occurrences = rest
+                .iter()
+                .map(|segment| match segment {
+                    TemplateSegments::Literal(lit) => lit.matches(*stop).count(),
+                    _ => 0,
+                })
+                .sum();
+            Capture::Greedy(occurrences)
+        }
+        _ => Capture::Lazy,
+    }
+}
 
+/// Returns the literals that can terminate a placeholder followed by `rest`.
+///
+/// A placeholder followed by an optional group or a block stops at its first literal or at whatever
+/// follows it, since the group may be absent and the block may be empty. Literals with alias keys contribute all of their spellings.
+fn stop_literals(rest: &[TemplateSegments], following: &[String], fields: &Fields) -> Vec<String> {
+    match rest.first() {
+        Some(TemplateSegments::Literal(lit)) => literal_alternatives(lit, rest.get(1), fields),
+        Some(alternation @ TemplateSegments::Alternation(_)) => {
+            literal_spellings(alternation, rest.get(1), fields).1
+        }
+        // A block may have no elements, so the text after it also ends the capture.
+        Some(TemplateSegments::Group(inner)) | Some(TemplateSegments::Block(_, inner)) => {
+            let mut stops = match inner.first() {
+                Some(
+                    segment @ (TemplateSegments::Literal(_) | TemplateSegments::Alternation(_)),
+                ) => literal_spellings(segment, inner.get(1), fields).1,
+                _ => Vec::new(),
+            };
+            stops.extend(stop_literals(&rest[1..], following, fields));
+            stops
+        }
+        // A capture ends where the text of a lookahead starts, and a negated one lets it go on.
+        Some(TemplateSegments::Lookahead(text, false)) => vec![text.to_string()],
+        Some(TemplateSegments::Lookahead(_, true)) => stop_literals(&rest[1..], following, fields),
+        Some(_) => Vec::new(),
+        None => following.to_vec(),
+    }
+}
+
+/// Returns the rendered text of a literal or an alternation and the spellings accepted when parsing.
+/// The spellings of an alternation are tried longest first, so that a spelling isn't cut by a shorter prefix
+/// like `http` of `https`.
+fn literal_spellings<'a>(
+    segment: &TemplateSegments<'a>,
+    next: Option<&TemplateSegments>,
+    fields: &Fields,
+) -> (&'a str, Vec<String>) {
+    match segment {
+        TemplateSegments::Literal(lit) => (lit, literal_alternatives(lit, next, fields)),
+        TemplateSegments::Alternation(alternatives) => {
+            let mut spellings = alternatives
+                .iter()
+                .map(|spelling| spelling.to_string())
+                .collect::<Vec<_>>();
+            spellings.sort_by_key(|spelling| std::cmp::Reverse(spelling.len()));
+            (alternatives.first().copied().unwrap_or_default(), spellings)
+        }
+        _ => ("", Vec::new()),
+    }
+}
+
+/// Returns the spellings of a literal accepted when parsing: the literal itself followed by
+/// the variants with the key of the next placeholder replaced by each `#[templatia(alias = "...")]`.
+fn literal_alternatives(
+    lit: &str,
+    next: Option<&TemplateSegments>,
+    fields: &Fields,
+) -> Vec<String> {
+    let mut alternatives = vec![lit.to_string()];
+    if let Some(TemplateSegments::Placeholder(name, _)) = next {
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        let key = fields.placeholder_name(&ident);
+        alternatives.extend(
+            fields
+                .get_aliases(&ident)
+                .iter()
+                .filter_map(|alias| replace_key(lit, &key, alias)),
+        );
+    }
+    alternatives
+}
+
+/// Replaces the key at the end of a literal like `"\nhost = "` with another spelling.
+///
+/// # Returns
+/// `None` when the literal doesn't end with the key followed only by non-alphanumeric characters.
+pub(super) fn replace_key(lit: &str, key: &str, replacement: &str) -> Option<String> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+    let start = lit.rfind(key)?;
+    let end = start + key.len();
+    if lit[..start].chars().next_back().is_some_and(is_word) || lit[end..].chars().any(is_word) {
+        return None;
+    }
+    Some(format!("{}{}{}", &lit[..start], replacement, &lit[end..]))
+}
+
+fn generate_field_parser(
+    field_name: &syn::Ident,
+    separator: &str,
+    stops: &Stops,
+    capture: Capture,
+    fields: &Fields,
+    empty_str_as_none: bool,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let krate = fields.crate_path();
+    // SAFETY: The placeholder is always in the fields, as checked at the start of generate_str_parser.
+    let field_type = fields.get_field_kind(field_name).unwrap();
+    // Errors report the name written in the template, which differs from the field name when renamed.
+    let placeholder = &fields.placeholder_name(field_name);
     let field_type_str = field_type.to_string();
+    let empty_str_as_none = fields
+        .empty_str_as_none(field_name)
+        .unwrap_or(empty_str_as_none && !fields.is_strict());
+    // In strict mode, an empty capture is never taken as `None` on its own.
+    let empty_is_none = !fields.is_strict();
+    // Fixed-width values are padded on render, so the padding is always trimmed.
+    let trim =
+        generate_trim(fields.is_trim(field_name) || matches!(capture, Capture::FixedWidth(_)));
+    // A fill other than spaces is stripped from the sides the spec pads.
+    let strip_fill = match fields.get_fill(field_name) {
+        Some((fill, '<')) => quote! { .map(|value: &str| value.trim_end_matches(#fill)) },
+        Some((fill, '>')) => quote! { .map(|value: &str| value.trim_start_matches(#fill)) },
+        Some((fill, _)) => quote! { .map(|value: &str| value.trim_matches(#fill)) },
+        None => quote! {},
+    };
+    let trim_captured = quote! { #trim #strip_fill };
+    // In the loose mode, the blanks around collection separators are dropped as well.
+    let trim_elements = if fields.is_loose_whitespace() && !fields.is_trim(field_name) {
+        quote! { #trim_captured.map(|value| value.trim_matches([' ', '\t'])) }
+    } else {
+        trim_captured.clone()
+    };
+    // Escaped separators stay inside the elements, which are unescaped as they are parsed.
+    let split_elements = match fields.get_escape(field_name) {
+        Some(escape) => {
+            quote! { #krate::__private::split_escaped(s, #separator, #escape).into_iter() }
+        }
+        None => quote! { s.split(#separator) },
+    };
+    // The separators percent-encoded inside `Url` elements are decoded after splitting.
+    let decode_elements = if field_type.is_url() && fields.get_escape(field_name).is_none() {
+        quote! { .map(|value| #krate::__private::percent_decode_separator(value, #separator)) }
+    } else {
+        quote! {}
+    };
+    // The `render_none` token and the `none_literals` are parsed back to `None` before any other interpretation.
+    let none_tokens = fields.get_none_tokens(field_name);
+    let is_none_token = if none_tokens.is_empty() {
+        quote! { false }
+    } else {
+        quote! { matches!(s, #(#none_tokens)|*) }
+    };
     match field_type {
+        // Borrowed strings are the captured slice itself, so they can't fail and need no `FromStr`.
+        FieldKind::Option(ty) if is_borrowed_str(ty) => {
+            let inner_parser = generate_str_parser(stops, capture);
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+                    .map(|s| if #is_none_token || (#empty_str_as_none && s.is_empty()) { None } else { Some(s) })
+            }
+        }
+        FieldKind::Primitive(ty) if is_borrowed_str(ty) => {
+            let inner_parser = generate_str_parser(stops, capture);
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+            }
+        }
+        // `Option<Box<T>>` delegates the captured text to the `Template` implementation of `T`,
+        // which allows recursive structs such as linked lists.
+        FieldKind::Option(ty) if boxed_inner(ty).is_some() => {
+            // SAFETY: The guard checked that the type is a Box.
+            let inner = boxed_inner(ty).unwrap();
+            let inner_parser = generate_str_parser(stops, capture);
+            let include_error = CustomError::Include.raise(
+                quote! { span },
+                &[quote! { #placeholder }, quote! { e.to_string() }],
+                colon_escaper,
+            );
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+                    .try_map(|s: &str, span| {
+                        if #is_none_token || (#empty_is_none && s.is_empty()) {
+                            return Ok(None);
+                        }
+                        <#inner as #krate::ParseTemplate>::from_str(s)
+                            .map(|value| Some(Box::new(value)))
+                            .map_err(|e| #include_error)
+                    })
+            }
+        }
         FieldKind::Option(ty) => {
             let is_string_type =
                 matches!(get_type_name(ty).to_lowercase().as_str(), "string" | "str");
-            let inner_parser = generate_parser(ty, next_literal);
+            let inner_parser =
+                generate_parser(ty, stops, capture, fields.get_bool_literals(field_name));
+            let parse_s = generate_value_parse(ty, &quote! { s }, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { s },
+                colon_escaper,
+            );
 
             quote! {
                 #inner_parser
+                    #trim_captured
                     .try_map(|s: &str, span| {
-                        if (#empty_str_as_none || !#is_string_type) && s.is_empty() {
+                        if #is_none_token || ((#empty_str_as_none || (#empty_is_none && !#is_string_type)) && s.is_empty()) {
                             Ok(None)
                         } else {
-                            s.parse::<#ty>()
+                            #parse_s
                                 .map(Some)
-                                .map_err(|_| {
-                                    chumsky::error::Rich::<char>::custom(
-                                        span,
-                                        format!(
-                                            "__templatia_parse_type__:{}::{}::{}",
-                                            stringify!(#field_name).#colon_escaper,
-                                            s.#colon_escaper,
-                                            #field_type_str.#colon_escaper,
-                                        )
-                                    )
-                                })
+                                .map_err(|_| #type_error)
                         }
                     })
             }
         }
         FieldKind::Vec(ty) => {
-            let inner_parser = generate_str_parser(next_literal);
+            let inner_parser = generate_str_parser(stops, capture);
+            let parse_value = generate_element_parse(ty, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
 
             quote! {
                 #inner_parser
+                    #trim_captured
                     .try_map(|s: &str, span| {
                         let mut vec = Vec::new();
                         if s.is_empty() {
                             Ok(vec)
                         } else {
-                            let values = s.split(',');
+                            let values = #split_elements #trim_elements #decode_elements;
 
                             for value in values {
-                                match value.parse::<#ty>() {
+                                match #parse_value {
                                     Ok(v) => {
                                         vec.push(v);
                                     },
                                     Err(_) => {
                                         // I'm not sure if this way is the best for the collection parser.
                                         // However, this way works for now.
-                                        return Err(chumsky::error::Rich::<char>::custom(
-                                            span,
-                                            format!(
-                                                "__templatia_parse_type__:{}::{}::{}",
-                                                stringify!(#field_name).#colon_escaper,
-                                                s.#colon_escaper,
-                                                #field_type_str.#colon_escaper,
-                                            )
-                                        ))
+                                        return Err(#type_error)
                                     }
                                 }
                             }
@@ -189,32 +1396,34 @@ fn generate_field_parser(
             }
         }
         FieldKind::HashSet(ty) => {
-            let inner_parser = generate_str_parser(next_literal);
+            let inner_parser = generate_str_parser(stops, capture);
+            let parse_value =
+                generate_value_parse(ty, &quote! { value }, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
 
             quote! {
                 #inner_parser
+                    #trim_captured
                     .try_map(|s: &str, span| {
                         let mut set = std::collections::HashSet::new();
                         if s.is_empty() {
                             Ok(set)
                         } else {
-                            let values = s.split(',');
+                            let values = #split_elements #trim_elements #decode_elements;
 
                             for value in values {
-                                match value.parse::<#ty>() {
+                                match #parse_value {
                                     Ok(v) => {
                                         set.insert(v);
                                     },
                                     Err(_) => {
-                                        return Err(chumsky::error::Rich::<char>::custom(
-                                            span,
-                                            format!(
-                                                "__templatia_parse_type__:{}::{}::{}",
-                                                stringify!(#field_name).#colon_escaper,
-                                                s.#colon_escaper,
-                                                #field_type_str.#colon_escaper,
-                                            )
-                                        ))
+                                        return Err(#type_error)
                                     }
                                 }
                             }
@@ -224,32 +1433,34 @@ fn generate_field_parser(
             }
         }
         FieldKind::BTreeSet(ty) => {
-            let inner_parser = generate_str_parser(next_literal);
+            let inner_parser = generate_str_parser(stops, capture);
+            let parse_value =
+                generate_value_parse(ty, &quote! { value }, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
 
             quote! {
                 #inner_parser
+                    #trim_captured
                     .try_map(|s: &str, span| {
                         let mut b_set = std::collections::BTreeSet::new();
                         if s.is_empty() {
                             Ok(b_set)
                         } else {
-                            let values = s.split(',');
+                            let values = #split_elements #trim_elements #decode_elements;
 
                             for value in values {
-                                match value.parse::<#ty>() {
+                                match #parse_value {
                                     Ok(v) => {
                                         b_set.insert(v);
                                     },
                                     Err(_) => {
-                                        return Err(chumsky::error::Rich::<char>::custom(
-                                            span,
-                                            format!(
-                                                "__templatia_parse_type__:{}::{}::{}",
-                                                stringify!(#field_name).#colon_escaper,
-                                                s.#colon_escaper,
-                                                #field_type_str.#colon_escaper,
-                                            )
-                                        ))
+                                        return Err(#type_error)
                                     }
                                 }
                             }
@@ -258,24 +1469,207 @@ fn generate_field_parser(
                     })
             }
         }
+        FieldKind::Result(ok_ty, err_ty) => {
+            let inner_parser = generate_str_parser(stops, capture);
+            let (ok_prefix, err_prefix) = fields.get_result_prefixes(field_name);
+            let parse_ok =
+                generate_value_parse(ok_ty, &quote! { value }, field_name, fields, capture);
+            let parse_err =
+                generate_value_parse(err_ty, &quote! { value }, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ok_ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
+            let unknown_variant_error = generate_type_error(
+                placeholder,
+                ok_ty,
+                &field_type_str,
+                &quote! { s },
+                colon_escaper,
+            );
+            let mut variants = [
+                (ok_prefix, quote! { #parse_ok.map(Ok) }),
+                (err_prefix, quote! { #parse_err.map(Err) }),
+            ];
+            // The longer prefix is tried first, so that a prefix starting the other one doesn't take its values.
+            variants.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+            let (prefixes, parses): (Vec<_>, Vec<_>) = variants.into_iter().unzip();
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+                    .try_map(|s: &str, span| {
+                        #(
+                            if let Some(value) = s.strip_prefix(#prefixes) {
+                                return #parses.map_err(|_| #type_error);
+                            }
+                        )*
+                        Err(#unknown_variant_error)
+                    })
+            }
+        }
+        FieldKind::VecDeque(ty)
+        | FieldKind::LinkedList(ty)
+        | FieldKind::BinaryHeap(ty)
+        | FieldKind::IndexSet(ty)
+        | FieldKind::SmallVec(ty) => {
+            let inner_parser = generate_str_parser(stops, capture);
+            let collection_type = match field_type {
+                FieldKind::VecDeque(_) => quote! { std::collections::VecDeque },
+                FieldKind::LinkedList(_) => quote! { std::collections::LinkedList },
+                FieldKind::IndexSet(_) => quote! { #krate::__private::indexmap::IndexSet },
+                FieldKind::SmallVec(_) => quote! { #krate::__private::smallvec::SmallVec },
+                _ => quote! { std::collections::BinaryHeap },
+            };
+            let parse_value = generate_element_parse(ty, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+                    .try_map(|s: &str, span| {
+                        let mut collection = #collection_type::new();
+                        if s.is_empty() {
+                            return Ok(collection);
+                        }
+                        for value in #split_elements #trim_elements #decode_elements {
+                            match #parse_value {
+                                Ok(v) => collection.extend([v]),
+                                Err(_) => return Err(#type_error),
+                            }
+                        }
+                        Ok(collection)
+                    })
+            }
+        }
+        // An `ArrayVec` can't grow, so the values beyond its capacity are out of range rather than a panic.
+        FieldKind::ArrayVec(ty) => {
+            let inner_parser = generate_str_parser(stops, capture);
+            let parse_value = generate_element_parse(ty, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
+            let capacity_error = CustomError::OutOfRange.raise(
+                quote! { span },
+                &[
+                    quote! { #placeholder },
+                    quote! { value },
+                    quote! { format!("must have at most {} elements", collection.capacity()) },
+                ],
+                colon_escaper,
+            );
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+                    .try_map(|s: &str, span| {
+                        let mut collection = #krate::__private::arrayvec::ArrayVec::new();
+                        if s.is_empty() {
+                            return Ok(collection);
+                        }
+                        for value in #split_elements #trim_elements #decode_elements {
+                            let v = #parse_value.map_err(|_| #type_error)?;
+                            if collection.try_push(v).is_err() {
+                                return Err(#capacity_error);
+                            }
+                        }
+                        Ok(collection)
+                    })
+            }
+        }
+        FieldKind::HashMap(key_ty, value_ty)
+        | FieldKind::BTreeMap(key_ty, value_ty)
+        | FieldKind::IndexMap(key_ty, value_ty) => {
+            let inner_parser = generate_str_parser(stops, capture);
+            let map_type = match field_type {
+                FieldKind::HashMap(..) => quote! { std::collections::HashMap },
+                FieldKind::IndexMap(..) => quote! { #krate::__private::indexmap::IndexMap },
+                _ => quote! { std::collections::BTreeMap },
+            };
+            let kv_separator = fields.get_kv_separator(field_name);
+            // Escaped delimiters stay inside the keys and the values, which are unescaped as they are parsed.
+            let split_entry = match fields.get_escape(field_name) {
+                Some(escape) => quote! {
+                    #krate::__private::split_once_escaped(entry, #kv_separator, #escape)
+                },
+                None => quote! { entry.split_once(#kv_separator) },
+            };
+            let parse_key =
+                generate_value_parse(key_ty, &quote! { key }, field_name, fields, capture);
+            let parse_value =
+                generate_value_parse(value_ty, &quote! { value }, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                value_ty,
+                &field_type_str,
+                &quote! { value },
+                colon_escaper,
+            );
+            // The blanks around the key and the value are dropped like those around the entries.
+            let trim_entry = if fields.is_trim(field_name) {
+                quote! { let (key, value) = (key.trim(), value.trim()); }
+            } else if fields.is_loose_whitespace() {
+                quote! { let (key, value) = (key.trim_matches([' ', '\t']), value.trim_matches([' ', '\t'])); }
+            } else {
+                quote! {}
+            };
+
+            quote! {
+                #inner_parser
+                    #trim_captured
+                    .try_map(|s: &str, span| {
+                        let mut map = #map_type::new();
+                        if s.is_empty() {
+                            return Ok(map);
+                        }
+                        for entry in #split_elements #trim_elements {
+                            // An entry without the key-value delimiter is reported with its whole text.
+                            let Some((key, value)) = #split_entry else {
+                                let value = entry;
+                                return Err(#type_error);
+                            };
+                            #trim_entry
+                            match (#parse_key, #parse_value) {
+                                (Ok(key), Ok(value)) => {
+                                    map.insert(key, value);
+                                }
+                                _ => return Err(#type_error),
+                            }
+                        }
+                        Ok(map)
+                    })
+            }
+        }
         FieldKind::Primitive(ty) => {
-            let parser = generate_parser(ty, next_literal);
+            let parser = generate_parser(ty, stops, capture, fields.get_bool_literals(field_name));
+            let parse_s = generate_value_parse(ty, &quote! { s }, field_name, fields, capture);
+            let type_error = generate_type_error(
+                placeholder,
+                ty,
+                &field_type_str,
+                &quote! { s },
+                colon_escaper,
+            );
 
             quote! {
                 #parser
+                    #trim_captured
                     .try_map(|s: &str, span| {
-                        s.parse::<#ty>()
-                            .map_err(|_| {
-                                chumsky::error::Rich::<char>::custom(
-                                    span,
-                                    format!(
-                                        "__templatia_parse_type__:{}::{}::{}",
-                                        stringify!(#field_name).#colon_escaper,
-                                        s.#colon_escaper,
-                                        #field_type_str.#colon_escaper,
-                                    )
-                                )
-                            })
+                        #parse_s
+                            .map_err(|_| #type_error)
                     })
             }
         }
@@ -283,8 +1677,220 @@ fn generate_field_parser(
     }
 }
 
-fn generate_parser(field_type: &syn::Type, next_literal: Option<&str>) -> proc_macro2::TokenStream {
-    let base_parser = generate_base_parser(next_literal);
+/// Generates the parser for a field with `#[templatia(with = "...")]`, which delegates the captured text
+/// to the `parse` function of the module. Failures are reported as `TemplateError::ParseToType`.
+fn generate_with_parser(
+    placeholder: &str,
+    field_type_str: &str,
+    with: &syn::Path,
+    stops: &Stops,
+    capture: Capture,
+    trim: bool,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let inner_parser = generate_str_parser(stops, capture);
+    let trim_captured = generate_trim(trim);
+    let type_error = CustomError::ParseToType.raise(
+        quote! { span },
+        &[
+            quote! { #placeholder },
+            quote! { s },
+            quote! { #field_type_str },
+        ],
+        colon_escaper,
+    );
+
+    quote! {
+        #inner_parser
+            #trim_captured
+            .try_map(|s: &str, span| {
+                #with::parse(s)
+                    .map_err(|_| #type_error)
+            })
+    }
+}
+
+/// Generates the parser for `{>field}`, which delegates the captured text to the field's `Template` implementation.
+fn generate_include_parser(
+    placeholder: &str,
+    field_type: &syn::Type,
+    element: Option<(&syn::Type, &str)>,
+    krate: &syn::Path,
+    stops: &Stops,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let inner_parser = generate_str_parser(stops, Capture::Lazy);
+    let include_error = CustomError::Include.raise(
+        quote! { span },
+        &[quote! { #placeholder }, quote! { e.to_string() }],
+        colon_escaper,
+    );
+    let include_error = quote! { |e| #include_error };
+
+    match element {
+        // An empty capture is an empty sequence rather than a single element parsed from "".
+        Some((element_type, separator)) => quote! {
+            #inner_parser
+                .try_map(|s: &str, span| {
+                    if s.is_empty() {
+                        return Ok(<#field_type as ::std::default::Default>::default());
+                    }
+                    s.split(#separator)
+                        .map(<#element_type as #krate::ParseTemplate>::from_str)
+                        .collect::<::std::result::Result<#field_type, _>>()
+                        .map_err(#include_error)
+                })
+        },
+        None => quote! {
+            #inner_parser
+                .try_map(|s: &str, span| {
+                    <#field_type as #krate::ParseTemplate>::from_str(s)
+                        .map_err(#include_error)
+                })
+        },
+    }
+}
+
+/// Generates the custom error raised when a captured value cannot be parsed into the field type.
+///
+/// For `NonZero*` types, a zero value is reported with a dedicated marker so that the caller
+/// gets a "must be non-zero" error instead of the generic type mismatch.
+fn generate_type_error(
+    placeholder: &str,
+    ty: &syn::Type,
+    field_type_str: &str,
+    checked_value: &proc_macro2::TokenStream,
+    colon_escaper: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let raise = |error: CustomError| {
+        error.raise(
+            quote! { span },
+            &[
+                quote! { #placeholder },
+                quote! { s },
+                quote! { #field_type_str },
+            ],
+            colon_escaper,
+        )
+    };
+
+    let type_error = raise(CustomError::ParseToType);
+    if is_nonzero_type(ty) {
+        let zero_error = raise(CustomError::ZeroValue);
+        quote! {
+            if #checked_value.parse::<i128>() == Ok(0) {
+                #zero_error
+            } else {
+                #type_error
+            }
+        }
+    } else {
+        type_error
+    }
+}
+
+/// Generates the adapter trimming captured text for `#[templatia(trim)]`, or nothing without it.
+fn generate_trim(trim: bool) -> proc_macro2::TokenStream {
+    if trim {
+        quote! { .map(str::trim) }
+    } else {
+        quote! {}
+    }
+}
+
+/// Generates the `FromStr` call for a captured value, decoding it first for `#[templatia(escape = '...')]`
+/// and `#[templatia(quoted)]`.
+///
+/// With `#[templatia(uppercase)]` or `#[templatia(lowercase)]` the value is retried in lower and
+/// upper case, so that input in either case is accepted regardless of what `FromStr` expects.
+/// With `#[templatia(radix = N)]` the value is parsed by `from_str_radix` after removing an optional prefix,
+/// and with `true_values`/`false_values` a bool is matched against the literal sets.
+fn generate_value_parse(
+    ty: &syn::Type,
+    value: &proc_macro2::TokenStream,
+    field_name: &syn::Ident,
+    fields: &Fields,
+    capture: Capture,
+) -> proc_macro2::TokenStream {
+    let krate = fields.crate_path();
+    if let Some(radix) = fields.get_radix(field_name) {
+        return quote! {
+            <#ty>::from_str_radix(&#krate::__private::strip_radix_prefix(#value, #radix), #radix)
+        };
+    }
+    if let Some((true_values, false_values)) = fields.get_bool_literals(field_name) {
+        return quote! {
+            (match #value {
+                #(#true_values)|* => Ok::<bool, ()>(true),
+                #(#false_values)|* => Ok(false),
+                _ => Err(()),
+            })
+        };
+    }
+    let value = match capture {
+        Capture::Escaped(escape) => quote! { #krate::__private::unescape(#value, #escape) },
+        Capture::Quoted(_) => quote! { #krate::__private::unquote(#value) },
+        _ => value.clone(),
+    };
+    if fields.is_bytesize(field_name) {
+        return quote! { #krate::__private::parse_bytesize(&#value).ok_or(()) };
+    }
+    if let Some(datetime_format) = fields.get_datetime_format(field_name) {
+        return quote! {
+            <#ty as #krate::__private::DateTimeFormat>::parse_datetime(&#value, #datetime_format)
+        };
+    }
+    match fields.get_case(field_name) {
+        Some(_) => quote! {
+            #value
+                .parse::<#ty>()
+                .or_else(|_| #value.to_lowercase().parse::<#ty>())
+                .or_else(|_| #value.to_uppercase().parse::<#ty>())
+        },
+        None => quote! { #value.parse::<#ty>() },
+    }
+}
+
+/// Generates the parsing of the element `value` of a sequence field, where an empty element of a
+/// sparse sequence like `Vec<Option<T>>` is `None`.
+fn generate_element_parse(
+    ty: &syn::Type,
+    field_name: &syn::Ident,
+    fields: &Fields,
+    capture: Capture,
+) -> proc_macro2::TokenStream {
+    let Some(inner) = option_inner(ty) else {
+        return generate_value_parse(ty, &quote! { value }, field_name, fields, capture);
+    };
+    let parse_value = generate_value_parse(inner, &quote! { value }, field_name, fields, capture);
+    quote! {
+        (if value.is_empty() {
+            Ok(None)
+        } else {
+            #parse_value.map(Some)
+        })
+    }
+}
+
+fn generate_parser(
+    field_type: &syn::Type,
+    stops: &Stops,
+    capture: Capture,
+    bool_literals: Option<(Vec<String>, Vec<String>)>,
+) -> proc_macro2::TokenStream {
+    if let Capture::FixedWidth(width) = capture {
+        return generate_fixed_width_parser(width);
+    }
+    if let Capture::Digits(signed) = capture {
+        return generate_digits_parser(signed);
+    }
+    if let Capture::Quoted(optional) = capture {
+        return generate_quoted_parser(optional);
+    }
+    let base_parser = generate_base_parser(stops, capture);
+    if capture == Capture::Rest {
+        return quote! { #base_parser.to_slice() };
+    }
 
     match get_type_name(field_type).as_str() {
         "char" => quote! {
@@ -292,6 +1898,34 @@ fn generate_parser(field_type: &syn::Type, next_literal: Option<&str>) -> proc_m
                 .map(|c| c.to_string())
                 .to_slice()
         },
+        // Custom literals are tried longest first so that a literal isn't cut by a shorter prefix.
+        "bool" if bool_literals.is_some() => {
+            // SAFETY: The guard checked that the literals are given, and they are never empty.
+            let (true_values, false_values) = bool_literals.unwrap();
+            let mut literals = true_values
+                .into_iter()
+                .chain(false_values)
+                .collect::<Vec<_>>();
+            literals.sort_by_key(|literal| std::cmp::Reverse(literal.len()));
+            let (first, rest) = literals.split_first().unwrap();
+
+            quote! {
+                just::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#first)
+                    #(.or(just(#rest)))*
+                    .to_slice()
+                    .or(#base_parser.to_slice())
+            }
+        }
+        // A value that doesn't scan, like an empty `Option`, is captured as usual to report its text.
+        _ if capture == Capture::Lazy && generate_scanner(field_type, stops).is_some() => {
+            // SAFETY: The guard checked that the type has a scanner.
+            let scanner = generate_scanner(field_type, stops).unwrap();
+            quote! {
+                #scanner
+                    .to_slice()
+                    .or(#base_parser.to_slice())
+            }
+        }
         "bool" => quote! {
             choice((
                 just::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>("true").to_slice(),
@@ -305,17 +1939,185 @@ fn generate_parser(field_type: &syn::Type, next_literal: Option<&str>) -> proc_m
     }
 }
 
-fn generate_str_parser(next_literal: Option<&str>) -> proc_macro2::TokenStream {
-    let base_parser = generate_base_parser(next_literal);
+/// Generates the scanner of an IP or socket address type, or of a `semver::Version` with the `semver`
+/// feature, which reads the characters the value can have and keeps the longest prefix that parses
+/// as the type, instead of stopping at a literal, since IPv6 addresses and ports contain `:` and
+/// versions contain `.` and `-`. So `{ip}:{port}` splits `127.0.0.1:80`, and `{addr}: ready` reads
+/// `[::1]:8080` before the literal. A prefix followed by the first character of a stop literal is
+/// preferred, so `{version}-{os}` splits `1.2.3-linux`. Returns `None` for other types.
+fn generate_scanner(field_type: &syn::Type, stops: &Stops) -> Option<proc_macro2::TokenStream> {
+    let is_value_char = match get_type_name(field_type).as_str() {
+        "Ipv4Addr" => quote! { c.is_ascii_digit() || c == '.' },
+        "Ipv6Addr" | "IpAddr" => quote! { c.is_ascii_hexdigit() || c == ':' || c == '.' },
+        "SocketAddrV4" => quote! { c.is_ascii_digit() || c == '.' || c == ':' },
+        "SocketAddrV6" | "SocketAddr" => {
+            quote! { c.is_ascii_hexdigit() || matches!(c, ':' | '.' | '[' | ']') }
+        }
+        // Other types named `Version` may be in scope, so only the full path is recognized.
+        "Version"
+            if cfg!(feature = "semver") && is_full_path(field_type, &["semver", "Version"]) =>
+        {
+            quote! { c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+') }
+        }
+        _ => return None,
+    };
+    let followers = stops
+        .literals
+        .iter()
+        .filter_map(|literal| literal.chars().next())
+        .collect::<Vec<_>>();
+    let is_followed = if followers.is_empty() {
+        quote! { true }
+    } else {
+        quote! { inp.peek().is_some_and(|c| matches!(c, #(#followers)|*)) }
+    };
+
+    Some(quote! {
+        chumsky::primitive::custom::<_, &str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(|inp| {
+            let start = inp.cursor();
+            let mut longest = None;
+            let mut followed = None;
+            while let Some(c) = inp.peek() {
+                if !(#is_value_char) {
+                    break;
+                }
+                inp.skip();
+                if inp.slice_since(&start..).parse::<#field_type>().is_ok() {
+                    if #is_followed {
+                        followed = Some(inp.save());
+                    }
+                    longest = Some(inp.save());
+                }
+            }
+            match followed.or(longest) {
+                Some(end) => {
+                    inp.rewind(end);
+                    Ok(inp.slice_since(&start..))
+                }
+                None => Err(chumsky::error::Rich::custom(inp.span_since(&start), "expected an address or a version")),
+            }
+        })
+    })
+}
+
+fn generate_str_parser(stops: &Stops, capture: Capture) -> proc_macro2::TokenStream {
+    if let Capture::FixedWidth(width) = capture {
+        return generate_fixed_width_parser(width);
+    }
+    if let Capture::Digits(signed) = capture {
+        return generate_digits_parser(signed);
+    }
+    if let Capture::Quoted(optional) = capture {
+        return generate_quoted_parser(optional);
+    }
+    let base_parser = generate_base_parser(stops, capture);
     quote! {
         #base_parser.to_slice()
     }
 }
 
-fn generate_base_parser(next_literal: Option<&str>) -> proc_macro2::TokenStream {
-    if let Some(next_lit) = next_literal {
+/// Generates the parser of a double-quoted token for `#[templatia(quoted)]`, keeping the quotes
+/// and escapes in the captured slice. An optional token may be absent, which captures an empty slice.
+fn generate_quoted_parser(optional: bool) -> proc_macro2::TokenStream {
+    let quoted = quote! {
+        just::<char, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>('"')
+            .then(
+                just('\\')
+                    .ignore_then(any())
+                    .ignored()
+                    .or(none_of("\"\\").ignored())
+                    .repeated(),
+            )
+            .then(just('"'))
+    };
+    if optional {
+        quote! { #quoted.or_not().to_slice() }
+    } else {
+        quote! { #quoted.to_slice() }
+    }
+}
+
+/// Generates the parser consuming exactly `width` characters for `#[templatia(width = N)]`.
+/// Generates the parser of an integer with an optional sign for `Capture::Digits`, which ends at the first
+/// character that isn't a digit regardless of the stop literals.
+fn generate_digits_parser(signed: bool) -> proc_macro2::TokenStream {
+    let signs = if signed { "+-" } else { "+" };
+    quote! {
+        one_of::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#signs)
+            .or_not()
+            .then(any().filter(|c: &char| c.is_ascii_digit()).repeated())
+            .to_slice()
+    }
+}
+
+fn generate_fixed_width_parser(width: usize) -> proc_macro2::TokenStream {
+    quote! {
+        any::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>()
+            .repeated()
+            .exactly(#width)
+            .to_slice()
+    }
+}
+
+/// Generates the parser consuming characters until one of the stop literals, or the end of input without them.
+///
+/// A greedy capture stops only at the occurrence of its stop literal after which the input contains
+/// exactly the counted number of occurrences, i.e., the last one that the rest of the template can use.
+fn generate_base_parser(stops: &Stops, capture: Capture) -> proc_macro2::TokenStream {
+    if capture == Capture::Rest {
+        return quote! {
+            any::<&str, chumsky::extra::Err<chumsky::error::Rich<char>>>()
+                .repeated()
+        };
+    }
+    if capture == Capture::UntilEol {
+        // `\r` also ends the line so that the capture of a CRLF line doesn't keep the carriage return.
+        return match stops.matcher() {
+            Some(matcher) => quote! {
+                #matcher
+                    .not()
+                    .ignore_then(none_of("\r\n"))
+                    .repeated()
+            },
+            None => quote! {
+                none_of::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>("\r\n")
+                    .repeated()
+            },
+        };
+    }
+    if let (Capture::Greedy(occurrences), Some(stop)) = (capture, stops.first_matcher()) {
+        let later_occurrences = occurrences.saturating_sub(1);
+        return quote! {
+            {
+                let no_stop = #stop
+                    .not()
+                    .ignore_then(any())
+                    .repeated();
+                #stop
+                    .then(no_stop.then(#stop).repeated().exactly(#later_occurrences))
+                    .then(no_stop)
+                    .then(end())
+                    .not()
+                    .ignore_then(any())
+                    .repeated()
+            }
+        };
+    }
+    if let (Capture::Escaped(escape), Some(matcher)) = (capture, stops.matcher()) {
+        return quote! {
+            just::<char, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#escape)
+                .ignore_then(any())
+                .ignored()
+                .or(#matcher
+                    .not()
+                    .ignore_then(any())
+                    .ignored())
+                .repeated()
+        };
+    }
+    if let Some(matcher) = stops.matcher() {
         quote! {
-            just::<&str, &str, chumsky::extra::Err<chumsky::error::Rich<char>>>(#next_lit)
+            #matcher
                 .not()
                 .ignore_then(any())
                 .repeated()
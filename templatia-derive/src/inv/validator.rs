@@ -1,30 +1,356 @@
-use crate::error::generate_consecutive_compile_error;
-use crate::fields::{FieldKind, Fields};
-use crate::parser::TemplateSegments;
-use crate::utils::is_allowed_consecutive_allowed_type;
+use crate::error::{generate_compile_error, generate_consecutive_compile_error};
+use crate::fields::{FieldKind, Fields, MatchStrategy};
+use crate::inv::parser::{replace_key, scans_digits};
+use crate::parser::{PlaceholderSpec, RangeBounds, TemplateSegments, field_segments};
+use crate::utils::{
+    boxed_inner, get_type_name, is_allowed_consecutive_allowed_type, is_borrowed_str,
+    is_integer_type, is_number_type,
+};
 
 pub(crate) fn validate_template_safety(
     segments: &[TemplateSegments],
     fields: &Fields,
 ) -> Result<(), proc_macro2::TokenStream> {
-    for window in segments.windows(2) {
-        if let [
-            TemplateSegments::Placeholder(first),
-            TemplateSegments::Placeholder(second),
-        ] = window
-        {
-            let first_type = fields.get_type_kind_by_name(first);
-            let (allowed_consecutive, first_type_name) = match first_type {
-                Some(field) => match field {
-                    FieldKind::Option(ty) => {
-                        (is_allowed_consecutive_allowed_type(ty), field.to_string())
-                    }
-                    FieldKind::Primitive(ty) => {
-                        (is_allowed_consecutive_allowed_type(ty), field.to_string())
-                    }
-                    _ => (false, field.to_string()),
+    for segment in segments {
+        if let TemplateSegments::Include(name) = segment
+            && segments.iter().any(
+                |other| matches!(other, TemplateSegments::Placeholder(other, _) if other == name),
+            )
+        {
+            return Err(generate_compile_error(&format!(
+                "\"{0}\" is used both as a placeholder {{{0}}} and an include {{>{0}}}. \
+                a field can be used only in one way",
+                name
+            )));
+        }
+    }
+
+    let all_fields = field_segments(segments);
+    for (segment, in_group) in &all_fields {
+        if let TemplateSegments::Block(name, body) = segment {
+            validate_block(name, body, *in_group, &all_fields, fields)?;
+        }
+    }
+    for (index, segment) in segments.iter().enumerate() {
+        if let TemplateSegments::Extras(name) = segment {
+            validate_extras(name, index, segments, &all_fields, fields)?;
+        }
+    }
+    for (segment, _) in &all_fields {
+        let TemplateSegments::Placeholder(
+            name,
+            PlaceholderSpec {
+                default: Some(_), ..
+            },
+        ) = segment
+        else {
+            continue;
+        };
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        if fields.get_with(&ident).is_some()
+            || !matches!(
+                fields.get_field_kind(&ident),
+                Some(FieldKind::Primitive(_) | FieldKind::Option(_))
+            )
+            || matches!(fields.get_field_kind(&ident), Some(FieldKind::Option(ty)) if boxed_inner(ty).is_some())
+        {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" has an inline default, which is supported only for fields parsed with FromStr \
+                and their Option<T>",
+                name
+            )));
+        }
+        if fields.get_default(&ident).is_some() {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" has both an inline default and #[templatia(default = \"...\")]. use only one of them",
+                name
+            )));
+        }
+    }
+    for (segment, _) in &all_fields {
+        let TemplateSegments::Placeholder(name, spec) = segment else {
+            continue;
+        };
+        if spec.filters.is_none() && spec.terminator.is_none() {
+            continue;
+        }
+        // Borrowed values would point into the text with the filters undone, which doesn't outlive the parse.
+        let borrowed = match fields.get_type_kind_by_name(name) {
+            Some(FieldKind::Primitive(ty) | FieldKind::Option(ty)) => {
+                is_borrowed_str(ty) || boxed_inner(ty).is_some()
+            }
+            _ => false,
+        };
+        if borrowed {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" has filters or a heredoc terminator, which are not supported \
+                for borrowed &str and Option<Box<T>> fields",
+                name
+            )));
+        }
+    }
+    for (segment, _) in &all_fields {
+        let TemplateSegments::Placeholder(
+            name,
+            PlaceholderSpec {
+                separator: Some(separator),
+                ..
+            },
+        ) = segment
+        else {
+            continue;
+        };
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        if fields.get_with(&ident).is_some()
+            || !matches!(
+                fields.get_field_kind(&ident),
+                Some(
+                    FieldKind::Vec(_)
+                        | FieldKind::HashSet(_)
+                        | FieldKind::BTreeSet(_)
+                        | FieldKind::VecDeque(_)
+                        | FieldKind::LinkedList(_)
+                        | FieldKind::BinaryHeap(_)
+                        | FieldKind::IndexSet(_)
+                        | FieldKind::SmallVec(_)
+                        | FieldKind::ArrayVec(_)
+                        | FieldKind::HashMap(..)
+                        | FieldKind::BTreeMap(..)
+                        | FieldKind::IndexMap(..)
+                )
+            )
+        {
+            return Err(generate_compile_error(&format!(
+                "{{{}*\"{}\"}} joins the elements of a collection, so it's supported only for \
+                collection fields such as Vec<T>, HashSet<T>, and HashMap<K, V> without `with`",
+                name, separator
+            )));
+        }
+    }
+    for (segment, _) in &all_fields {
+        if let TemplateSegments::Placeholder(name, spec) = segment
+            && let Some(range) = spec.range()
+        {
+            validate_range(name, range, fields)?;
+        }
+    }
+    for (segment, _) in &all_fields {
+        if let TemplateSegments::Placeholder(
+            name,
+            PlaceholderSpec {
+                type_hint: Some(hint),
+                ..
+            },
+        ) = segment
+        {
+            validate_type_hint(name, hint, fields)?;
+        }
+    }
+    for (segment, in_group) in &all_fields {
+        if !in_group {
+            continue;
+        }
+        let (TemplateSegments::Placeholder(name, _) | TemplateSegments::Include(name)) = segment
+        else {
+            continue;
+        };
+        if !matches!(segment, TemplateSegments::Placeholder(..))
+            || !matches!(
+                fields.get_type_kind_by_name(name),
+                Some(FieldKind::Option(_))
+            )
+        {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" is inside an optional group [...]. \
+                only placeholders of Option<T> fields can be used in optional groups",
+                name
+            )));
+        }
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        if fields.get_match_strategy(&ident) == MatchStrategy::Greedy {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" is inside an optional group [...] and cannot use greedy matching",
+                name
+            )));
+        }
+        if all_fields
+            .iter()
+            .filter(|(other, _)| other.field_name() == Some(name))
+            .count()
+            > 1
+        {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" is inside an optional group [...] and cannot be used more than once",
+                name
+            )));
+        }
+    }
+
+    // A rest capture consumes the remaining input, so nothing can follow it.
+    for (index, (segment, in_group)) in all_fields.iter().enumerate() {
+        let Some(name) = segment.field_name() else {
+            continue;
+        };
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        let is_last = !in_group
+            && index == all_fields.len() - 1
+            && matches!(segments.last(), Some(TemplateSegments::Placeholder(last, _)) if *last == name);
+        if fields.is_rest(&ident) && !is_last {
+            return Err(generate_compile_error(&format!(
+                "\"{}\" captures the rest of the input and must be the last segment of the template",
+                name
+            )));
+        }
+    }
+
+    // Groups are inlined so that a placeholder directly followed by a group's placeholder is also checked.
+    // Negated lookaheads don't end a capture, so the segments around them are checked as neighbors.
+    let flattened = flatten_groups(segments);
+
+    // Aliases replace the key written right before the placeholder, so at least one occurrence needs such a key.
+    let mut alias_checked = Vec::new();
+    for segment in &flattened {
+        let TemplateSegments::Placeholder(name, _) = segment else {
+            continue;
+        };
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        let Some(alias) = fields.get_aliases(&ident).first() else {
+            continue;
+        };
+        if alias_checked.contains(name) {
+            continue;
+        }
+        alias_checked.push(name);
+
+        let key = fields.placeholder_name(&ident);
+        let keyed = flattened.windows(2).any(|window| {
+            matches!(
+                window,
+                [TemplateSegments::Literal(lit), TemplateSegments::Placeholder(other, _)]
+                    if other == name && replace_key(lit, &key, alias).is_some()
+            )
+        });
+        if !keyed {
+            return Err(generate_compile_error(&format!(
+                "\"{0}\" has an alias but is not preceded by its key in the template. \
+                aliases replace the key right before the placeholder like \"{0}={{{0}}}\"",
+                key
+            )));
+        }
+    }
+
+    // The terminator of a heredoc is recognized only on a line of its own.
+    for (index, segment) in flattened.iter().enumerate() {
+        if let TemplateSegments::Placeholder(
+            name,
+            PlaceholderSpec {
+                terminator: Some(terminator),
+                ..
+            },
+        ) = segment
+            && !flattened.get(index + 1).is_none_or(|next| {
+                matches!(next, TemplateSegments::Literal(lit) if lit.starts_with('\n') || lit.starts_with("\r\n"))
+            })
+        {
+            return Err(generate_compile_error(&format!(
+                "{{{}<<{}}} must be followed by a line break or the end of the template \
+                so that the terminator is on a line of its own",
+                name, terminator
+            )));
+        }
+    }
+
+    // The text of an environment placeholder is skipped up to the next literal, so it can't touch another value.
+    // Blocks end with a literal, so only a block starting with a placeholder can't follow one.
+    for window in flattened.windows(2) {
+        let variable = match window {
+            [TemplateSegments::Env(variable), next] => match next {
+                TemplateSegments::Placeholder(..)
+                | TemplateSegments::Include(_)
+                | TemplateSegments::Env(_) => Some(variable),
+                TemplateSegments::Block(_, body) => {
+                    (!matches!(body.first(), Some(TemplateSegments::Literal(_))))
+                        .then_some(variable)
+                }
+                _ => None,
+            },
+            [
+                TemplateSegments::Placeholder(..) | TemplateSegments::Include(_),
+                TemplateSegments::Env(variable),
+            ] => Some(variable),
+            _ => None,
+        };
+        if let Some(variable) = variable {
+            return Err(generate_compile_error(&format!(
+                "{{${}}} must be separated from the placeholders next to it by a literal",
+                variable
+            )));
+        }
+    }
+
+    for window in flattened.windows(2) {
+        let ends_capture = |segment: &TemplateSegments| match segment {
+            TemplateSegments::Placeholder(..)
+            | TemplateSegments::Include(_)
+            | TemplateSegments::Env(_) => true,
+            TemplateSegments::Block(_, body) => {
+                !matches!(body.first(), Some(TemplateSegments::Literal(_)))
+            }
+            _ => false,
+        };
+        if let [first, second] = window
+            && let (TemplateSegments::Env(variable), other)
+            | (other, TemplateSegments::Env(variable)) = (*first, *second)
+            && ends_capture(other)
+            && !matches!(other, TemplateSegments::Block(..) if matches!(first, TemplateSegments::Block(..)))
+        {
+            return Err(generate_compile_error(&format!(
+                "{{${}}} must be separated from the placeholders next to it by a literal",
+                variable
+            )));
+        }
+    }
+
+    for window in flattened.windows(2) {
+        if let [first_segment, second_segment] = window
+            && let (Some(first), Some(second)) =
+                (first_segment.field_name(), second_segment.field_name())
+        {
+            // Blocks end with a literal, and a block starting with a literal ends the capture before it.
+            if matches!(first_segment, TemplateSegments::Block(..))
+                || matches!(
+                    second_segment,
+                    TemplateSegments::Block(_, body) if matches!(body.first(), Some(TemplateSegments::Literal(_)))
+                )
+            {
+                continue;
+            }
+            // Included templates are captured up to the next literal, so they can never be consecutive.
+            let first_ident = syn::Ident::new(first, proc_macro2::Span::call_site());
+            let (allowed_consecutive, first_type_name) = match first_segment {
+                TemplateSegments::Include(_) => (false, "included template".to_string()),
+                // Fixed-width values end after their width, so anything can follow them.
+                _ if fields.get_width(&first_ident).is_some() => (true, String::new()),
+                // Quoted values end at their closing quote.
+                _ if fields.is_quoted(&first_ident) => (true, String::new()),
+                // Integers with a type hint are scanned as digits, so they end at the first other character.
+                _ if scans_digits(first_segment, fields).is_some() => (true, String::new()),
+                // Values of `with` modules are captured up to the next literal like strings.
+                _ if fields.get_with(&first_ident).is_some() => {
+                    (false, "custom `with` conversion".to_string())
+                }
+                _ => match fields.get_type_kind_by_name(first) {
+                    Some(field) => match field {
+                        FieldKind::Option(ty) => {
+                            (is_allowed_consecutive_allowed_type(ty), field.to_string())
+                        }
+                        FieldKind::Primitive(ty) => {
+                            (is_allowed_consecutive_allowed_type(ty), field.to_string())
+                        }
+                        _ => (false, field.to_string()),
+                    },
+                    None => (false, "unrecognized".to_string()),
                 },
-                None => (false, "unrecognized".to_string()),
             };
 
             if !allowed_consecutive {
@@ -39,3 +365,217 @@ pub(crate) fn validate_template_safety(
 
     Ok(())
 }
+
+/// Checks a `{#field}...{/field}` block: the field is a `Vec` of a struct used only by this block,
+/// and the body has distinct placeholders separated by literals and ends with a literal that
+/// terminates each element.
+fn validate_block(
+    name: &str,
+    body: &[TemplateSegments],
+    in_group: bool,
+    all_fields: &[(&TemplateSegments, bool)],
+    fields: &Fields,
+) -> Result<(), proc_macro2::TokenStream> {
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let is_struct_vec = matches!(
+        fields.get_type_kind_by_name(name),
+        Some(FieldKind::Vec(syn::Type::Path(path))) if path.qself.is_none()
+    );
+    if !is_struct_vec || fields.get_with(&ident).is_some() {
+        return Err(generate_compile_error(&format!(
+            "block {{#{}}} can be used only on Vec<T> fields of a struct type T without `with`",
+            name
+        )));
+    }
+    if in_group
+        || all_fields
+            .iter()
+            .filter(|(other, _)| other.field_name() == Some(name))
+            .count()
+            > 1
+    {
+        return Err(generate_compile_error(&format!(
+            "block {{#{}}} must be used once and outside optional groups",
+            name
+        )));
+    }
+    let placeholders = body
+        .iter()
+        .filter_map(TemplateSegments::field_name)
+        .collect::<Vec<_>>();
+    if placeholders.is_empty() {
+        return Err(generate_compile_error(&format!(
+            "block {{#{}}} must contain a placeholder of the element",
+            name
+        )));
+    }
+    if let Some(duplicate) = placeholders
+        .iter()
+        .enumerate()
+        .find_map(|(index, name)| placeholders[..index].contains(name).then_some(name))
+    {
+        return Err(generate_compile_error(&format!(
+            "\"{}\" is used more than once in block {{#{}}}",
+            duplicate, name
+        )));
+    }
+    let separated = body.windows(2).all(|window| {
+        !matches!(
+            window,
+            [
+                TemplateSegments::Placeholder(..),
+                TemplateSegments::Placeholder(..)
+            ]
+        )
+    });
+    if !separated || !matches!(body.last(), Some(TemplateSegments::Literal(_))) {
+        return Err(generate_compile_error(&format!(
+            "the placeholders of block {{#{0}}} must be separated by literals, and the body must end \
+            with a literal like \"\\n\" that ends each element",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that the type hint of `{field:type}` names the declared type of the field, or the inner
+/// type of an `Option` field. Types are compared by their last path segment, so `std::num::NonZeroU16`
+/// and `NonZeroU16` are the same.
+fn validate_type_hint(
+    name: &str,
+    hint: &str,
+    fields: &Fields,
+) -> Result<(), proc_macro2::TokenStream> {
+    let Ok(hint_type) = syn::parse_str::<syn::Type>(hint) else {
+        return Err(generate_compile_error(&format!(
+            "\"{}\" has the type hint \"{}\", which is not a type",
+            name, hint
+        )));
+    };
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    // SAFETY: The placeholder is always in the fields, as checked at the start of generate_str_parser.
+    let declared = fields.get_field_type(&ident).unwrap();
+    let same_type = |ty: &syn::Type| match (get_type_name(ty), get_type_name(&hint_type)) {
+        (a, b) if a != "unrecognized" && b != "unrecognized" => a == b,
+        _ => quote::quote!(#ty).to_string() == quote::quote!(#hint_type).to_string(),
+    };
+    let matches = same_type(declared)
+        || matches!(fields.get_field_kind(&ident), Some(FieldKind::Option(inner)) if same_type(inner));
+    if !matches {
+        return Err(generate_compile_error(&format!(
+            "\"{}\" has the type hint {} but is declared as {}",
+            name,
+            quote::quote!(#hint_type),
+            quote::quote!(#declared),
+        )));
+    }
+    Ok(())
+}
+
+fn flatten_groups<'s, 'a>(segments: &'s [TemplateSegments<'a>]) -> Vec<&'s TemplateSegments<'a>> {
+    segments
+        .iter()
+        .flat_map(|segment| match segment {
+            TemplateSegments::Group(inner) => flatten_groups(inner),
+            TemplateSegments::Lookahead(_, true) => Vec::new(),
+            _ => vec![segment],
+        })
+        .collect()
+}
+
+/// Checks that a `{*field}` placeholder stands on the last line of the template and collects into
+/// a map of `String` keys and values, which the unknown `key = value` lines of the input fill.
+fn validate_extras(
+    name: &str,
+    index: usize,
+    segments: &[TemplateSegments],
+    all_fields: &[(&TemplateSegments, bool)],
+    fields: &Fields,
+) -> Result<(), proc_macro2::TokenStream> {
+    let at_line_start = match index.checked_sub(1).map(|previous| &segments[previous]) {
+        None => true,
+        Some(TemplateSegments::Literal(lit)) => lit.ends_with('\n'),
+        Some(_) => false,
+    };
+    if !at_line_start || index + 1 != segments.len() {
+        return Err(generate_compile_error(&format!(
+            "{{*{}}} must start the last line of the template and end the template",
+            name
+        )));
+    }
+    if all_fields
+        .iter()
+        .filter(|(other, _)| other.field_name() == Some(name))
+        .count()
+        > 1
+    {
+        return Err(generate_compile_error(&format!(
+            "\"{}\" collects the extra lines with {{*{}}} and cannot be used more than once",
+            name, name
+        )));
+    }
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let is_string = |ty: &syn::Type| get_type_name(ty) == "String";
+    let is_string_map = matches!(
+        fields.get_field_kind(&ident),
+        Some(FieldKind::HashMap(key, value) | FieldKind::BTreeMap(key, value))
+            if is_string(key) && is_string(value)
+    );
+    if !is_string_map || fields.get_with(&ident).is_some() {
+        return Err(generate_compile_error(&format!(
+            "{{*{}}} requires a HashMap<String, String> or BTreeMap<String, String> field without `with`",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that a `{field:min..max}` range constrains a number field with bounds of its type,
+/// and that some number can fall in it.
+fn validate_range(
+    name: &str,
+    range: RangeBounds,
+    fields: &Fields,
+) -> Result<(), proc_macro2::TokenStream> {
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let value_type = match fields.get_field_kind(&ident) {
+        Some(
+            FieldKind::Primitive(ty)
+            | FieldKind::Option(ty)
+            | FieldKind::Vec(ty)
+            | FieldKind::HashSet(ty)
+            | FieldKind::BTreeSet(ty)
+            | FieldKind::VecDeque(ty)
+            | FieldKind::LinkedList(ty)
+            | FieldKind::BinaryHeap(ty)
+            | FieldKind::IndexSet(ty)
+            | FieldKind::SmallVec(ty)
+            | FieldKind::ArrayVec(ty),
+        ) if fields.get_with(&ident).is_none() => Some(*ty),
+        _ => None,
+    };
+    let Some(value_type) = value_type.filter(|ty| is_number_type(ty)) else {
+        return Err(generate_compile_error(&format!(
+            "\"{}\" has a range, which is supported only for integer and float fields without `with`",
+            name
+        )));
+    };
+    let bounds = range.min.into_iter().chain(range.max);
+    if is_integer_type(value_type) && bounds.clone().any(|bound| bound.parse::<i128>().is_err()) {
+        return Err(generate_compile_error(&format!(
+            "the range of \"{}\" must have integer bounds because the field is an integer",
+            name
+        )));
+    }
+    if let (Some(min), Some(max)) = (range.min, range.max) {
+        // SAFETY: The bounds were checked to be number literals when the placeholder was parsed.
+        let (min, max) = (min.parse::<f64>().unwrap(), max.parse::<f64>().unwrap());
+        if min > max || (min == max && !range.inclusive) {
+            return Err(generate_compile_error(&format!(
+                "the range of \"{}\" is empty. its lower bound must be below its upper bound",
+                name
+            )));
+        }
+    }
+    Ok(())
+}
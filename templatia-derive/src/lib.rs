@@ -3,15 +3,24 @@
 //!
 //! Procedural macros for the templatia template parsing library.
 //!
-//! This crate provides the `#[derive(Template)]` macro that automatically generates
-//! `templatia::Template` trait implementations for named structs.
+//! This crate provides the `#[derive(Template)]` macro that automatically generates the
+//! `templatia::RenderTemplate` and `templatia::ParseTemplate` implementations, and through them
+//! `templatia::Template`, for named structs, fieldless enums, and unions.
 //!
 //! ## Limitations
 //!
-//! - **Named Structs Only**: Currently only `struct Name { field: Type }` is supported
+//! - **Named Structs, Unit Enums, and Unions Only**: `struct Name { field: Type }`, `enum Name { A, B }`,
+//!   and unions with a designated active field and `unsafe_union` are supported
 //! - **No Tuple Structs**: `struct Point(i32, i32)` is not supported yet
-//! - **No Enums**: Enum support is planned for future versions
-//! - **Field Requirements**: Template fields must implement `Display`, `FromStr`, and `PartialEq`
+//! - **No Data-carrying Enums**: Enums whose variants have fields are not supported yet
+//! - **Field Requirements**: The bounds are split by impl. `RenderTemplate` needs `Display` on the
+//!   rendered fields. `ParseTemplate` needs `FromStr` with a `Display` error and `PartialEq` on the
+//!   parsed fields, and `Display` on fields that are repeated in the template or have a range, since
+//!   their values go into the errors. `render_only` and `parse_only` leave out the other impl with
+//!   its bounds, and `skip_render` and `skip_parse` drop the bound of their side. Fields with `with`
+//!   go through the module instead, `&str` fields borrow from the input without `FromStr`,
+//!   `PathBuf` and `OsString` render without `Display`, and `datetime_format` fields use the format
+//!   instead of `Display` and `FromStr`
 //!
 //! ## Attribute Reference
 //!
@@ -23,66 +32,1120 @@
 //! - Placeholders must match struct field names exactly
 //! - All placeholders must reference existing fields
 //! - Duplicate placeholders are allowed but must have consistent values during parsing
+//! - `{{` and `}}` are escaped braces. Opening braces pair up from the left and a placeholder ends
+//!   at its first `}`, so `{{{field}}}` renders the value between literal braces
+//! - A number like `{0}` refers to the field at that position in declaration order, so
+//!   `"{0}:{1}"` is a shorthand for `"{host}:{port}"` on `struct { host: String, port: u16 }`.
+//!   A position beyond the last field fails to compile
+//!
+//! ### `#[templatia(template_file = "...")]`
+//!
+//! Reads the template from a file at compile time instead of the attribute string. The path is
+//! resolved relative to the `CARGO_MANIFEST_DIR` of the crate using the derive, and the file
+//! content is used verbatim (including a trailing newline, if any). Editing the file triggers a
+//! rebuild. Cannot be combined with `template`.
+//!
+//! ### `{>field}` includes
+//!
+//! A placeholder written as `{>field}` splices the template of a field whose type itself
+//! implements `Template` (e.g., a shared header struct). Rendering delegates to the field's
+//! `render_string`, and parsing captures the text up to the next literal and delegates to the
+//! field's `from_str`. Failures of the included template are reported as `TemplateError::Include`.
+//! Because the captured text ends at the next literal, the included output must not contain it,
+//! and an include cannot be directly followed by another placeholder.
+//!
+//! Including a `Vec<T>`, `VecDeque<T>`, or `LinkedList<T>` field where `T: Template` renders each
+//! element through its own template, joined by the field's `separator` (`,` by default). Parsing
+//! splits the captured text on the separator and delegates each part to `T::from_str`, and an
+//! empty capture is an empty sequence. The rendered elements must not contain the separator.
+//!
+//! ### `{#- ... -#}` comments
+//!
+//! Text between `{#-` and `-#}` is removed while the template is parsed at compile time, so it
+//! neither renders nor needs to appear in the input. A comment standing alone on its line is
+//! removed with that line, and a comment within a line leaves the text around it joined.
+//!
+//! ### `{% raw %}...{% endraw %}` raw sections
+//!
+//! Text between `{% raw %}` and `{% endraw %}` is a literal as written, so braces, brackets, and
+//! parentheses in embedded JSON or code don't need to be doubled (e.g.,
+//! `"{% raw %}{\"id\": {% endraw %}{id}{% raw %}}{% endraw %}"`). Spaces inside the tag delimiters
+//! are optional, and an unclosed raw section fails to compile.
+//!
+//! ### `{?=text}` and `{?!text}` lookaheads
+//!
+//! A lookahead checks the input ahead when parsing without consuming it, and renders nothing.
+//! `{?=text}` requires the text to come next and ends the capture of the placeholder before it
+//! there, so two free-form placeholders can be told apart by a sentinel that starts the second
+//! one (e.g., `"{title}{?=---}{body}"` parses `Hi---there` into `Hi` and `---there`).
+//! `{?!text}` rejects the input when the text comes next, such as `"key={?!\"}{value}"` for
+//! unquoted values. The text is kept verbatim, including spaces, and can't contain braces.
+//!
+//! ### `(a|b)` alternations with `#[templatia(alternation)]`
+//!
+//! With the container attribute `#[templatia(alternation)]`, a parenthesized list of spellings
+//! separated by `|`, as in `(http|https)://{host}`, accepts any of them when parsing and renders
+//! the first one. Spellings are plain text and may be empty, as in `color(s|)`. Parentheses
+//! without `|`, or around placeholders, brackets, or line breaks, stay literal text, and `((` and
+//! `))` are escaped parentheses displayed as `(` and `)`. Without the attribute, parentheses are
+//! always literal text, so templates such as `"f(x|y)"` and `"((x))"` render as written.
+//!
+//! ### `{field:type}` type hints
+//!
+//! A placeholder written as `{port:u16}` states the type of the field in the template, and the
+//! derive fails to compile when it doesn't match the declared type (or the inner type of an
+//! `Option<T>`). Types are compared by their last path segment. A hinted integer field is
+//! captured as ASCII digits after an optional sign instead of up to the next literal, so it can
+//! be directly followed by another placeholder, as in `{size:u32}{unit}`. Fields with `with`,
+//! `radix`, `format`, `width`, `escape`, `quoted`, `rest`, `until_eol`, `trim`, or greedy
+//! matching keep their own capture. A hint can be combined with an inline default as
+//! `{port:u16=8080}`.
+//!
+//! ### `{field:min..max}` ranges
+//!
+//! A placeholder written as `{port:1..=65535}` rejects parsed numbers outside the range with
+//! `TemplateError::OutOfRange`, like the `min` and `max` attributes. `..=` includes the upper bound
+//! and `..` excludes it, and either bound can be left out (e.g., `{ratio:0..}`). The bounds are
+//! number literals, which must be integers for integer fields, and they apply to each value of
+//! `Option` and collection fields. Rendering is unchanged.
+//!
+//! ### `{field:modifier}` modifiers
+//!
+//! The per-field options `trim`, `uppercase`, `lowercase`, `quoted`, `rest`, `until_eol`, and
+//! `width=N` can be written after `:` in a placeholder, as in `{name:trim}` or `{id:width=6}`,
+//! and work like the field attribute of the same name. Several modifiers are separated by `,`
+//! and follow a type hint or range if any (e.g., `{id:u32,width=6}`). A modifier applies to the
+//! field in every template of the type, and combinations that the attributes can't make fail to
+//! compile in the same way.
+//!
+//! ### `{field|filter}` filters
+//!
+//! Filters after the name transform the rendered value, applied left to right as in
+//! `{tag|trim|lower}`. The built-in filters are `upper`, `lower`, `trim`, and `url_encode`.
+//! When parsing, the filters that can be undone are undone in reverse order before the value
+//! is parsed: `trim` trims the captured text and `url_encode` percent-decodes it. The case
+//! filters can't restore the original case, so the text is parsed as captured. Filters are
+//! written before an inline default, as in `{name|upper=GUEST}`, and aren't available for
+//! borrowed `&str` and `Option<Box<T>>` fields.
+//!
+//! ### `{$NAME}` environment variables
+//!
+//! A placeholder written as `{$HOME}` renders the environment variable read when `render_string`
+//! runs, or nothing when it isn't set. The variable isn't a field, so parsing skips its text: the
+//! current value of the variable when the input has it there, and otherwise any text up to the
+//! next literal, since the input may come from another environment.
+//! An environment placeholder must be separated from the placeholders next to it by a literal.
+//!
+//! ### `{*field}` extra lines
+//!
+//! A placeholder written as `{*extras}` on the last line of the template collects the input lines
+//! that no line of the template matches into a `HashMap<String, String>` or
+//! `BTreeMap<String, String>` field, so the unknown entries of a `key = value` file round-trip.
+//! Lines are matched by their key, the text before the first placeholder, like
+//! `ignore_unknown_lines`, and an unknown line is split at its first `=` with the key and value
+//! trimmed. Rendering writes each entry as a `key = value` line sorted by key, and nothing when the
+//! map is empty. It must start the last line and end the template (e.g.,
+//! `"host = {host}\n{*extras}"`), and it's not available with `find` or for structs borrowing `&str`
+//! from the input.
+//!
+//! ### `{field<<END}` heredocs
+//!
+//! A placeholder written as `{body<<END}` holds a multi-line value that is rendered followed by a
+//! line break and the terminator line `END`. Parsing captures any text, including line breaks and
+//! the other literals of the template, up to a line holding only the terminator, and the template
+//! continues after that line, so a heredoc must be followed by a line break or the end of the
+//! template. The value must not contain the terminator line itself: rendering such a value panics
+//! in debug builds, and in release builds its output fails to parse. Heredocs aren't available
+//! for borrowed `&str` and `Option<Box<T>>` fields.
+//!
+//! ### Field attribute `#[templatia(default = "...")]`
+//!
+//! Constructs the field from the given expression when the field is missing from the template
+//! or its captured value is empty, instead of `Default::default()` (or `None` for `Option<T>`).
+//! The expression must evaluate to the field type and is resolved in the scope of the struct,
+//! e.g., `#[templatia(default = "Duration::from_secs(30)")]`. Fields with a default expression
+//! may be left out of the template without `allow_missing_placeholders`.
+//!
+//! ### `{field=default}` inline defaults
+//!
+//! A placeholder written as `{port=8080}` parses the text after `=` with `FromStr` when the
+//! captured value is empty, like `#[templatia(default = "...")]` with the default written as
+//! text in the template. For an `Option<T>` field the default becomes `Some`, and `None` is
+//! rendered as the default text unless `render_none` is set. The text is used verbatim and
+//! can't contain braces. Inline defaults are available for fields parsed with `FromStr` and
+//! their `Option<T>`, and can't be combined with the `default` attribute on the same field.
+//!
+//! ### `{field:spec}` format specs
+//!
+//! A `format!` spec after `:` in a placeholder, as in `{price:.2}`, `{id:>8}`, or `{name:*^10}`,
+//! works like `#[templatia(format = "{:spec}")]` and can be combined with modifiers (e.g.,
+//! `{name:<12,uppercase}`). The spec takes the usual fill, alignment, sign, `#`, `0`, width, and
+//! precision, optionally followed by one of the types `x`, `X`, `b`, `o`, `e`, and `E`. A spec with a
+//! width also trims the captured text when parsing, and strips another fill character from the
+//! sides its alignment pads, so padded values parse back. Values starting or ending with that fill
+//! character themselves lose it, so a fill that can't appear in the values fits best. As with the
+//! attribute, the rendered text should stay parseable, so `#x` needs a field that parses the prefix.
+//!
+//! ### Field attribute `#[templatia(rename = "...")]`
+//!
+//! References the field by the given placeholder name instead of its Rust identifier, e.g.,
+//! `{db}` for `#[templatia(rename = "db")] database_url: String`. The renamed placeholder is used
+//! by the default template and reported in errors; the original field name can no longer be used
+//! as a placeholder.
+//!
+//! ### Field attribute `#[templatia(with = "module")]`
+//!
+//! Converts the field through the functions of the given module instead of `Display`/`FromStr`,
+//! which allows types without those traits or with a custom text format:
+//! - `fn render(value: &T) -> String`
+//! - `fn parse(s: &str) -> Result<T, E>`
+//!
+//! A parse failure is reported as `TemplateError::ParseToType`, and duplicate placeholders are
+//! compared by their rendered text. The field type itself needs no trait bounds.
+//!
+//! ### Field attribute `#[templatia(format = "...")]`
+//!
+//! Renders each value of the field with the given `format!` string instead of `Display`,
+//! e.g., `"{:08}"` for zero padding or `"{:.2}"` for fixed precision. The string must contain
+//! exactly one `{}` or `{:spec}` argument. Parsing is unaffected and still uses `FromStr`, so
+//! the formatted output should stay parseable (`"00000042"` parses back to `42`).
+//! For `Option<T>` and collections the format applies to each contained value.
+//!
+//! ### Field attribute `#[templatia(trim)]`
+//!
+//! Trims leading and trailing whitespace of the captured text before it is parsed, so padded
+//! input such as `"port =  8080 "` doesn't fail with `TemplateError::ParseToType`. For
+//! collections each element is trimmed as well (`"a, b"` parses as `["a", "b"]`).
+//! Rendering is unaffected.
+//!
+//! ### Field attributes `#[templatia(uppercase)]` and `#[templatia(lowercase)]`
+//!
+//! Renders the value converted to upper or lower case, e.g., for log levels. Parsing accepts
+//! either case: the captured text is passed to `FromStr` as is, then in lower and upper case.
+//! The two attributes are exclusive and can't be combined with `with`.
+//!
+//! ### `#[templatia(separator = "...")]`
+//!
+//! Sets the delimiter between the elements of `Vec<T>`, `VecDeque<T>`, `LinkedList<T>`,
+//! `BinaryHeap<T>`, `HashSet<T>`, and `BTreeSet<T>` fields, which is `,` by default. On the
+//! struct it changes the default of all fields, and on a field it applies to that field only
+//! (e.g., `"hosts={hosts}"` with `separator = ";"` renders `hosts=a;b`). The separator must not
+//! be empty. A `BinaryHeap` renders its values in ascending order, and a `HashSet` renders its
+//! elements sorted by their rendered text, so the output is the same on every run.
+//!
+//! An empty segment parses as an empty collection. A collection whose only element renders as
+//! empty text, such as `vec![String::new()]`, `vec![None]`, or a set holding only `""`, renders
+//! the same empty segment and therefore parses back as an empty collection.
+//!
+//! ### Sparse sequences like `Vec<Option<T>>`
+//!
+//! The elements of `Vec<T>`, `VecDeque<T>`, and `LinkedList<T>` fields can be `Option`s. A `None`
+//! renders as an empty slot between the separators, so `[Some(1), None, Some(3)]` renders `1,,3`,
+//! and empty elements parse back to `None`. An empty segment is still an empty collection, so a
+//! sequence of a single `None` parses back as an empty sequence, as described under `separator`.
+//!
+//! ### `#[templatia(entry_sep = "...", kv_sep = "...")]`
+//!
+//! `HashMap<K, V>` and `BTreeMap<K, V>` fields render as entries of the key and the value joined
+//! by `kv_sep`, which is `=` by default, and the entries are joined by `entry_sep`, which is the
+//! `separator` by default, so `{env}` renders `a=1,b=2`. With `entry_sep = ";", kv_sep = ":"`
+//! it renders `a:1;b:2` and parses accordingly. `HashMap` entries are sorted by their rendered
+//! text, and `BTreeMap` entries come in key order. Like `separator`, both can be set on the
+//! struct for all map fields or on a map field for that field only, and must not be empty.
+//!
+//! ### `IndexMap<K, V>` and `IndexSet<T>` fields
+//!
+//! With the `indexmap` feature of templatia, `IndexMap` and `IndexSet` fields of the `indexmap`
+//! crate are collections like `HashMap` and `HashSet`, except that they render in insertion order
+//! and parse in the order of the input, so the key order of a config file survives a round-trip.
+//! Without the feature, they are unsupported types.
+//!
+//! ### `SmallVec<[T; N]>` and `ArrayVec<T, N>` fields
+//!
+//! With the `smallvec` and `arrayvec` features of templatia, `SmallVec` and `ArrayVec` fields are
+//! sequences like `Vec<T>`, including sparse elements and the `separator`. An `ArrayVec` can't
+//! grow past its capacity, so parsing more values fails with `TemplateError::OutOfRange` instead
+//! of panicking. Without the features, they are unsupported types.
+//!
+//! ### `{field*"..."}` inline separators
+//!
+//! A collection placeholder written as `{tags*", "}` joins the elements with the text between the
+//! quotes and splits on it when parsing, in place of the `separator` of the field, so `"a, b, c"`
+//! round-trips without an attribute. It applies to that placeholder only, is kept verbatim
+//! including spaces, and must not be empty or contain braces.
+//!
+//! ### Field attribute `#[templatia(validate = "path::to::fn")]`
+//!
+//! Calls `fn(&T) -> Result<(), String>` with each parsed value of the field. An `Err` fails
+//! `from_str` with `TemplateError::Validation`, which carries the placeholder name and the
+//! returned message. Values constructed from a default are validated as well. The value is passed
+//! by reference, so deref coercion applies (e.g., `fn(&str)` for a `String` field).
+//!
+//! ### Field attribute `#[templatia(secret)]`
+//!
+//! Renders a mask instead of the value, which is useful for log-safe dumps of passwords or tokens.
+//! The mask is `***` by default and can be set with `#[templatia(secret = "<redacted>")]`.
+//! A `None` value of an `Option<T>` field renders empty. Parsing still reads the real value, so
+//! rendered output doesn't round-trip for such fields.
+//!
+//! ### Field attribute `#[templatia(alias = "...")]`
+//!
+//! Accepts another spelling of the key written right before the placeholder when parsing,
+//! e.g., `"hostname={host}"` for the template `"host={host}"`, so config keys can be renamed
+//! while old input stays readable. Rendering always uses the template. The attribute can be
+//! repeated, and the placeholder must be preceded by its key (its name followed only by
+//! punctuation or whitespace such as `=` or `: `).
+//!
+//! ### Field attributes `#[templatia(empty_str_is_none)]` and `#[templatia(empty_str_is_some)]`
+//!
+//! Override `empty_str_option_not_none` for a single `Option` field, so that an empty capture is
+//! parsed as `None` or as `Some("")` regardless of the struct-level setting. They are exclusive
+//! and can be used only on `Option<T>` fields.
+//!
+//! ### Field attribute `#[templatia(width = N)]`
+//!
+//! The placeholder consumes exactly `N` characters when parsing, so it can be directly followed
+//! by another placeholder as in fixed-width record formats (e.g., `"{code}{name}{amount}"`).
+//! Values are padded to the width on render with the default `Display` alignment (`None` renders
+//! as spaces), and the padding is trimmed before parsing. A longer value would shift the fields
+//! after it, so rendering panics on it in debug builds and cuts it to the width in release builds.
+//! It can't be combined with `with`, and an explicit `format` replaces the padding.
+//!
+//! ### Field attribute `#[templatia(match = "lazy" | "greedy")]`
+//!
+//! Chooses where the capture of a placeholder ends. `lazy`, the default, stops at the first
+//! occurrence of the next literal. `greedy` consumes up to the last occurrence that still leaves
+//! enough occurrences for the literals after it, so values may contain the literal
+//! (e.g., `"{path}.{ext}"` parses `"archive.tar.gz"` as `archive.tar` and `gz`). Literals inside
+//! optional groups are not counted, and greedy placeholders can't be used inside groups.
+//!
+//! ### Field attribute `#[templatia(escape = '\\')]`
+//!
+//! Makes values containing the literal right after the placeholder round-trip. Rendering prefixes
+//! the escape character to each occurrence of that literal and of the escape character itself,
+//! and parsing skips escaped literals and removes the escape characters
+//! (e.g., `"{a},{b}"` renders `a: "x,y"` as `x\,y,...`). It can be used on `T` and `Option<T>`
+//! fields, but not on borrowed `&str` fields or with `with`, `width`, or greedy matching.
+//!
+//! On a sequence or set field like `Vec<String>`, the separator inside each element is escaped as
+//! well, and parsing splits only on the unescaped separators before unescaping each element, so
+//! `tags: vec!["a,b", "c"]` renders `a\,b,c` and parses back to the same elements. On a map field,
+//! `entry_sep` and `kv_sep` are escaped in each key and value, so `{"a=b": "c,d"}` renders
+//! `a\=b=c\,d`. Without `escape`, map keys and values containing either delimiter don't round-trip.
+//!
+//! ### Field attribute `#[templatia(quoted)]`
+//!
+//! Renders the value as a double-quoted token with `"` and `\` escaped by a backslash, and parses
+//! it by reading the quoted token instead of searching for the next literal. Values may therefore
+//! contain any literal of the template, and a quoted placeholder can be directly followed by another
+//! placeholder. A `None` value renders as nothing, while `Some("")` renders as `""`. The same
+//! restrictions as for `escape` apply, and the two can't be combined.
+//!
+//! ### `Result<T, E>` fields and `#[templatia(ok_prefix = "...", err_prefix = "...")]`
+//!
+//! A `Result<T, E>` field renders its value after a prefix telling the variants apart, which is
+//! `ok:` for `Ok` and `err:` for `Err` (e.g., `status=ok:200` or `status=err:timeout`), and parses
+//! the text after the prefix into the variant it names. The field attributes `ok_prefix` and
+//! `err_prefix` change the prefixes, which must differ; one of them may be empty, as the longer
+//! prefix is tried first when parsing. Text starting with neither prefix fails with
+//! `TemplateError::ParseToType`.
+//!
+//! ### `#[templatia(render_none = "...")]`
+//!
+//! Renders `None` of `Option<T>` fields as the given token instead of an empty string, and parses
+//! that token back to `None` (e.g., `"null"`). On the struct it applies to all `Option` fields, and
+//! on a field it applies to that field only. Optional groups are still omitted for `None`.
+//!
+//! ### `#[templatia(none_literals = [...])]`
+//!
+//! Lists tokens that parse to `None` for `Option<T>` fields, such as
+//! `none_literals = ["null", "~", "-"]`, so an absent value can be written visibly instead of as an
+//! empty capture. They are accepted in addition to the `render_none` token, and `None` renders as
+//! the `render_none` token, so `render_none = "null"` makes the rendering visible too. Like
+//! `render_none`, it applies to all `Option` fields on the struct, and a field's own list replaces it.
+//!
+//! ### Field attribute `#[templatia(radix = N)]`
+//!
+//! Renders and parses an integer field in base 2, 8, or 16 (e.g., `ff` for `255` with `radix = 16`).
+//! Parsing uses `from_str_radix` and accepts an optional `0b`, `0o`, or `0x` prefix, so
+//! `format = "{:#x}"` can be added to render with the prefix. It can be used on primitive
+//! integers and on `Option` and collections of them. Negative values render with a sign
+//! (`-10` for `-16`) instead of two's complement unless an explicit `format` is given.
+//!
+//! ### `NonZero` integer fields
+//!
+//! `NonZeroU16`-style integers and `NonZero<T>` are single values however their path is written,
+//! alone, in an `Option`, or in a collection, and a zero in the input fails with
+//! `TemplateError::ZeroValue`. They don't implement `Default`, so a field missing from the template,
+//! skipped with `skip_parse`, or falling back in `from_str_lossy` needs a `default` expression like
+//! `#[templatia(default = "NonZeroU16::new(8080).unwrap()")]`. Without one, the derive reports the
+//! field instead of an unsatisfied `Default` bound.
+//!
+//! ### IP and socket address fields
+//!
+//! `IpAddr`, `Ipv4Addr`, `Ipv6Addr`, `SocketAddr`, `SocketAddrV4`, and `SocketAddrV6` fields
+//! aren't captured up to the next literal, since IPv6 addresses and ports contain `:`. The capture
+//! reads the characters an address can have and keeps the longest prefix that is a valid address,
+//! so `"{ip}:{port}"` parses `127.0.0.1:8080` and `"{addr}: ready"` parses `[::1]:8080: ready`.
+//! A bare IPv6 address followed by `:` and more hex digits is ambiguous, so an address with a port
+//! should be a `SocketAddr`, which brackets IPv6 addresses. The usual capture applies with options
+//! like `width`, `quoted`, `escape`, or greedy matching.
+//!
+//! ### `Version` fields
+//!
+//! With the `semver` feature of templatia, a field written as `semver::Version` (or
+//! `::semver::Version`) is captured like an address:
+//! the capture reads the characters a version can have and keeps the longest valid version,
+//! preferring one that is followed by the first character of the next literal. So
+//! `"{version}.{ext}"` parses `1.2.3.zip`, and `"app-{version}-{os}"` parses both `app-1.2.3-linux`
+//! and `app-2.0.0-beta.1-windows`. Without the feature, or when the type is written as a bare
+//! `Version`, which may be any type of that name, it is captured up to the next literal like
+//! other values.
+//!
+//! ### `PathBuf` and `OsString` fields
+//!
+//! `PathBuf` and `OsString` don't implement `Display`, so they render through `Path::display` and
+//! `OsStr::to_string_lossy`, and parse from the captured text as is. Non-UTF-8 contents are
+//! rendered with replacement characters and don't parse back to the same value. They can be used
+//! alone, in an `Option`, or in a collection, and in duplicate placeholders.
+//!
+//! ### Field attribute `#[templatia(datetime_format = "...")]`
+//!
+//! With the `chrono` feature of templatia, renders and parses a `chrono` value with a
+//! `strftime`-like format instead of its `Display` and `FromStr` (e.g., `"%d/%m/%Y %H:%M"` for
+//! `18/10/2026 09:30`). It can be used on `NaiveDate`, `NaiveTime`, `NaiveDateTime`,
+//! `DateTime<Utc>`, and `DateTime<FixedOffset>` fields and on `Option` and collections of them,
+//! but not with `with`, `format`, or `radix`. A `DateTime<Utc>` format without an offset reads the
+//! value as UTC, and a format `chrono` can't render falls back to `Display`. The feature also lets
+//! `DateTime<Tz>` fields be used without the attribute.
+//!
+//! With the `time` feature, the attribute takes a format description of the `time` crate instead
+//! (e.g., `"[year]-[month]-[day] [hour]:[minute]"`) for `Date`, `Time`, `PrimitiveDateTime`, and
+//! `OffsetDateTime` fields. These types don't implement `FromStr`, so parsing them needs the attribute.
+//!
+//! ### Field attribute `#[templatia(uuid_format = "...")]`
+//!
+//! With the `uuid` feature of templatia, selects how a `Uuid` field renders: `"hyphenated"`
+//! (`67e55044-10b1-426f-9247-bb680e5fe0c8`, the `Display` default), `"simple"` without hyphens,
+//! `"braced"` in braces, or `"urn"` with the `urn:uuid:` prefix. Parsing accepts any of them, since
+//! `Uuid` itself is parsed like other primitive-like values. It can be used on `Uuid` fields and on
+//! `Option` and collections of them, but not with `with` or `format`.
+//!
+//! ### `Url` fields
+//!
+//! A `Url` renders its serialization, which is already percent-encoded, so it never contains
+//! whitespace, quotes, or angle brackets, and those make safe literals after a `{url}` placeholder.
+//! With the `url` feature of templatia, the ASCII characters of the separator inside each element
+//! of a `Url` collection are percent-encoded on render and decoded again when parsing, along with a
+//! `%` that would otherwise be decoded, so every URL reads back to itself. With `escape`, the
+//! escape character is used instead. The element type must be written as `url::Url` (or
+//! `::url::Url`), since a derive macro only sees the written path and another type named `Url`
+//! may be in scope.
+//!
+//! ### Field attribute `#[templatia(bytesize)]`
+//!
+//! Renders and parses a `u64` field as a byte size for memory and disk limits. Parsing accepts
+//! a number with an optional fraction and unit, like `512KiB`, `2 MB`, `1.5GiB`, or `100`, where
+//! the case-insensitive units `KB` to `EB` are powers of 1000 and `KiB` to `EiB` are powers of 1024.
+//! A value that isn't a whole number of bytes or overflows `u64` is a `TemplateError::ParseToType`.
+//! Rendering uses the largest unit dividing the value exactly, preferring the binary units, so
+//! `524288` renders `512KiB`, `2000000` renders `2MB`, and `100` renders `100B`. It can be used
+//! on `u64` fields and on `Option` and collections of them, but not with `with`, `format`,
+//! `radix`, or `datetime_format`.
+//!
+//! ### Field attributes `#[templatia(true_values = [...])]` and `#[templatia(false_values = [...])]`
+//!
+//! Lists the spellings a `bool` field accepts when parsing, such as
+//! `true_values = ["yes", "on"], false_values = ["no", "off"]`. The first spelling of each list
+//! is rendered. A list that isn't given keeps `true` or `false`. They can be used on `bool` fields
+//! and on `Option` and collections of them, and the spellings must be non-empty and distinct.
+//!
+//! ### `#[templatia(bool_style = "...")]`
+//!
+//! Picks a built-in spelling for rendering `bool` values: `"true_false"`, `"yes_no"`, `"on_off"`,
+//! or `"one_zero"`. Parsing accepts the spellings of every style, so `enabled=on` and `enabled=1`
+//! both read as `true`. On the struct it applies to all `bool` fields, and on a field it applies to
+//! that field only. A field can't combine it with `true_values` or `false_values`, which take
+//! precedence over the struct-level style.
+//!
+//! ### `#[templatia(float = "...")]`
+//!
+//! Picks a representation of `f32` and `f64` values that reads back to the exact same bits, so
+//! `from_str(&value.render_string())` reproduces every float. `"shortest"` renders the shorter of
+//! the decimal and the exponent notation (`0.1` but `1e300`), and `"exponent"` always renders the
+//! exponent notation (`1e-1`). Both print the fewest digits that identify the value, like `Display`.
+//! A fixed precision such as `format = "{:.3}"` rounds the value and can't be combined with it.
+//! On the struct it applies to all float fields without `format`, and on a field to that field only.
+//!
+//! ### Field attributes `#[templatia(skip_render)]` and `#[templatia(skip_parse)]`
+//!
+//! Limits a field to one direction. A `skip_render` placeholder renders nothing but is still
+//! parsed, so the field needs `FromStr` but not `Display`. A `skip_parse` placeholder is rendered,
+//! but its text is only consumed when parsing and the field gets its `default` expression or
+//! `Default::default()`, so the field needs `Display` but not `FromStr`. Rendered output of such
+//! fields does not round-trip, and they can't be combined with each other or `skip_parse` with `validate`.
+//!
+//! ### Field attribute `#[templatia(rest)]`
+//!
+//! Captures everything after the preceding literal, including newlines and text that looks
+//! like the template's literals, for a placeholder that ends the template such as
+//! `"header: {meta}\n{body}"`. The placeholder must be the last segment of the template outside
+//! optional groups, and it can't be combined with `width`, `quoted`, `escape`, or greedy matching.
+//!
+//! ### Field attribute `#[templatia(until_eol)]`
+//!
+//! Ends the capture at the next line break (`\n` or `\r\n`) as well as at the next literal, so a
+//! placeholder in the middle of a line of a multi-line template can't consume the following lines.
+//! Input whose line lacks the literal after the placeholder is then rejected instead of being
+//! captured across lines. It can't be combined with `rest`, `width`, `quoted`, `escape`, or greedy matching.
+//!
+//! ### Field attributes `#[templatia(min = ..., max = ...)]` and `#[templatia(min_len = ..., max_len = ...)]`
+//!
+//! Rejects parsed values outside inclusive bounds with `TemplateError::OutOfRange`. `min` and
+//! `max` compare integer and float values with the given expressions (e.g., `min = 1, max = 65535`),
+//! and `min_len` and `max_len` count the characters of string values. They apply to each value of
+//! `Option` and collection fields, and a `default` used for an empty capture isn't checked.
+//!
+//! ### `[...]` optional groups
+//!
+//! A bracketed section containing a placeholder of an `Option<T>` field is an optional group:
+//! it is rendered only when all of its `Option` fields are `Some`, and it may be absent when
+//! parsing, leaving those fields `None` (e.g., `"{host}[:{port}]"`). Only placeholders of
+//! `Option<T>` fields can be used inside a group, each at most once, and groups can't be nested.
+//! A value rendering as empty text, such as `Some("")`, omits the group like `None` and parses
+//! back as `None`, unless an empty capture of its field parses as `Some("")` through
+//! `empty_str_option_not_none` or `empty_str_is_some`, in which case the group is rendered.
+//! Brackets without such a placeholder are kept as literal text. Like braces, brackets are
+//! escaped by doubling them, so `"[[{section}]]"` renders `[server]` for `section: "server"`
+//! even when `section` is an `Option`.
+//!
+//! A field of type `Option<Box<T>>` delegates to the `Template` implementation of `T`, which
+//! allows recursive structs such as `"{value}[ -> {next}]"` with `next: Option<Box<Self>>`.
+//! Failures of the nested value are reported as `TemplateError::Include`. The nested text is
+//! captured up to the next literal, so a recursive placeholder should end the template.
+//!
+//! ### `{#field}...{/field}` repeated blocks
+//!
+//! Repeats the body for each element of a `Vec<T>` field, where `T` is a struct and the
+//! placeholders of the body name the fields of `T` (e.g.,
+//! `"{#servers}host={host}:{port}\n{/servers}"` for `servers: Vec<ServerEntry>`). Each element
+//! renders its fields through `Display`, and parsing repeats the body as long as it matches, building
+//! each element from its fields through `FromStr`. The body must name every field of `T`, each once,
+//! separate its placeholders with literals, and end with a literal that ends each element.
+//! Failures are reported as `TemplateError::ParseToType` for a placeholder like `servers.port`.
+//!
+//! ### `#[templatia(error = "MyError")]`
+//!
+//! Uses the given type as `Template::Error` instead of `TemplateError`, so a library can expose
+//! its own error enum. The type must implement `From<TemplateError>`, which converts every failure
+//! of the generated `from_str`. The `FromStr` generated by `impl_from_str` uses the same error type.
+//!
+//! ### `#[templatia(crate = "path::to::templatia")]`
+//!
+//! Changes the path of the templatia crate used by the generated code, which is `::templatia` by
+//! default. A crate re-exporting templatia (e.g., `pub use templatia;` in `my_facade`) lets its
+//! users derive `Template` without depending on templatia directly by setting
+//! `crate = "my_facade::templatia"`.
+//!
+//! ### `#[templatia(loose_whitespace)]`
+//!
+//! Accepts uneven spacing when parsing, such as in hand-edited configuration files. Any run of
+//! spaces and tabs may appear around each literal and at the start and end of the input, and a run
+//! of spaces and tabs inside a literal matches any run, so `host = {host}` also parses `host=x` and
+//! `host \t=  x`. A run that keeps words apart (e.g., in `"{a} and {b}"`) still needs at least one
+//! blank. Blanks around collection separators are dropped, and rendering is unchanged.
+//!
+//! ### `#[templatia(allow_trailing)]`
+//!
+//! Stops parsing once the template is matched and ignores the rest of the input, which otherwise
+//! fails to parse. This suits inputs with a known head, such as the first line of a log entry.
+//! A placeholder at the end of the template still captures up to the end of the input, so end the
+//! template with a literal (e.g., `"{level}: {code};"`) to leave the rest out.
+//!
+//! ### `#[templatia(trim_input)]`
+//!
+//! Ignores spaces, tabs, and line breaks before and after the input when parsing, so the final
+//! `\n` or `\r\n` that editors write at the end of a file no longer makes `from_str` fail.
+//! A placeholder at the end of the template leaves the trailing whitespace out of its value.
+//! Rendering is unchanged.
+//!
+//! ### `#[templatia(omit_none)]`
+//!
+//! Leaves out the whole template line of an `Option` field that is `None`, instead of rendering
+//! its literals around an empty value (e.g., `user=` in `"host={host}\nuser={user}"`). Each line
+//! whose placeholders all belong to `Option<T>` fields acts as an implicit `[...]` optional group
+//! covering the line and the line break before it (after it on the first line), so the line may
+//! also be absent when parsing. Lines with other placeholders, includes, blocks,
+//! or explicit groups are kept as they are, as are lines of fields used more than once, converted
+//! with `with`, or matched greedily.
+//!
+//! ### `#[templatia(ignore_unknown_lines)]`
+//!
+//! Skips the input lines whose key doesn't belong to the template, so a `key = value` file can
+//! carry settings meant for other programs. The key of a template line is its text before the
+//! first placeholder, compared with whitespace ignored, and blank lines are kept. It suits
+//! line-oriented templates such as the default one; a line starting with a placeholder accepts any
+//! input line. It's not available for structs borrowing `&str` from the input.
+//!
+//! ### `#[templatia(comment = "...")]`
+//!
+//! Skips the input lines starting with the marker, after any indentation, and blank lines when
+//! parsing, so hand-edited configuration files can be annotated and spaced out freely. Since blank
+//! input lines are dropped, templates with blank lines of their own don't match, and the final line
+//! break of the input is accepted whether or not the template ends with one. Rendering is
+//! unchanged, and it's not available for structs borrowing `&str` from the input.
+//!
+//! ### `#[templatia(impl_display, impl_from_str)]`
+//!
+//! Also implements `std::fmt::Display` through `render_to` and `std::str::FromStr` through
+//! `from_str`, so the type works with `format!` and `str::parse` directly, and can be used as a
+//! placeholder field type in other templates. This applies to unit enums as well.
+//! `impl_from_str` is not available for structs borrowing `&str` from the input, since `FromStr`
+//! can't return values borrowing its input.
+//!
+//! ### `#[templatia(render_only)]` and `#[templatia(parse_only)]`
+//!
+//! The derive implements `RenderTemplate` and `ParseTemplate`, and through them `Template`.
+//! `render_only` leaves out `ParseTemplate`, so the fields need no `FromStr`, which suits types
+//! that are only ever written out, like reports. `parse_only` leaves out `RenderTemplate`, so the
+//! fields need no `Display` unless the parser writes them into an error, which it does for
+//! repeated placeholders and range checks. `render_only` can't be combined with the options that
+//! only apply to parsing, `impl_from_str`, `lossy`, `find`, `legacy`, and `error`, and `parse_only`
+//! can't be combined with `impl_display`. With `parse_only`, localized templates are accepted when
+//! parsing, but `render_localized` isn't generated. Unit enums always implement both.
+//!
+//! ### `#[templatia(on_duplicate = "...")]`
+//!
+//! Chooses how a placeholder used several times in the template is parsed. `"error"`, the default,
+//! fails with `TemplateError::InconsistentValues` when the occurrences differ, while `"first"` and
+//! `"last"` take the value of the first or last occurrence, which suits noisy inputs. The other
+//! occurrences must still parse as the field type.
+//!
+//! ### `#[templatia(validate = "...")]`
+//!
+//! Checks the parsed value as a whole, for invariants across fields such as `min <= max`. The path
+//! names a function like `fn check(&self) -> Result<(), E>` with `E: Display`, which runs after
+//! `from_str` builds the value with any of the templates. An `Err` is reported as
+//! `TemplateError::InvalidValue` with the message. `from_str_lossy` adds the error to its list.
+//!
+//! ```rust
+//! use templatia::TemplateError;
+//! use templatia::prelude::*;
+//!
+//! #[derive(Template)]
+//! #[templatia(template = "{min}..{max}", validate = "Self::check")]
+//! struct Range {
+//!     min: u32,
+//!     max: u32,
+//! }
+//!
+//! impl Range {
+//!     fn check(&self) -> Result<(), String> {
+//!         if self.min <= self.max {
+//!             Ok(())
+//!         } else {
+//!             Err(format!("{} is greater than {}", self.min, self.max))
+//!         }
+//!     }
+//! }
+//!
+//! assert!(Range::from_str("1..5").is_ok());
+//! assert!(matches!(Range::from_str("5..1"), Err(TemplateError::InvalidValue { .. })));
+//! ```
+//!
+//! ### `#[templatia(find)]`
+//!
+//! Also generates `find(haystack) -> Option<Self>`, which searches a larger text, such as a log or
+//! prose, for the first position where the primary template matches and parses the value from
+//! there. The text after the match is ignored, but a placeholder at the end of the template still
+//! captures up to the end of the text, so end the template with a literal to bound the match.
+//!
+//! ```rust
+//! use templatia::prelude::*;
+//!
+//! #[derive(Template)]
+//! #[templatia(template = "user={name};", find)]
+//! struct Login {
+//!     name: String,
+//! }
+//!
+//! let login = Login::find("12:00 INFO user=alice; logged in").unwrap();
+//! assert_eq!(login.name, "alice");
+//! ```
+//!
+//! ### `#[templatia(lossy)]`
+//!
+//! Also generates `from_str_lossy(s) -> (Self, Vec<TemplateError>)`, which parses the primary
+//! template like `from_str` but doesn't stop at values failing to parse. Such a field takes its
+//! `default` expression or `Default::default()`, and every problem is reported in the returned list,
+//! which suits showing a configuration with warnings. When the literals of the template don't match,
+//! all fields take their defaults and only the literal mismatch is reported, since no value could
+//! be told apart. The fields need `Default` unless they have a `default`, and
+//! structs borrowing `&str` from the input, unions, and enums aren't supported.
+//!
+//! ```rust
+//! use templatia::TemplateError;
+//! use templatia::prelude::*;
+//!
+//! #[derive(Template)]
+//! #[templatia(template = "{host}:{port}", lossy)]
+//! struct Endpoint {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let (endpoint, errors) = Endpoint::from_str_lossy("localhost:http");
+//! assert_eq!(endpoint.host, "localhost");
+//! assert_eq!(endpoint.port, 0);
+//! assert!(matches!(errors[..], [TemplateError::ParseToType { .. }]));
+//! ```
+//!
+//! ### Placeholder names
+//!
+//! Structs and unions also get `placeholders() -> &'static [&'static str]`, which lists the
+//! placeholder names of the primary template in order of their first appearance, so a form or a
+//! validator can tell which fields the template expects. Renamed fields are listed by their
+//! `rename`, fields left out of the template aren't listed, and repeated placeholders appear once.
+//!
+//! ```rust
+//! use templatia::prelude::*;
+//!
+//! #[derive(Template)]
+//! #[templatia(template = "{user}@{host}:{port}")]
+//! struct Login {
+//!     host: String,
+//!     port: u16,
+//!     user: String,
+//! }
+//!
+//! assert_eq!(Login::placeholders(), ["user", "host", "port"]);
+//! ```
+//!
+//! ### `#[templatia(dedent)]`
+//!
+//! Removes the indentation of the source code from multi-line templates, including `alt_template`,
+//! `legacy`, and `template_file` templates. A line break right after the opening quote is dropped,
+//! as is the indentation of the closing quote on the last line, and the spaces and tabs shared by
+//! all non-blank lines are stripped from each line.
+//!
+//! ```rust
+//! use templatia::prelude::*;
+//!
+//! #[derive(Template)]
+//! #[templatia(
+//!     template = "
+//!     [server]
+//!     host = {host}
+//!     ",
+//!     dedent
+//! )]
+//! struct Server {
+//!     host: String,
+//! }
+//!
+//! let server = Server { host: "localhost".into() };
+//! assert_eq!(server.render_string(), "[server]\nhost = localhost\n");
+//! ```
+//!
+//! ### `#[templatia(unescape)]`
+//!
+//! Resolves the escape sequences `\n`, `\r`, `\t`, `\0`, `\\`, `\"`, and `\u{...}` in the templates,
+//! as in Rust string literals, so raw string templates and `template_file` contents can spell
+//! control characters on one line (e.g., `template = r"{key}\t{value}\n"`). It applies to
+//! `alt_template` and `legacy` templates too, before `dedent`. Other sequences after a backslash
+//! fail to compile, so a literal backslash is written as `\\`.
+//!
+//! ### `#[templatia(strict)]`
+//!
+//! Parses the input exactly as the template renders it, for uses such as protocol framing where
+//! leniency could hide corrupted input. Literals must match byte for byte and the whole input must
+//! be consumed, so `strict` can't be combined with `loose_whitespace`, `trim_input`,
+//! `allow_trailing`, or `newline`. An empty capture of an `Option` field is parsed as a value
+//! instead of being taken as `None`, which gives `Some("")` for strings and a parse error for types
+//! that reject empty text. `render_none` tokens and optional groups still express `None`.
+//!
+//! ### `#[templatia(prefix = "...", suffix = "...")]`
+//!
+//! Adds literal text before and after the template, such as a `"# generated by app\n"` banner.
+//! The text is rendered as is and required when parsing, and it applies to the `alt_template` and
+//! `legacy` templates as well, so a shared banner is written only once. Braces and brackets in the
+//! text are kept as literal characters rather than read as placeholders or optional groups.
+//!
+//! ### `#[templatia(newline = "...")]`
+//!
+//! Sets the line ending of the rendered text to `"lf"` or `"crlf"`. With `"crlf"`, each line break
+//! of the template is rendered as `\r\n`, such as for configuration files read on Windows. With
+//! either value, a line break of the template matches both `\n` and `\r\n` when parsing, so text
+//! edited on any platform parses the same way.
+//!
+//! ### `#[templatia(serde_compat)]`
+//!
+//! Keys the default template with the names serde uses, so both serializations stay consistent.
+//! A field's `#[serde(rename = "...")]` and the struct's `#[serde(rename_all = "...")]` are read
+//! (the `serialize` name when the two directions differ). A templatia `rename` comes first, a
+//! field's serde `rename` beats any `rename_all`, and templatia's `rename_all` beats serde's.
+//! Like `rename_all`, it applies only to the default template.
+//!
+//! ### `#[templatia(default_format = "...", default_separator = "...")]`
+//!
+//! Changes the layout of the default template of a struct without `template` or `template_file`.
+//! `default_format` is the text of each field, where `{key}` is replaced by the key and `{value}`,
+//! which must appear exactly once, by the placeholder (`"{key} = {value}"` by default).
+//! `default_separator` joins the fields (`"\n"` by default), so
+//! `default_format = "{key}: {value}", default_separator = "; "` produces `"host: {host}; port: {port}"`.
+//!
+//! ### `#[templatia(alt_template = "...")]`
+//!
+//! Adds an alternative template that `from_str` tries when the primary template does not match.
+//! The attribute can be repeated; alternatives are tried in declaration order and the first
+//! successful parse is returned. If none match, the error of the primary template is returned.
+//! `render_string` always uses the primary template. Each alternative follows the same
+//! placeholder rules as the primary template.
+//!
+//! ### `#[templatia(template(en = "...", ja = "..."))]`
+//!
+//! Declares a template per locale, such as for human-readable status lines. The first locale is
+//! the default used by `render_string`, and the generated `render_localized(locale)` renders the
+//! template of the given locale, falling back to the default for other locales. `from_str` accepts
+//! the text of any locale, trying them in declaration order before the `alt_template`s.
+//!
+//! ```rust
+//! use templatia::prelude::*;
+//!
+//! #[derive(Template)]
+//! #[templatia(template(en = "{count} files copied", ja = "{count}件のファイルをコピーしました"))]
+//! struct Copied {
+//!     count: u32,
+//! }
+//!
+//! let copied = Copied { count: 3 };
+//! assert_eq!(copied.render_localized("ja"), "3件のファイルをコピーしました");
+//! assert_eq!(copied.render_string(), "3 files copied");
+//! assert_eq!(Copied::from_str("5件のファイルをコピーしました").unwrap().count, 5);
+//! ```
+//!
+//! ### `#[templatia(version = N, legacy(vN = "..."), migrate(vN = "path"))]`
+//!
+//! Declares the version of the current template and the templates of previous versions,
+//! keyed by `v` followed by the version number (e.g., `legacy(v1 = "host={host}")`).
+//! `from_str` tries legacy templates after the primary and alternative templates, from the
+//! newest version to the oldest. Fields missing from a legacy template are initialized with
+//! `None` or `Default::default()`, and the migration function registered for the same key
+//! (`fn(Self) -> Self`) is applied to the parsed value so it can be brought up to date.
+//! Legacy versions must be unique and lower than the current version.
+//!
+//! ### `#[templatia(rename_all = "...")]`
+//!
+//! For fieldless enums, changes the keyword each variant renders as and parses from. For structs
+//! without `template` or `template_file`, changes the keys of the default template, so
+//! `max_connections` with `rename_all = "kebab-case"` becomes `max-connections = {max_connections}`.
+//! A field's own `rename` takes precedence over the rule. Accepts the serde rule names:
+//! `lowercase`, `UPPERCASE`, `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`,
+//! `kebab-case`, and `SCREAMING-KEBAB-CASE`.
+//!
+//! A fieldless enum used as a placeholder field type in other templates needs `impl_display` and
+//! `impl_from_str`, which implement `Display` and `FromStr` through the keywords.
+//!
+//! ### `#[templatia(active = "...")]` and `#[templatia(unsafe_union)]`
+//!
+//! Both are required for unions. `active` names the field that is always initialized; the template
+//! can only reference this field, rendering reads it, and `from_str` constructs the union through
+//! it. The default template is `active = {active}`. Since reading a union field can't be checked,
+//! `unsafe_union` acknowledges that the union doesn't implement `RenderTemplate` or `Template`:
+//! it implements `ParseTemplate` and renders through the inherent `unsafe fn render_string`, and
+//! `unsafe fn render_localized` with localized templates, whose callers must ensure that the active
+//! field is initialized. `impl_display` and `render_only` aren't available for unions.
+//!
+//! ### Borrowed `&str` fields
+//!
+//! Structs with lifetime parameters are supported. Fields of type `&'a str` and `Option<&'a str>`
+//! are parsed as slices of the input instead of through `FromStr`. Because `ParseTemplate::from_str`
+//! can't return values borrowing from its input, such structs implement
+//! `templatia::BorrowedTemplate<'src>` instead, whose `from_str` requires the input to outlive the
+//! struct's lifetimes. Other fields keep the usual requirements. Such structs can't be used with `{>field}` includes.
 //!
 //! For detailed usage examples and comprehensive documentation, see the main `templatia` crate.
 
+mod case;
+mod enums;
 pub(crate) mod error;
 pub(crate) mod fields;
+mod impls;
 mod inv;
 mod parser;
 mod render;
+mod serde_compat;
+mod unions;
 mod utils;
 
-use crate::error::generate_unsupported_compile_error;
-use crate::fields::{FieldKind, Fields};
-use crate::parser::{TemplateSegments, parse_template};
+use crate::case::RenameRule;
+use crate::enums::{UnitEnumOptions, generate_unit_enum_impl};
+use crate::error::{generate_nonzero_default_compile_error, generate_unsupported_compile_error};
+use crate::fields::{
+    BoolStyle, FieldDefaults, FieldKind, Fields, FloatStyle, MatchStrategy, Newline, OnDuplicate,
+};
+use crate::impls::{
+    ImplTarget, generate_display_impl, generate_find_impl, generate_from_str_impl,
+    generate_localized_impl, generate_lossy_impl, generate_parse_impl, generate_placeholders_impl,
+    generate_render_impl,
+};
+use crate::parser::{
+    TemplateSegments, dedent, field_segments, omit_none_lines, parse_template,
+    resolve_placeholder_names, template_modifiers, unescape,
+};
 use crate::render::generate_format_string_args;
-use darling::FromDeriveInput;
+use crate::serde_compat::{serde_rename, serde_rename_all};
+use crate::unions::{select_template_fields, union_as_struct};
+use crate::utils::{boxed_inner, is_borrowed_str, is_nonzero_type, is_self_type, option_inner};
 use darling::util::{Flag, Override};
-use inv::generator::generate_str_parser;
+use darling::{FromDeriveInput, FromVariant};
+use inv::errors::generate_error_mapper;
+use inv::generator::{ParserOptions, generate_str_parser};
 use proc_macro::TokenStream;
 use quote::quote;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use syn::{DeriveInput, parse_macro_input};
 
 #[derive(Debug, FromDeriveInput)]
-#[darling(attributes(templatia), supports(struct_named))]
+#[darling(attributes(templatia), supports(struct_named, enum_unit))]
 struct TemplateOpts {
-    /// The target struct identifier.
+    /// The target struct or enum identifier.
     ident: syn::Ident,
-    /// All fields of the target struct.
-    data: darling::ast::Data<(), syn::Field>,
-    /// Optional template string provided via `#[templatia(template = "...")]`.
+    /// All fields of the target struct, or all variants of the target unit enum.
+    data: darling::ast::Data<TemplateVariantOpts, syn::Field>,
+    /// Optional template string provided via `#[templatia(template = "...")]`,
+    /// or templates keyed by locale provided via `#[templatia(template(en = "...", ja = "..."))]`.
+    #[darling(default)]
+    template: Override<TemplateAttr>,
+    /// Optional template file path relative to `CARGO_MANIFEST_DIR` provided via `#[templatia(template_file = "...")]`.
+    #[darling(default)]
+    template_file: Option<String>,
+    /// Alternative templates tried in order by `from_str` when the primary template does not match.
+    #[darling(multiple)]
+    alt_template: Vec<String>,
+    /// Version number of the current template provided via `#[templatia(version = N)]`.
     #[darling(default)]
-    template: Override<String>,
+    version: Option<u32>,
+    /// Templates of previous versions provided via `#[templatia(legacy(v1 = "..."))]`.
+    #[darling(default)]
+    legacy: HashMap<String, String>,
+    /// Migration functions of previous versions provided via `#[templatia(migrate(v1 = "path"))]`.
+    #[darling(default)]
+    migrate: HashMap<String, syn::Path>,
     #[darling(default)]
     allow_missing_placeholders: Flag,
     #[darling(default)]
     empty_str_option_not_none: Flag,
+    /// Optional casing rule for unit enum variants provided via `#[templatia(rename_all = "...")]`.
+    #[darling(default)]
+    rename_all: Option<RenameRule>,
+    /// The always-initialized field of a union provided via `#[templatia(active = "...")]`.
+    #[darling(default)]
+    active: Option<syn::Ident>,
+    /// Acknowledges that rendering a union reads a field unchecked, provided via `#[templatia(unsafe_union)]`.
+    unsafe_union: Flag,
+    /// Default delimiter of collection elements provided via `#[templatia(separator = "...")]`.
+    #[darling(default)]
+    separator: Option<String>,
+    /// Default delimiter of map entries provided via `#[templatia(entry_sep = "...")]`.
+    #[darling(default)]
+    entry_sep: Option<String>,
+    /// Default delimiter of map keys and values provided via `#[templatia(kv_sep = "...")]`.
+    #[darling(default)]
+    kv_sep: Option<String>,
+    /// Default token rendered for `None` provided via `#[templatia(render_none = "...")]`.
+    #[darling(default)]
+    render_none: Option<String>,
+    /// Default spelling of `bool` fields provided via `#[templatia(bool_style = "...")]`.
+    #[darling(default)]
+    bool_style: Option<BoolStyle>,
+    /// Default representation of float fields provided via `#[templatia(float = "...")]`.
+    #[darling(default)]
+    float: Option<FloatStyle>,
+    /// Default tokens parsed as `None` provided via `#[templatia(none_literals = [...])]`.
+    #[darling(default)]
+    none_literals: Option<Vec<syn::LitStr>>,
+    /// Accepts any runs of spaces and tabs around literals and separators when parsing,
+    /// provided via `#[templatia(loose_whitespace)]`.
+    #[darling(default)]
+    loose_whitespace: Flag,
+    /// Ignores the input left after the end of the template when parsing, provided via
+    /// `#[templatia(allow_trailing)]`.
+    #[darling(default)]
+    allow_trailing: Flag,
+    /// Ignores whitespace and line breaks before and after the input when parsing, provided via
+    /// `#[templatia(trim_input)]`.
+    #[darling(default)]
+    trim_input: Flag,
+    /// Leaves out the template lines of `None` values when rendering, provided via
+    /// `#[templatia(omit_none)]`.
+    #[darling(default)]
+    omit_none: Flag,
+    /// Reads `(a|b)` sections as alternations and `((`/`))` as escaped parentheses, provided via
+    /// `#[templatia(alternation)]`.
+    #[darling(default)]
+    alternation: Flag,
+    /// Also implements `std::fmt::Display` through `render_string`, provided via `#[templatia(impl_display)]`.
+    #[darling(default)]
+    impl_display: Flag,
+    /// Also implements `std::str::FromStr` through `from_str`, provided via `#[templatia(impl_from_str)]`.
+    #[darling(default)]
+    impl_from_str: Flag,
+    /// Implements only `RenderTemplate`, so the fields need no `FromStr`, provided via `#[templatia(render_only)]`.
+    #[darling(default)]
+    render_only: Flag,
+    /// Implements only `ParseTemplate`, so the fields need no `Display`, provided via `#[templatia(parse_only)]`.
+    #[darling(default)]
+    parse_only: Flag,
+    /// Matches the input exactly without any leniency, provided via `#[templatia(strict)]`.
+    #[darling(default)]
+    strict: Flag,
+    /// Resolution of repeated placeholders provided via `#[templatia(on_duplicate = "first")]`.
+    #[darling(default)]
+    on_duplicate: Option<OnDuplicate>,
+    /// Generates `from_str_lossy`, which parses with defaults for the values failing to parse,
+    /// provided via `#[templatia(lossy)]`.
+    #[darling(default)]
+    lossy: Flag,
+    /// Function checking the parsed value as a whole provided via `#[templatia(validate = "Self::check")]`.
+    #[darling(default)]
+    validate: Option<syn::Path>,
+    /// Skips the input lines whose key isn't in the template before parsing, provided via
+    /// `#[templatia(ignore_unknown_lines)]`.
+    #[darling(default)]
+    ignore_unknown_lines: Flag,
+    /// Generates `find`, which parses the first part of a larger text matching the template,
+    /// provided via `#[templatia(find)]`.
+    #[darling(default)]
+    find: Flag,
+    /// Takes the default template keys from `#[serde(rename = "...")]` and `#[serde(rename_all = "...")]`,
+    /// provided via `#[templatia(serde_compat)]`.
+    #[darling(default)]
+    serde_compat: Flag,
+    /// Strips the common leading indentation of the templates provided via `#[templatia(dedent)]`.
+    #[darling(default)]
+    dedent: Flag,
+    /// Resolves `\n`, `\t`, `\r`, `\\`, and `\u{...}` escape sequences in the templates provided via
+    /// `#[templatia(unescape)]`.
+    #[darling(default)]
+    unescape: Flag,
+    /// Marker of the input lines skipped as comments, along with blank lines, provided via
+    /// `#[templatia(comment = "#")]`.
+    #[darling(default)]
+    comment: Option<String>,
+    /// Literal text placed before every template provided via `#[templatia(prefix = "...")]`.
+    #[darling(default)]
+    prefix: Option<String>,
+    /// Literal text placed after every template provided via `#[templatia(suffix = "...")]`.
+    #[darling(default)]
+    suffix: Option<String>,
+    /// Line ending of the rendered template provided via `#[templatia(newline = "crlf")]`.
+    #[darling(default)]
+    newline: Option<Newline>,
+    /// Layout of each field in the default template with `{key}` and `{value}` provided via
+    /// `#[templatia(default_format = "{key}: {value}")]`.
+    #[darling(default)]
+    default_format: Option<String>,
+    /// Delimiter between the fields of the default template provided via `#[templatia(default_separator = "; ")]`.
+    #[darling(default)]
+    default_separator: Option<String>,
+    /// Path of the templatia crate for the generated code provided via `#[templatia(crate = "...")]`,
+    /// used when templatia is re-exported from another crate.
+    #[darling(default, rename = "crate")]
+    crate_path: Option<syn::Path>,
+    /// Error type of the generated impl converted from `TemplateError` via `From`,
+    /// provided via `#[templatia(error = "MyError")]`.
+    #[darling(default)]
+    error: Option<syn::Path>,
+}
+
+/// The value of `#[templatia(template ...)]`.
+#[derive(Debug)]
+enum TemplateAttr {
+    /// A single template given with `template = "..."`.
+    Single(String),
+    /// Templates keyed by locale given with `template(en = "...", ja = "...")`, in declaration order.
+    /// The first one is the default template.
+    Localized(Vec<(String, String)>),
 }
 
-/// Derive macro for implementing `templatia::Template` trait on named structs.
+impl darling::FromMeta for TemplateAttr {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(Self::Single(value.to_string()))
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        let mut templates: Vec<(String, String)> = Vec::with_capacity(items.len());
+        for item in items {
+            let darling::ast::NestedMeta::Meta(syn::Meta::NameValue(pair)) = item else {
+                return Err(darling::Error::custom(
+                    "expected templates keyed by locale like `template(en = \"...\")`",
+                )
+                .with_span(item));
+            };
+            let Some(locale) = pair.path.get_ident().map(|ident| ident.to_string()) else {
+                return Err(
+                    darling::Error::custom("the locale must be a single identifier")
+                        .with_span(&pair.path),
+                );
+            };
+            if templates.iter().any(|(other, _)| *other == locale) {
+                return Err(darling::Error::duplicate_field(&locale).with_span(&pair.path));
+            }
+            let template = String::from_expr(&pair.value)?;
+            templates.push((locale, template));
+        }
+        if templates.is_empty() {
+            return Err(darling::Error::too_few_items(1));
+        }
+        Ok(Self::Localized(templates))
+    }
+}
+
+/// A template of a previous version that is still accepted by `from_str`.
+struct LegacyTemplate<'a> {
+    /// The version number of this template. Must be lower than the current version.
+    version: u32,
+    /// The template string used by this version.
+    template: &'a str,
+    /// Optional `fn(Self) -> Self` applied to values parsed with this template.
+    migrate: Option<&'a syn::Path>,
+}
+
+#[derive(Debug, FromVariant)]
+struct TemplateVariantOpts {
+    /// The variant identifier.
+    ident: syn::Ident,
+}
+
+/// Derive macro for implementing `templatia::Template` trait on named structs, unit enums, and unions.
 ///
-/// This procedural macro automatically generates `Template` trait implementations,
-/// enabling bidirectional conversion between structs and template strings.
+/// This procedural macro automatically generates `RenderTemplate` and `ParseTemplate` implementations,
+/// which together make up `Template`, enabling bidirectional conversion between structs and template strings.
+/// For fieldless enums, each variant is rendered as its name, and `Display`/`FromStr`
+/// implementations are generated alongside `Template` on request. Unions are parsed into the field
+/// designated with `#[templatia(active = "...")]` and rendered from it by an inherent `unsafe fn`.
 ///
 /// # Type Requirements
 ///
 /// All fields referenced in the template must implement:
-/// - `std::fmt::Display` for serialization (`render_string`)
-/// - `std::str::FromStr` for deserialization (`from_str`)
-/// - `std::cmp::PartialEq` for consistency validation with duplicate placeholders
+/// - `std::fmt::Display` for serialization (`render_string`), bounding `RenderTemplate`
+/// - `std::str::FromStr` for deserialization (`from_str`), bounding `ParseTemplate`
+/// - `std::cmp::PartialEq` for consistency validation with duplicate placeholders, bounding `ParseTemplate`
 ///
 /// # Compilation Errors
 ///
 /// The macro will produce compile-time errors in the following cases:
 /// - Template references non-existent struct fields
 /// - Template parsing fails due to invalid syntax
-/// - Applied to unsupported struct types (tuple structs, unit structs, enums)
+/// - Applied to unsupported types (tuple structs, unit structs, enums with data-carrying variants)
+/// - Applied to a union without `#[templatia(active = "...")]` and `#[templatia(unsafe_union)]`
 /// - Field types don't satisfy the required trait bounds
 #[proc_macro_derive(Template, attributes(templatia))]
 pub fn template_derive(input: TokenStream) -> TokenStream {
-    let ast = parse_macro_input!(input as DeriveInput);
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    // Unions are handled as named structs whose template only sees the active field.
+    let is_union = union_as_struct(&mut ast);
 
     let opts = match TemplateOpts::from_derive_input(&ast) {
         Ok(opts) => opts,
@@ -90,27 +1153,374 @@ pub fn template_derive(input: TokenStream) -> TokenStream {
     };
 
     let name = &opts.ident;
+    let krate = opts
+        .crate_path
+        .clone()
+        .unwrap_or_else(|| syn::parse_quote! { ::templatia });
 
-    let template = match &opts.template {
-        Override::Explicit(template) => template.to_string(),
-        Override::Inherit => {
-            if let syn::Data::Struct(data_struct) = &ast.data {
-                if let syn::Fields::Named(fields_named) = &data_struct.fields {
-                    fields_named
-                        .named
-                        .iter()
-                        .filter_map(|field| field.ident.as_ref())
-                        .map(|ident| format!("{0} = {{{0}}}", ident.to_string()))
-                        .collect::<Vec<_>>()
-                        .join("\n")
-                } else {
-                    String::new()
+    if let darling::ast::Data::Enum(variants) = &opts.data {
+        if !matches!(opts.template, Override::Inherit) {
+            return syn::Error::new_spanned(
+                name,
+                "templatia(template = \"...\") is not supported for enums. \
+                unit enums are rendered as their variant names",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if let Some(active) = &opts.active {
+            return syn::Error::new_spanned(
+                active,
+                "templatia(active = \"...\") is supported only for unions",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if opts.unsafe_union.is_present() {
+            return syn::Error::new_spanned(
+                name,
+                "templatia(unsafe_union) is supported only for unions",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if let Some(validate) = &opts.validate {
+            return syn::Error::new_spanned(
+                validate,
+                "templatia(validate = \"...\") is not supported for enums, whose variants are always valid",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if opts.find.is_present() {
+            return syn::Error::new_spanned(
+                name,
+                "templatia(find) is not supported for enums. search for the variant names instead",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if opts.lossy.is_present() {
+            return syn::Error::new_spanned(
+                name,
+                "templatia(lossy) is not supported for enums, which have no fields to fill with defaults",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if opts.render_only.is_present() || opts.parse_only.is_present() {
+            return syn::Error::new_spanned(
+                name,
+                "templatia(render_only) and templatia(parse_only) are not supported for enums. \
+                unit enums always implement both RenderTemplate and ParseTemplate",
+            )
+            .to_compile_error()
+            .into();
+        }
+        if variants.is_empty() {
+            return syn::Error::new_spanned(name, "Template cannot be derived for an empty enum")
+                .to_compile_error()
+                .into();
+        }
+
+        let variants = variants
+            .iter()
+            .map(|variant| &variant.ident)
+            .collect::<Vec<_>>();
+
+        return generate_unit_enum_impl(
+            name,
+            &ast.generics,
+            &variants,
+            UnitEnumOptions {
+                rename_all: opts.rename_all,
+                error: opts.error.as_ref(),
+                impl_display: opts.impl_display.is_present(),
+                impl_from_str: opts.impl_from_str.is_present(),
+            },
+            &krate,
+        )
+        .into();
+    }
+
+    // On structs, these options shape the default template, so they have nothing to apply to otherwise.
+    let default_template_options = [
+        ("rename_all", opts.rename_all.is_some()),
+        ("default_format", opts.default_format.is_some()),
+        ("default_separator", opts.default_separator.is_some()),
+        ("serde_compat", opts.serde_compat.is_present()),
+    ];
+    if let Some((option, _)) = default_template_options.iter().find(|(_, given)| *given)
+        && (!matches!(opts.template, Override::Inherit) || opts.template_file.is_some())
+    {
+        return syn::Error::new_spanned(
+            name,
+            format!(
+                "templatia({} = \"...\") on structs applies only to the default template, \
+                so it can't be combined with template or template_file",
+                option
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+    // Strict mode promises exact matching, so the lenient parsing options contradict it.
+    let lenient_options = [
+        ("loose_whitespace", opts.loose_whitespace.is_present()),
+        ("trim_input", opts.trim_input.is_present()),
+        ("allow_trailing", opts.allow_trailing.is_present()),
+        ("newline", opts.newline.is_some()),
+        (
+            "ignore_unknown_lines",
+            opts.ignore_unknown_lines.is_present(),
+        ),
+        ("comment", opts.comment.is_some()),
+    ];
+    if let Some((option, _)) = lenient_options.iter().find(|(_, given)| *given)
+        && opts.strict.is_present()
+    {
+        return syn::Error::new_spanned(
+            name,
+            format!(
+                "templatia({}) relaxes parsing, so it can't be combined with templatia(strict)",
+                option
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+    if opts.render_only.is_present() && opts.parse_only.is_present() {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(render_only) and templatia(parse_only) can't be combined. \
+            leave both out to implement Template",
+        )
+        .to_compile_error()
+        .into();
+    }
+    // These options only add to the parsing or rendering side, which the other flag leaves out.
+    let parse_options = [
+        ("impl_from_str", opts.impl_from_str.is_present()),
+        ("lossy", opts.lossy.is_present()),
+        ("find", opts.find.is_present()),
+        ("legacy", !opts.legacy.is_empty()),
+        ("error", opts.error.is_some()),
+    ];
+    if let Some((option, _)) = parse_options.iter().find(|(_, given)| *given)
+        && opts.render_only.is_present()
+    {
+        return syn::Error::new_spanned(
+            name,
+            format!(
+                "templatia({}) applies to parsing, so it can't be combined with templatia(render_only)",
+                option
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+    if opts.impl_display.is_present() && opts.parse_only.is_present() {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(impl_display) applies to rendering, so it can't be combined with templatia(parse_only)",
+        )
+        .to_compile_error()
+        .into();
+    }
+    if opts
+        .comment
+        .as_deref()
+        .is_some_and(|marker| marker.trim().is_empty())
+    {
+        return syn::Error::new_spanned(name, "templatia(comment) marker must not be blank")
+            .to_compile_error()
+            .into();
+    }
+    let default_format = opts.default_format.as_deref().unwrap_or("{key} = {value}");
+    if default_format.matches("{value}").count() != 1 {
+        return syn::Error::new_spanned(
+            name,
+            format!(
+                "default_format \"{}\" must contain `{{value}}` exactly once",
+                default_format
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let all_fields = if let darling::ast::Data::Struct(data_struct) = &opts.data {
+        &data_struct.fields
+    } else {
+        // Enums are handled above, so only named structs and unions reach this point.
+        unreachable!()
+    };
+    let all_fields = match select_template_fields(
+        name,
+        all_fields,
+        is_union,
+        opts.active.as_ref(),
+        opts.unsafe_union.is_present(),
+    ) {
+        Ok(fields) => fields,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    if matches!(opts.template, Override::Explicit(_)) && opts.template_file.is_some() {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(template = \"...\") and templatia(template_file = \"...\") cannot be used together",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let mut template_file_tracker = quote! {};
+    let template_file = match &opts.template_file {
+        Some(template_file) => {
+            let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+            let path = std::path::Path::new(&manifest_dir).join(template_file);
+            match std::fs::read_to_string(&path) {
+                Ok(template) => {
+                    // include_str! registers the file as a dependency so that editing it triggers a rebuild.
+                    let path = path.to_string_lossy().to_string();
+                    template_file_tracker = quote! {
+                        const _: &str = include_str!(#path);
+                    };
+                    Some(template)
+                }
+                Err(e) => {
+                    return syn::Error::new_spanned(
+                        name,
+                        format!(
+                            "Failed to read template file \"{}\" relative to CARGO_MANIFEST_DIR: {}",
+                            template_file,
+                            e.kind()
+                        ),
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+        }
+        None => None,
+    };
+    let mut legacy_templates = match collect_legacy_templates(&opts) {
+        Ok(legacy_templates) => legacy_templates,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    // Newer versions are more likely to match, so they are tried first.
+    legacy_templates.sort_by_key(|legacy| std::cmp::Reverse(legacy.version));
+
+    // Modifiers like `{name:trim}` are per-field options, so they are collected from every written
+    // template before the fields are analyzed.
+    let written_templates = match &opts.template {
+        Override::Explicit(TemplateAttr::Single(template)) => std::slice::from_ref(template),
+        _ => &[],
+    };
+    let localized_templates = match &opts.template {
+        Override::Explicit(TemplateAttr::Localized(templates)) => templates.as_slice(),
+        _ => &[],
+    };
+    let modifiers = written_templates
+        .iter()
+        .chain(&template_file)
+        .chain(localized_templates.iter().map(|(_, template)| template))
+        .chain(&opts.alt_template)
+        .map(String::as_str)
+        .chain(legacy_templates.iter().map(|legacy| legacy.template))
+        .flat_map(template_modifiers)
+        .collect::<Vec<_>>();
+
+    let defaults = FieldDefaults {
+        separator: opts.separator.as_deref(),
+        entry_sep: opts.entry_sep.as_deref(),
+        kv_sep: opts.kv_sep.as_deref(),
+        render_none: opts.render_none.as_deref(),
+        none_literals: opts.none_literals.as_deref(),
+        bool_style: opts.bool_style,
+        float: opts.float,
+        loose_whitespace: opts.loose_whitespace.is_present(),
+        allow_trailing: opts.allow_trailing.is_present(),
+        trim_input: opts.trim_input.is_present(),
+        newline: opts.newline,
+        strict: opts.strict.is_present(),
+        empty_str_option_not_none: opts.empty_str_option_not_none.is_present(),
+        on_duplicate: opts.on_duplicate.unwrap_or_default(),
+        modifiers: &modifiers,
+    };
+    let fields = match Fields::new(&all_fields, defaults, krate.clone()) {
+        Ok(fields) => fields,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    // Templates written as indented multi-line strings lose the indentation of the source code.
+    // Escape sequences are resolved first, so that the line breaks they produce are dedented too.
+    let prepare_template = |template: &str| -> Result<String, syn::Error> {
+        let template = if opts.unescape.is_present() {
+            unescape(template).map_err(|e| {
+                syn::Error::new_spanned(name, format!("Failed to unescape template: {}", e))
+            })?
+        } else {
+            template.to_string()
+        };
+        Ok(if opts.dedent.is_present() {
+            dedent(&template)
+        } else {
+            template
+        })
+    };
+
+    let template = match (&opts.template, &template_file) {
+        (_, Some(template)) => prepare_template(template),
+        (Override::Explicit(TemplateAttr::Single(template)), None) => prepare_template(template),
+        // The first locale is the default, and the others are tried like alternative templates.
+        (Override::Explicit(TemplateAttr::Localized(templates)), None) => {
+            prepare_template(&templates[0].1)
+        }
+        (Override::Inherit, None) => {
+            // With `serde_compat`, the names serde would use fill in where templatia has no own naming.
+            let serde_rule = if opts.serde_compat.is_present() {
+                match serde_rename_all(&ast.attrs) {
+                    Ok(rule) => rule,
+                    Err(error) => return error.to_compile_error().into(),
                 }
             } else {
-                String::new()
+                None
+            };
+            let mut lines = Vec::with_capacity(all_fields.len());
+            for field in &all_fields {
+                let Some(ident) = field.ident.as_ref() else {
+                    continue;
+                };
+                let placeholder = fields.placeholder_name(ident);
+                let serde_name = if opts.serde_compat.is_present() {
+                    match serde_rename(&field.attrs) {
+                        Ok(name) => name,
+                        Err(error) => return error.to_compile_error().into(),
+                    }
+                } else {
+                    None
+                };
+                // An explicit `rename` takes precedence over the `rename_all` rule.
+                let key = match (opts.rename_all.or(serde_rule), serde_name) {
+                    _ if *ident != placeholder => placeholder.clone(),
+                    (_, Some(serde_name)) => serde_name,
+                    (Some(rule), None) => rule.apply_to_field(&placeholder),
+                    (None, None) => placeholder.clone(),
+                };
+                lines.push(
+                    default_format
+                        .replace("{key}", &key)
+                        .replace("{value}", &format!("{{{}}}", placeholder)),
+                );
             }
+            Ok(lines.join(opts.default_separator.as_deref().unwrap_or("\n")))
         }
     };
+    let template = match template {
+        Ok(template) => template,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     let marker_input = format!("{}::{}", name, template);
     let hash = {
@@ -126,16 +1536,13 @@ pub fn template_derive(input: TokenStream) -> TokenStream {
     let allow_missing_placeholders = opts.allow_missing_placeholders.is_present();
     let empty_str_as_none = opts.empty_str_option_not_none.is_present();
 
-    let all_fields = if let darling::ast::Data::Struct(data_struct) = &opts.data {
-        &data_struct.fields
-    } else {
-        // Currently, this crates supports only named struct so this branch is unreachable.
-        unreachable!()
-    };
-
-    let fields = Fields::new(all_fields);
-
-    let segments = match parse_template(&template) {
+    let omit_none = opts.omit_none.is_present();
+    let alternation = opts.alternation.is_present();
+    let prefix = opts.prefix.as_deref();
+    let suffix = opts.suffix.as_deref();
+    let segments = match parse_segments(&template, &fields, omit_none, alternation)
+        .map(|s| with_banner(s, prefix, suffix))
+    {
         Ok(segments) => segments,
         Err(e) => {
             let error =
@@ -145,149 +1552,982 @@ pub fn template_derive(input: TokenStream) -> TokenStream {
         }
     };
 
-    let (format_string, format_args) = generate_format_string_args(&segments, &fields);
+    let alt_templates = localized_templates
+        .iter()
+        .skip(1)
+        .map(|(_, template)| template)
+        .chain(&opts.alt_template)
+        .map(|alt_template| prepare_template(alt_template))
+        .collect::<Result<Vec<_>, _>>();
+    let alt_templates = match alt_templates {
+        Ok(alt_templates) => alt_templates,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let mut alt_segments = Vec::with_capacity(alt_templates.len());
+    for alt_template in &alt_templates {
+        match parse_segments(alt_template, &fields, omit_none, alternation)
+            .map(|s| with_banner(s, prefix, suffix))
+        {
+            Ok(segments) => alt_segments.push(segments),
+            Err(e) => {
+                let error = syn::Error::new_spanned(
+                    &opts.ident,
+                    format!("Failed to parse alt_template \"{}\": {}", alt_template, e),
+                );
+                return error.to_compile_error().into();
+            }
+        }
+    }
 
-    // Gathering the all placeholder name without duplication
-    let placeholder_names = segments
+    let legacy_sources = legacy_templates
         .iter()
-        .filter_map(|segment| {
-            if let TemplateSegments::Placeholder(name) = segment {
-                Some(name.trim().to_string())
-            } else {
-                None
+        .map(|legacy| prepare_template(legacy.template))
+        .collect::<Result<Vec<_>, _>>();
+    let legacy_sources = match legacy_sources {
+        Ok(legacy_sources) => legacy_sources,
+        Err(error) => return error.to_compile_error().into(),
+    };
+    let mut legacy_segments = Vec::with_capacity(legacy_templates.len());
+    for (legacy, source) in legacy_templates.iter().zip(&legacy_sources) {
+        match parse_segments(source, &fields, omit_none, alternation)
+            .map(|s| with_banner(s, prefix, suffix))
+        {
+            Ok(segments) => legacy_segments.push(segments),
+            Err(e) => {
+                let error = syn::Error::new_spanned(
+                    &opts.ident,
+                    format!(
+                        "Failed to parse legacy template version {}: {}",
+                        legacy.version, e
+                    ),
+                );
+                return error.to_compile_error().into();
             }
+        }
+    }
+
+    let (format_string, format_args) = generate_format_string_args(&segments, &fields);
+    let localized_renders = localized_templates
+        .iter()
+        .zip(std::iter::once(&segments).chain(&alt_segments))
+        .map(|((locale, _), segments)| {
+            let (format_string, format_args) = generate_format_string_args(segments, &fields);
+            quote! { #locale => format!(#format_string, #(#format_args),*) }
         })
-        .collect::<HashSet<_>>();
+        .collect::<Vec<_>>();
 
+    let placeholder_names = collect_placeholder_names(&segments);
+    let mut include_names = collect_include_names(&segments);
+    let mut block_names = collect_block_names(&segments);
+
+    let parser_options = ParserOptions {
+        allow_missing_placeholders,
+        empty_str_as_none: !empty_str_as_none,
+        lossy: false,
+        anchored: true,
+    };
     let str_from_parser = generate_str_parser(
         name,
         &fields,
         &placeholder_names,
         &segments,
-        allow_missing_placeholders,
-        !empty_str_as_none,
+        parser_options,
         &escaped_colon_marker,
     );
+    let find_parser = opts.find.is_present().then(|| {
+        generate_str_parser(
+            name,
+            &fields,
+            &placeholder_names,
+            &segments,
+            ParserOptions {
+                anchored: false,
+                ..parser_options
+            },
+            &escaped_colon_marker,
+        )
+    });
+    // A match can start only where the leading literal appears, unless the literal may be spelled otherwise.
+    let find_literal = match segments.first() {
+        Some(TemplateSegments::Literal(lit))
+            if !fields.is_loose_whitespace()
+                && !fields.is_trim_input()
+                && !fields.is_any_newline()
+                && all_fields.iter().all(|field| {
+                    field
+                        .ident
+                        .as_ref()
+                        .is_none_or(|ident| fields.get_aliases(ident).is_empty())
+                }) =>
+        {
+            Some(lit.to_string())
+        }
+        _ => None,
+    };
+    let lossy_parser = opts.lossy.is_present().then(|| {
+        generate_str_parser(
+            name,
+            &fields,
+            &placeholder_names,
+            &segments,
+            ParserOptions {
+                lossy: true,
+                ..parser_options
+            },
+            &escaped_colon_marker,
+        )
+    });
 
-    // Generate trait bound
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    // Fields referenced by any of the templates need the trait bounds, so the names are merged here.
+    let mut all_placeholder_names = placeholder_names.clone();
+    let mut alt_parsers = Vec::with_capacity(alt_segments.len());
+    for segments in &alt_segments {
+        let alt_placeholder_names = collect_placeholder_names(segments);
+        alt_parsers.push(generate_str_parser(
+            name,
+            &fields,
+            &alt_placeholder_names,
+            segments,
+            parser_options,
+            &escaped_colon_marker,
+        ));
+        all_placeholder_names.extend(alt_placeholder_names);
+        include_names.extend(collect_include_names(segments));
+        block_names.extend(collect_block_names(segments));
+    }
+
+    // Legacy templates may lack fields added in later versions,
+    // so missing placeholders are always filled with defaults before the migration runs.
+    for (legacy, segments) in legacy_templates.iter().zip(&legacy_segments) {
+        let legacy_placeholder_names = collect_placeholder_names(segments);
+        let legacy_parser = generate_str_parser(
+            name,
+            &fields,
+            &legacy_placeholder_names,
+            segments,
+            ParserOptions {
+                allow_missing_placeholders: true,
+                ..parser_options
+            },
+            &escaped_colon_marker,
+        );
+        alt_parsers.push(match legacy.migrate {
+            Some(migrate) => quote! { #legacy_parser.map(#migrate) },
+            None => legacy_parser,
+        });
+        all_placeholder_names.extend(legacy_placeholder_names);
+        include_names.extend(collect_include_names(segments));
+        block_names.extend(collect_block_names(segments));
+    }
 
-    let mut new_where_clause = where_clause
-        .cloned()
+    // Generate trait bound
+    // Rendering and parsing are implemented separately, so each side only bounds what it uses.
+    let mut render_where_clause = ast
+        .generics
+        .where_clause
+        .clone()
         .unwrap_or_else(|| syn::parse_quote! { where });
+    let mut parse_where_clause = render_where_clause.clone();
+
+    let rendered_on_parse = std::iter::once(&segments)
+        .chain(&alt_segments)
+        .chain(&legacy_segments)
+        .flat_map(|segments| collect_parse_rendered_names(segments))
+        .collect::<HashSet<_>>();
 
-    for field in fields.used_fields_in_template(&placeholder_names) {
+    for field in fields.used_fields_in_template(&all_placeholder_names) {
         if let Some(ident) = field.ident.as_ref() {
+            // The parser writes the values of repeated placeholders and out of range values into its errors.
+            let (min, max) = fields.get_range(ident);
+            let (min_len, max_len) = fields.get_len_range(ident);
+            let rendered_on_parse = rendered_on_parse.contains(&ident.to_string())
+                || min.is_some()
+                || max.is_some()
+                || min_len.is_some()
+                || max_len.is_some();
+
+            // The lossy parse falls back to the default of a field that fails to parse.
+            if opts.lossy.is_present()
+                && fields.get_default(ident).is_none()
+                && !fields.is_skip_parse(ident)
+            {
+                let ty = &field.ty;
+                if is_nonzero_type(ty) {
+                    return generate_nonzero_default_compile_error(
+                        ident,
+                        ty,
+                        "for the values failing to parse in from_str_lossy",
+                    )
+                    .into();
+                }
+                parse_where_clause
+                    .predicates
+                    .push(syn::parse_quote! { #ty: ::std::default::Default });
+            }
+            if include_names.contains(&ident.to_string()) {
+                // An included sequence delegates to the template of each element instead of its own.
+                let ty = fields
+                    .get_field_kind(ident)
+                    .and_then(FieldKind::sequence_element)
+                    .unwrap_or(&field.ty);
+                render_where_clause.predicates.push(syn::parse_quote! {
+                    #ty: #krate::RenderTemplate
+                });
+                parse_where_clause.predicates.push(syn::parse_quote! {
+                    #ty: #krate::ParseTemplate + ::std::cmp::PartialEq
+                });
+                parse_where_clause.predicates.push(syn::parse_quote! {
+                    <#ty as #krate::ParseTemplate>::Error: ::std::fmt::Display
+                });
+                if rendered_on_parse {
+                    parse_where_clause.predicates.push(syn::parse_quote! {
+                        #ty: #krate::RenderTemplate
+                    });
+                }
+                continue;
+            }
+
+            // The functions of a `with` module define the conversions, so the field type needs no bounds.
+            // The elements of a block are built from their own fields, which the compiler checks directly.
+            if fields.get_with(ident).is_some() || block_names.contains(&ident.to_string()) {
+                continue;
+            }
+
+            // The converted types paired with whether they may be filled with their default.
+            let mut bounded_types: Vec<(&syn::Type, bool)> = Vec::new();
             match fields.get_field_kind(ident) {
+                // Borrowed strings are sliced out of the input, so they need no trait bounds.
+                Some(FieldKind::Option(ty)) | Some(FieldKind::Primitive(ty))
+                    if is_borrowed_str(ty) => {}
+                // `Option<Box<T>>` delegates to the template implementations of `T`.
+                // A bound on the deriving type itself would be recursive, so it is omitted.
+                Some(FieldKind::Option(ty)) if boxed_inner(ty).is_some() => {
+                    if let Some(inner) = boxed_inner(ty)
+                        && !is_self_type(inner, name)
+                    {
+                        render_where_clause.predicates.push(syn::parse_quote! {
+                            #inner: #krate::RenderTemplate
+                        });
+                        parse_where_clause.predicates.push(syn::parse_quote! {
+                            #inner: #krate::ParseTemplate
+                        });
+                        parse_where_clause.predicates.push(syn::parse_quote! {
+                            <#inner as #krate::ParseTemplate>::Error: ::std::fmt::Display
+                        });
+                        if rendered_on_parse {
+                            parse_where_clause.predicates.push(syn::parse_quote! {
+                                #inner: #krate::RenderTemplate
+                            });
+                        }
+                    }
+                }
+                // The elements of sparse sequences like `Vec<Option<T>>` are bound by their inner type.
+                Some(FieldKind::Vec(ty))
+                | Some(FieldKind::VecDeque(ty))
+                | Some(FieldKind::LinkedList(ty))
+                | Some(FieldKind::SmallVec(ty))
+                | Some(FieldKind::ArrayVec(ty))
+                    if option_inner(ty).is_some() =>
+                {
+                    // SAFETY: The guard checked that the element is an Option.
+                    bounded_types.push((option_inner(ty).unwrap(), false));
+                }
                 Some(FieldKind::Option(ty))
                 | Some(FieldKind::Vec(ty))
                 | Some(FieldKind::HashSet(ty))
-                | Some(FieldKind::BTreeSet(ty)) => {
-                    new_where_clause.predicates.push(syn::parse_quote! {
-                        #ty: ::std::fmt::Display + ::std::str::FromStr + ::std::cmp::PartialEq
-                    });
-                    new_where_clause.predicates.push(syn::parse_quote! {
-                        <#ty as ::std::str::FromStr>::Err: ::std::fmt::Display
-                    });
-                }
+                | Some(FieldKind::BTreeSet(ty))
+                | Some(FieldKind::VecDeque(ty))
+                | Some(FieldKind::LinkedList(ty))
+                | Some(FieldKind::BinaryHeap(ty))
+                | Some(FieldKind::IndexSet(ty))
+                | Some(FieldKind::SmallVec(ty))
+                | Some(FieldKind::ArrayVec(ty)) => bounded_types.push((ty, false)),
                 Some(FieldKind::Primitive(ty)) => {
-                    if !allow_missing_placeholders {
-                        new_where_clause.predicates.push(syn::parse_quote! {
-                            #ty: ::std::fmt::Display + ::std::str::FromStr + ::std::cmp::PartialEq
-                        });
-                    } else {
-                        new_where_clause.predicates.push(syn::parse_quote! {
-                            #ty: ::std::fmt::Display + ::std::str::FromStr + ::std::cmp::PartialEq + ::std::default::Default
-                        });
+                    // `Option` and collections default to empty, but other fields need `Default`
+                    // when they are missing from the template or skipped on parse without a `default`.
+                    let skipped =
+                        fields.is_skip_parse(ident) && fields.get_default(ident).is_none();
+                    if skipped && is_nonzero_type(ty) {
+                        return generate_nonzero_default_compile_error(
+                            ident,
+                            ty,
+                            "because it is skipped on parse",
+                        )
+                        .into();
                     }
-                    new_where_clause.predicates.push(syn::parse_quote! {
-                        <#ty as ::std::str::FromStr>::Err: ::std::fmt::Display
-                    });
+                    // A `NonZero*` field missing from a template is reported by the parser generation.
+                    let needs_default =
+                        (allow_missing_placeholders && !is_nonzero_type(ty)) || skipped;
+                    bounded_types.push((ty, needs_default));
+                }
+                Some(FieldKind::Result(ok, err)) => {
+                    bounded_types.extend([(*ok, false), (*err, false)]);
+                }
+                Some(FieldKind::HashMap(key, value))
+                | Some(FieldKind::BTreeMap(key, value))
+                | Some(FieldKind::IndexMap(key, value)) => {
+                    bounded_types.extend([(*key, false), (*value, false)]);
                 }
                 Some(kind) => return generate_unsupported_compile_error(ident, kind).into(),
                 None => {
                     return generate_unsupported_compile_error(ident, &FieldKind::Unknown).into();
                 }
             }
+            for (ty, needs_default) in bounded_types {
+                let bounds = field_bounds(&fields, ident, ty, needs_default, rendered_on_parse);
+                render_where_clause.predicates.extend(bounds.render);
+                parse_where_clause.predicates.extend(bounds.parse);
+            }
         }
     }
 
-    let where_clause = if new_where_clause.predicates.is_empty() {
+    let render_where = if render_where_clause.predicates.is_empty() {
+        quote! {}
+    } else {
+        quote! { #render_where_clause }
+    };
+    let parse_where = if parse_where_clause.predicates.is_empty() {
         quote! {}
     } else {
-        quote! { #new_where_clause }
+        quote! { #parse_where_clause }
     };
 
     let replace_escaped_to_colon = quote! { replace(#escaped_colon_marker, ":") };
 
-    quote! {
-        impl #impl_generics ::templatia::Template for #name #ty_generics #where_clause {
-            type Error = templatia::TemplateError;
+    // Structs borrowing `&str` from the input can't implement `ParseTemplate::from_str` whose input has
+    // an unrelated lifetime, so they implement `BorrowedTemplate<'src>` instead, where the input outlives the struct.
+    let borrows_input = fields
+        .used_fields_in_template(&all_placeholder_names)
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .any(|ident| {
+            matches!(
+                fields.get_field_kind(ident),
+                Some(FieldKind::Primitive(ty)) | Some(FieldKind::Option(ty)) if is_borrowed_str(ty)
+            )
+        });
+
+    if borrows_input && opts.find.is_present() {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(find) is not supported for structs borrowing `&str` from the input",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if is_union && (opts.impl_display.is_present() || opts.render_only.is_present()) {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(impl_display) and templatia(render_only) are not supported for unions, \
+            which are rendered only through the unsafe render_string",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if (borrows_input || is_union) && opts.lossy.is_present() {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(lossy) is supported only for structs that don't borrow `&str` from the input",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let has_extras = std::iter::once(&segments)
+        .chain(&alt_segments)
+        .chain(&legacy_segments)
+        .flat_map(|segments| segments.iter())
+        .any(|segment| matches!(segment, TemplateSegments::Extras(_)));
+    // The extra lines are taken out of the whole input, which `find` only searches.
+    if has_extras && (borrows_input || opts.find.is_present()) {
+        return syn::Error::new_spanned(
+            name,
+            "{*...} placeholders are not supported for structs borrowing `&str` from the input \
+            or with templatia(find), because the extra lines are taken out of a copy of the whole input",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let line_filter_options = [
+        (
+            "ignore_unknown_lines",
+            opts.ignore_unknown_lines.is_present(),
+        ),
+        ("comment", opts.comment.is_some()),
+    ];
+    if let Some((option, _)) = line_filter_options.iter().find(|(_, given)| *given)
+        && borrows_input
+    {
+        return syn::Error::new_spanned(
+            name,
+            format!(
+                "templatia({}) is not supported for structs borrowing `&str` from the input \
+                because the values would borrow the filtered copy of the input",
+                option
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if borrows_input && opts.impl_from_str.is_present() {
+        return syn::Error::new_spanned(
+            name,
+            "templatia(impl_from_str) is not supported for structs borrowing `&str` from the input \
+            because FromStr can't return values borrowing its input. use BorrowedTemplate::from_str instead",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // Maps the custom errors raised by the generated parsers to `TemplateError`.
+    let error_mapper = generate_error_mapper(&krate, &replace_escaped_to_colon);
 
-            fn render_string(&self) -> String {
-                format!(#format_string, #(#format_args),*)
+    // Lines are dropped from the input before parsing, and the remaining ones are joined again.
+    let mut line_filters = Vec::new();
+    if let Some(marker) = &opts.comment {
+        line_filters.push(quote! {
+            !line.trim().is_empty() && !line.trim_start().starts_with(#marker)
+        });
+    }
+    // The lines are compared with the text before their first placeholder, ignoring whitespace,
+    // so the keys match regardless of the spacing around them. Blank lines are always kept.
+    let mut keys = std::iter::once(&segments)
+        .chain(&alt_segments)
+        .chain(&legacy_segments)
+        .flat_map(|segments| line_keys(segments))
+        .collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+    let is_known_line = quote! {
+        (line.trim().is_empty() || [#(#keys),*].iter().any(|key: &&str| {
+            let mut chars = line.chars().filter(|c| !c.is_whitespace());
+            key.chars().all(|k| chars.next() == Some(k))
+        }))
+    };
+    // The unknown `key = value` lines go to the `{*field}` map instead of the template.
+    if has_extras {
+        line_filters.push(quote! {
+            match line.split_once('=') {
+                Some((key, value)) if !#is_known_line => {
+                    __templatia_extras.push((key.trim().to_string(), value.trim().to_string()));
+                    false
+                }
+                _ => true,
+            }
+        });
+    }
+    if opts.ignore_unknown_lines.is_present() {
+        line_filters.push(is_known_line);
+    }
+    // Blank lines are dropped with comments, so the line break ending the template is restored.
+    let restore_final_newline = (opts.comment.is_some()
+        && matches!(segments.last(), Some(TemplateSegments::Literal(lit)) if lit.ends_with('\n')))
+    .then(|| quote! { filtered.push('\n'); });
+    let extras = has_extras.then(|| {
+        quote! { let mut __templatia_extras: Vec<(String, String)> = Vec::new(); }
+    });
+    // The end of the input ends an unknown last line as a line break would, so the line break
+    // before it stays with the known lines. Filters keep or drop equal lines alike, so a last
+    // line that was dropped isn't the last of the kept ones.
+    let restore_dropped_line_break = has_extras.then(|| {
+        quote! {
+            let last_line = s.rsplit('\n').next().unwrap_or_default();
+            if !last_line.is_empty()
+                && lines.last() != Some(&last_line)
+                && !filtered.is_empty()
+                && !filtered.ends_with('\n')
+            {
+                filtered.push('\n');
             }
+        }
+    });
+    let filter_lines = (!line_filters.is_empty()).then(|| {
+        quote! {
+            #extras
+            let lines = s
+                .split('\n')
+                .filter(|line| #(#line_filters)&&*)
+                .collect::<Vec<_>>();
+            let mut filtered = lines.join("\n");
+            #restore_final_newline
+            #restore_dropped_line_break
+            let s: &str = &filtered;
+        }
+    });
 
-            fn from_str(s: &str) -> Result<Self, Self::Error> {
-                use ::templatia::__private::chumsky;
-                use ::templatia::__private::chumsky::Parser;
-                use ::templatia::__private::chumsky::prelude::*;
-
-                let parser = #str_from_parser;
-                match parser.parse(s).into_result() {
-                    Ok(value) => Ok(value),
-                    Err(errs) => {
-                        for err in &errs {
-                            if let ::templatia::__private::chumsky::error::RichReason::Custom(msg) = err.reason() {
-                                let m = msg.to_string();
-                                const PFX_CONFLICT: &str = "__templatia_conflict__:";
-                                const PFX_PARSE: &str = "__templatia_parse_type__:";
-                                const PFX_PARSE_LITERAL: &str = "__templatia_parse_literal__:";
-                                if let Some(rest) = m.strip_prefix(PFX_CONFLICT) {
-                                    if let Some((placeholder, rest)) = rest.split_once("::") {
-                                        if let Some((first_value, second_value)) = rest.split_once("::") {
-                                            return Err(::templatia::TemplateError::InconsistentValues {
-                                                placeholder: placeholder.#replace_escaped_to_colon.to_string(),
-                                                first_value: first_value.#replace_escaped_to_colon.to_string(),
-                                                second_value: second_value.#replace_escaped_to_colon.to_string(),
-                                            });
-                                        }
-                                    }
-                                } else if let Some(rest) = m.strip_prefix(PFX_PARSE) {
-                                    if let Some((placeholder, rest)) = rest.split_once("::") {
-                                        if let Some((value, ty)) = rest.split_once("::") {
-                                            return Err(::templatia::TemplateError::ParseToType {
-                                                placeholder: placeholder.#replace_escaped_to_colon.to_string(),
-                                                value: value.#replace_escaped_to_colon.to_string(),
-                                                type_name: ty.#replace_escaped_to_colon.to_string(),
-                                            })
-                                        }
-                                    }
-                                } else if let Some(rest) = m.strip_prefix(PFX_PARSE_LITERAL) {
-                                    if let Some((expected, got)) = rest.split_once("::") {
-                                        let expected_next_literal = expected.trim_matches('"')
-                                            .#replace_escaped_to_colon
-                                            .to_string();
-                                        let remaining_text = got.#replace_escaped_to_colon.to_string();
-
-                                        return Err(::templatia::TemplateError::UnexpectedInput {
-                                            expected_next_literal,
-                                            remaining_text,
-                                        })
-                                    }
-                                }
-                            }
-                        }
+    let parse_body = quote! {
+        use #krate::__private::chumsky;
+        use #krate::__private::chumsky::Parser;
+        use #krate::__private::chumsky::prelude::*;
+
+        #filter_lines
+        let to_template_error = #error_mapper;
+        let parser = #str_from_parser;
+        match parser.parse(s).into_result() {
+            Ok(value) => Ok(value),
+            Err(errs) => {
+                // Alternative templates are tried in declaration order, followed by legacy templates
+                // from the newest version. The errors of the primary template are reported when none of them match.
+                #(
+                    if let Ok(value) = (#alt_parsers).parse(s).into_result() {
+                        return Ok(value);
+                    }
+                )*
+
+                for err in &errs {
+                    if let Some(error) = to_template_error(err) {
+                        return Err(error);
+                    }
+                }
+
+                let error_message = errs.into_iter()
+                    .map(|err| err.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                Err(#krate::TemplateError::Parse(error_message))
+            }
+        }
+    };
+    // The validation of the whole value runs after any of the templates matched.
+    let parse_body = match &opts.validate {
+        Some(validate) => quote! {
+            let parse = || -> Result<Self, #krate::TemplateError> { #parse_body };
+            let value = parse()?;
+            match #validate(&value) {
+                Ok(()) => Ok(value),
+                Err(message) => Err(#krate::TemplateError::InvalidValue {
+                    message: message.to_string(),
+                }),
+            }
+        },
+        None => parse_body,
+    };
+    // A custom error type receives the `TemplateError` through its `From` implementation.
+    let (error_type, parse_body) = match &opts.error {
+        Some(error) => (
+            quote! { #error },
+            quote! {
+                let parse = || -> Result<Self, #krate::TemplateError> { #parse_body };
+                parse().map_err(<#error as ::std::convert::From<#krate::TemplateError>>::from)
+            },
+        ),
+        None => (quote! { #krate::TemplateError }, parse_body),
+    };
+
+    let target = ImplTarget {
+        name,
+        generics: &ast.generics,
+        krate: &krate,
+    };
+    // When the literals don't match, no field can be told apart, so all of them take their defaults.
+    let lossy_impl = lossy_parser.map(|lossy_parser| {
+        let field_defaults = all_fields
+            .iter()
+            .filter_map(|field| {
+                let ident = field.ident.as_ref()?;
+                Some(match fields.get_default(ident) {
+                    Some(default) => quote! { #ident: #default },
+                    None => quote! { #ident: ::std::default::Default::default() },
+                })
+            })
+            .collect::<Vec<_>>();
+        generate_lossy_impl(
+            &target,
+            &parse_where,
+            &lossy_parser,
+            filter_lines.as_ref(),
+            &error_mapper,
+            &field_defaults,
+            opts.validate.as_ref(),
+        )
+    });
+
+    // Unknown locales fall back to the default template like `render_string`.
+    // With `parse_only`, the localized templates are only accepted when parsing.
+    let localized_impl =
+        (!localized_renders.is_empty() && !opts.parse_only.is_present()).then(|| {
+            generate_localized_impl(
+                &target,
+                &render_where,
+                &localized_renders,
+                &quote! { format!(#format_string, #(#format_args),*) },
+                is_union,
+            )
+        });
+
+    let find_impl = find_parser.map(|find_parser| {
+        generate_find_impl(
+            &target,
+            &parse_where,
+            &find_parser,
+            find_literal.as_deref(),
+            opts.validate.as_ref(),
+        )
+    });
+
+    // The names follow the primary template, since the other templates only widen what is parsed.
+    let placeholder_list = collect_ordered_placeholder_names(&segments, &fields);
+    let placeholders_impl = generate_placeholders_impl(&target, &placeholder_list);
+
+    let render_impl = (!opts.parse_only.is_present()).then(|| {
+        generate_render_impl(
+            &target,
+            &render_where,
+            &format_string,
+            &format_args,
+            is_union,
+        )
+    });
+    let parse_impl = (!opts.render_only.is_present()).then(|| {
+        generate_parse_impl(
+            &target,
+            &parse_where_clause,
+            borrows_input,
+            &error_type,
+            &parse_body,
+        )
+    });
+    // The std impls delegate to the template impls, so they share their bounds.
+    let display_impl = opts
+        .impl_display
+        .is_present()
+        .then(|| generate_display_impl(&target, &render_where));
+    let from_str_impl = opts
+        .impl_from_str
+        .is_present()
+        .then(|| generate_from_str_impl(&target, &parse_where));
+
+    quote! {
+        #template_file_tracker
+
+        #render_impl
+
+        #parse_impl
+
+        #display_impl
+
+        #from_str_impl
+
+        #lossy_impl
+
+        #find_impl
+
+        #localized_impl
+
+        #placeholders_impl
+    }
+    .into()
+}
+
+/// Collects the legacy templates keyed as `vN` and pairs them with their migration functions.
+fn collect_legacy_templates(opts: &TemplateOpts) -> Result<Vec<LegacyTemplate<'_>>, syn::Error> {
+    let parse_version = |key: &str| {
+        key.strip_prefix('v')
+            .and_then(|version| version.parse::<u32>().ok())
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &opts.ident,
+                    format!(
+                        "invalid legacy version key \"{}\". use `v` followed by the version number like `v1`",
+                        key
+                    ),
+                )
+            })
+    };
 
-                        let error_message = errs.into_iter()
-                            .map(|err| err.to_string())
-                            .collect::<Vec<_>>()
-                            .join("\n");
+    for key in opts.migrate.keys() {
+        if !opts.legacy.contains_key(key) {
+            return Err(syn::Error::new_spanned(
+                &opts.ident,
+                format!("migrate({} = ...) has no matching legacy template", key),
+            ));
+        }
+    }
+
+    let mut legacy_templates = Vec::with_capacity(opts.legacy.len());
+    for (key, template) in &opts.legacy {
+        let version = parse_version(key)?;
+        if let Some(current) = opts.version
+            && version >= current
+        {
+            return Err(syn::Error::new_spanned(
+                &opts.ident,
+                format!(
+                    "legacy template version {} must be lower than the current version {}",
+                    version, current
+                ),
+            ));
+        }
+        if legacy_templates
+            .iter()
+            .any(|other: &LegacyTemplate| other.version == version)
+        {
+            return Err(syn::Error::new_spanned(
+                &opts.ident,
+                format!("legacy template version {} is defined twice", version),
+            ));
+        }
+
+        legacy_templates.push(LegacyTemplate {
+            version,
+            template,
+            migrate: opts.migrate.get(key),
+        });
+    }
+
+    Ok(legacy_templates)
+}
+
+/// The where predicates of a field, split by the implementation that needs them.
+struct FieldBounds {
+    /// The predicates of the `RenderTemplate` implementation.
+    render: Vec<syn::WherePredicate>,
+    /// The predicates of the `ParseTemplate` or `BorrowedTemplate` implementation.
+    parse: Vec<syn::WherePredicate>,
+}
+
+/// Returns the trait bounds of a field converted with `Display` and `FromStr`.
+///
+/// `#[templatia(skip_render)]` fields are never rendered, so `Display` isn't required, and
+/// `#[templatia(skip_parse)]` fields are never parsed, so `FromStr` isn't required.
+///
+/// # Parameters
+/// - fields: The fields of the struct.
+/// - ident: The field name.
+/// - ty: The converted type, which is the element type of `Option` and collections.
+/// - needs_default: Whether the field may be filled with `Default::default()` of `ty`.
+/// - rendered_on_parse: Whether the parser writes the values into its errors, which needs `Display`.
+///
+/// # Returns
+/// The where predicates of the field for rendering and for parsing.
+fn field_bounds(
+    fields: &Fields,
+    ident: &syn::Ident,
+    ty: &syn::Type,
+    needs_default: bool,
+    rendered_on_parse: bool,
+) -> FieldBounds {
+    let skip_parse = fields.is_skip_parse(ident);
+    // A `datetime_format` parses through `DateTimeFormat`, since the `time` types don't implement `FromStr`.
+    let datetime_format = fields.get_datetime_format(ident).is_some();
+    // `PathBuf` and `OsString` render lossily without `Display`.
+    let lossy_display = fields
+        .get_field_kind(ident)
+        .is_some_and(|kind| kind.lossy_display().is_some());
+    let display = !fields.is_skip_render(ident) && !lossy_display;
+    let krate = fields.crate_path();
+
+    let mut render: syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]> =
+        syn::punctuated::Punctuated::new();
+    let mut parse = render.clone();
+    if display {
+        render.push(syn::parse_quote! { ::std::fmt::Display });
+        if rendered_on_parse {
+            parse.push(syn::parse_quote! { ::std::fmt::Display });
+        }
+    }
+    if !skip_parse && !datetime_format {
+        parse.push(syn::parse_quote! { ::std::str::FromStr });
+    }
+    parse.push(syn::parse_quote! { ::std::cmp::PartialEq });
+    if datetime_format {
+        render.push(syn::parse_quote! { #krate::__private::DateTimeFormat });
+        parse.push(syn::parse_quote! { #krate::__private::DateTimeFormat });
+    }
+    if needs_default {
+        parse.push(syn::parse_quote! { ::std::default::Default });
+    }
+
+    let mut bounds = FieldBounds {
+        render: Vec::new(),
+        parse: vec![syn::parse_quote! { #ty: #parse }],
+    };
+    if !render.is_empty() {
+        bounds.render.push(syn::parse_quote! { #ty: #render });
+    }
+    if !skip_parse && !datetime_format {
+        bounds.parse.push(syn::parse_quote! {
+            <#ty as ::std::str::FromStr>::Err: ::std::fmt::Display
+        });
+    }
+    bounds
+}
+
+/// Collects the names of the placeholders whose values the parser writes into its errors,
+/// which are the placeholders repeated in the template and those with a `{field:min..max}` range.
+fn collect_parse_rendered_names(segments: &[TemplateSegments]) -> HashSet<String> {
+    let mut seen = HashSet::new();
+    let mut names = HashSet::new();
+    for (segment, _) in field_segments(segments) {
+        let Some(name) = segment.field_name() else {
+            continue;
+        };
+        let name = name.trim().to_string();
+        let ranged =
+            matches!(segment, TemplateSegments::Placeholder(_, spec) if spec.range.is_some());
+        if !seen.insert(name.clone()) || ranged {
+            names.insert(name);
+        }
+    }
+    names
+}
 
-                        Err(templatia::TemplateError::Parse(error_message))
+/// Parses a template and resolves its placeholder names to field names.
+/// With `omit_none`, the lines of `Option` placeholders become implicit optional groups.
+fn parse_segments<'a>(
+    template: &'a str,
+    fields: &'a Fields,
+    omit_none: bool,
+    alternation: bool,
+) -> Result<Vec<TemplateSegments<'a>>, String> {
+    let is_optional = |name: &str| {
+        // Fields converted by a `with` module are opaque values, so they can't form optional groups.
+        fields.resolve_placeholder(name).is_ok_and(|name| {
+            let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+            fields.get_with(&ident).is_none()
+                && matches!(
+                    fields.get_type_kind_by_name(name),
+                    Some(FieldKind::Option(_))
+                )
+        })
+    };
+    let segments = parse_template(template, &is_optional, alternation)?;
+    let segments = resolve_placeholder_names(segments, &|name| fields.resolve_placeholder(name))?;
+    if !omit_none {
+        return Ok(segments);
+    }
+
+    // Greedy placeholders can't be used inside groups, so their lines are always rendered.
+    let is_omittable = |name: &str| {
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        fields.get_with(&ident).is_none()
+            && fields.get_match_strategy(&ident) != MatchStrategy::Greedy
+            && matches!(
+                fields.get_type_kind_by_name(name),
+                Some(FieldKind::Option(_))
+            )
+    };
+    Ok(omit_none_lines(segments, &is_omittable))
+}
+
+/// Surrounds the segments of a template with the literal `prefix` and `suffix` of the container.
+fn with_banner<'a>(
+    segments: Vec<TemplateSegments<'a>>,
+    prefix: Option<&'a str>,
+    suffix: Option<&'a str>,
+) -> Vec<TemplateSegments<'a>> {
+    let prefix = prefix.filter(|prefix| !prefix.is_empty());
+    let suffix = suffix.filter(|suffix| !suffix.is_empty());
+    prefix
+        .map(TemplateSegments::Literal)
+        .into_iter()
+        .chain(segments)
+        .chain(suffix.map(TemplateSegments::Literal))
+        .collect()
+}
+
+/// Gathers all placeholder names of the template without duplication.
+fn collect_placeholder_names(segments: &[TemplateSegments]) -> HashSet<String> {
+    field_segments(segments)
+        .into_iter()
+        .filter_map(|(segment, _)| segment.field_name().map(|name| name.trim().to_string()))
+        .collect::<HashSet<_>>()
+}
+
+/// Collects the placeholder names of the fields referenced in a template for `placeholders()`.
+///
+/// # Returns
+/// - The names in order of their first appearance, including those of includes, blocks, and
+///   optional groups. Renamed fields are listed by their `rename`.
+fn collect_ordered_placeholder_names(
+    segments: &[TemplateSegments],
+    fields: &Fields,
+) -> Vec<String> {
+    let mut names = Vec::new();
+    for (segment, _) in field_segments(segments) {
+        let Some(name) = segment.field_name() else {
+            continue;
+        };
+        let ident = syn::Ident::new(name.trim(), proc_macro2::Span::call_site());
+        let name = fields.placeholder_name(&ident);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Collects the key of each template line for `#[templatia(ignore_unknown_lines)]`.
+///
+/// # Returns
+/// - The literal text from the start of each line up to its first placeholder, without whitespace.
+///   A line starting with a placeholder has an empty key, which matches every input line.
+fn line_keys(segments: &[TemplateSegments]) -> Vec<String> {
+    fn visit(segments: &[TemplateSegments], keys: &mut Vec<String>, current: &mut Option<String>) {
+        for segment in segments {
+            match segment {
+                TemplateSegments::Literal(lit) => {
+                    for (i, part) in lit.split('\n').enumerate() {
+                        if i > 0 {
+                            keys.extend(current.take());
+                            *current = Some(String::new());
+                        }
+                        if let Some(key) = current {
+                            key.extend(part.chars().filter(|c| !c.is_whitespace()));
+                        }
                     }
                 }
+                // The key of a line ends before an alternation, which has no single spelling.
+                TemplateSegments::Placeholder(..)
+                | TemplateSegments::Include(_)
+                | TemplateSegments::Alternation(_)
+                | TemplateSegments::Env(_) => {
+                    keys.extend(current.take());
+                }
+                // A lookahead consumes no text, so the key goes on after it.
+                TemplateSegments::Lookahead(..) => {}
+                // The line of the extra entries has no key, so it doesn't match every input line.
+                TemplateSegments::Extras(_) => {
+                    current.take();
+                }
+                TemplateSegments::Group(inner) | TemplateSegments::Block(_, inner) => {
+                    visit(inner, keys, current)
+                }
             }
         }
-    }.into()
+    }
+
+    let mut keys = Vec::new();
+    let mut current = Some(String::new());
+    visit(segments, &mut keys, &mut current);
+    // The empty line after a final line break has no key of its own.
+    keys.extend(current.filter(|key| !key.is_empty()));
+    keys
+}
+
+/// Gathers the names of fields repeated with `{#field}...{/field}` without duplication.
+fn collect_block_names(segments: &[TemplateSegments]) -> HashSet<String> {
+    field_segments(segments)
+        .into_iter()
+        .filter_map(|(segment, _)| match segment {
+            TemplateSegments::Block(name, _) => Some(name.to_string()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>()
+}
+
+/// Gathers the names of fields included with `{>field}` without duplication.
+fn collect_include_names(segments: &[TemplateSegments]) -> HashSet<String> {
+    field_segments(segments)
+        .into_iter()
+        .filter_map(|(segment, _)| match segment {
+            TemplateSegments::Include(name) => Some(name.trim().to_string()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>()
 }
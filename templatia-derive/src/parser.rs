@@ -1,9 +1,627 @@
 pub(crate) enum TemplateSegments<'a> {
     Literal(&'a str),
-    Placeholder(&'a str),
+    /// `{field}`, optionally with a type hint and an inline default like `{field:u16=8080}`.
+    Placeholder(&'a str, PlaceholderSpec<'a>),
+    /// `{>field}`: the field's own `Template` implementation is spliced in at this position.
+    Include(&'a str),
+    /// `[...]`: an optional section that is rendered only when its `Option` placeholders are `Some`
+    /// and that may be absent when parsing.
+    Group(Vec<TemplateSegments<'a>>),
+    /// `{#field}...{/field}`: the body is repeated for each element of a `Vec` field, and its
+    /// placeholders refer to the fields of the element.
+    Block(&'a str, Vec<TemplateSegments<'a>>),
+    /// `(a|b)`: literal text accepted in any of the spellings when parsing, rendered as the first one.
+    Alternation(Vec<&'a str>),
+    /// `{$NAME}`: the environment variable rendered from the process environment, whose text is
+    /// skipped when parsing.
+    Env(&'a str),
+    /// `{*field}`: the `key = value` lines of the input that no other line of the template matches,
+    /// collected into a map field and rendered as lines of their own.
+    Extras(&'a str),
+    /// `{?=text}` or `{?!text}`: the text must or, when negated, must not come next, without being
+    /// consumed when parsing. Nothing is rendered.
+    Lookahead(&'a str, bool),
 }
 
-pub(crate) fn parse_template(template: &'_ str) -> Result<Vec<TemplateSegments<'_>>, String> {
+/// The options written inside a placeholder after its name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct PlaceholderSpec<'a> {
+    /// `{field=default}`: the text used for an empty capture when parsing and for a `None` when rendering.
+    pub(crate) default: Option<&'a str>,
+    /// `{field:type}`: the type the field is declared with, checked at compile time.
+    pub(crate) type_hint: Option<&'a str>,
+    /// `{field|filter|...}`: the filter names separated by `|`, all of them known to [`Filter`].
+    pub(crate) filters: Option<&'a str>,
+    /// `{field<<END}`: the line that ends a multi-line value, rendered on its own line after the value.
+    pub(crate) terminator: Option<&'a str>,
+    /// `{field*", "}`: the text joining the elements of a collection, in place of its `separator`.
+    pub(crate) separator: Option<&'a str>,
+    /// `{field:1..=65535}`: the range the parsed number must fall in, written in place of a type hint.
+    pub(crate) range: Option<&'a str>,
+    /// `{field:trim}`, `{field:u32,width=6}`: the [`Modifier`]s separated by `,`, after the type hint or range.
+    pub(crate) modifiers: Option<&'a str>,
+}
+
+/// The bounds of a `{field:min..max}` range, whose upper bound is included with `..=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RangeBounds<'a> {
+    pub(crate) min: Option<&'a str>,
+    pub(crate) max: Option<&'a str>,
+    pub(crate) inclusive: bool,
+}
+
+impl<'a> RangeBounds<'a> {
+    /// Splits the text of a range like `1..=65535`, `0..`, or `..10` into its bounds.
+    ///
+    /// # Returns
+    /// - `None` when the text isn't a range of number literals with at least one bound.
+    fn parse(text: &'a str) -> Option<Self> {
+        let (min, max) = text.split_once("..")?;
+        let (max, inclusive) = match max.strip_prefix('=') {
+            Some(max) => (max, true),
+            None => (max, false),
+        };
+        let bound = |text: &'a str| {
+            let text = text.trim();
+            (!text.is_empty()).then_some(text)
+        };
+        let (min, max) = (bound(min), bound(max));
+        let is_number = |text: &str| text.parse::<f64>().is_ok();
+        if (min.is_none() && max.is_none())
+            || (inclusive && max.is_none())
+            || !min.into_iter().chain(max).all(is_number)
+        {
+            return None;
+        }
+        Some(RangeBounds {
+            min,
+            max,
+            inclusive,
+        })
+    }
+}
+
+impl<'a> PlaceholderSpec<'a> {
+    /// Returns the bounds of the range constraint, if any.
+    pub(crate) fn range(&self) -> Option<RangeBounds<'a>> {
+        self.range.and_then(RangeBounds::parse)
+    }
+
+    /// Returns the filters applied to the rendered value, in the order written.
+    pub(crate) fn filters(&self) -> Vec<Filter> {
+        self.filters
+            .into_iter()
+            .flat_map(|filters| filters.split('|'))
+            .filter_map(Filter::from_name)
+            .collect()
+    }
+
+    /// Returns the modifiers applied to the field, in the order written.
+    pub(crate) fn modifiers(&self) -> Vec<Modifier> {
+        self.modifiers
+            .into_iter()
+            .flat_map(|modifiers| modifiers.split(','))
+            .filter_map(|item| Modifier::parse(item)?.ok())
+            .collect()
+    }
+}
+
+/// A per-field option written in a placeholder, like `{field:trim}` or `{field:width=6}`, which
+/// works as the field attribute of the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Modifier {
+    /// `trim`: `#[templatia(trim)]`.
+    Trim,
+    /// `uppercase`: `#[templatia(uppercase)]`.
+    Uppercase,
+    /// `lowercase`: `#[templatia(lowercase)]`.
+    Lowercase,
+    /// `quoted`: `#[templatia(quoted)]`.
+    Quoted,
+    /// `rest`: `#[templatia(rest)]`.
+    Rest,
+    /// `until_eol`: `#[templatia(until_eol)]`.
+    UntilEol,
+    /// `width=N`: `#[templatia(width = N)]`.
+    Width(usize),
+    /// `.2`, `>8`: `#[templatia(format = "{:.2}")]`, with `#[templatia(trim)]` when the spec pads
+    /// the value to a width, so the padding is dropped again when parsing. `fill` is the fill
+    /// character and alignment of a padding spec filling with anything but spaces, like `*^10`.
+    Format {
+        spec: String,
+        padded: bool,
+        fill: Option<(char, char)>,
+    },
+}
+
+impl Modifier {
+    /// Modifiers written with a value, whose `=` doesn't start the default of the placeholder.
+    const VALUED: [&'static str; 1] = ["width"];
+
+    /// Parses one `,`-separated item of the text after `:` in a placeholder.
+    ///
+    /// # Returns
+    /// - `None` when the item isn't a modifier, such as a type hint.
+    /// - `Some(Err(..))` when the item is a modifier with an invalid value.
+    fn parse(item: &str) -> Option<Result<Self, String>> {
+        if let Some(format) = Self::parse_format(item.trim()) {
+            return Some(format);
+        }
+        let modifier = match item.trim() {
+            "trim" => Modifier::Trim,
+            "uppercase" => Modifier::Uppercase,
+            "lowercase" => Modifier::Lowercase,
+            "quoted" => Modifier::Quoted,
+            "rest" => Modifier::Rest,
+            "until_eol" => Modifier::UntilEol,
+            item => {
+                let (name, value) = item.split_once('=')?;
+                if name.trim() != "width" {
+                    return None;
+                }
+                return Some(value.trim().parse().map(Modifier::Width).map_err(|_| {
+                    format!(
+                        "The width modifier \"{}\" must be a non-negative integer like width=6",
+                        item
+                    )
+                }));
+            }
+        };
+        Some(Ok(modifier))
+    }
+
+    /// Parses a `format!` spec like `.2`, `>8`, `*^10`, or `+08.3`, which may end with one of the
+    /// types `x`, `X`, `b`, `o`, `e`, and `E`.
+    ///
+    /// # Returns
+    /// - `None` when the item doesn't start like a spec. A range like `1..=10` isn't a spec, and
+    ///   neither is an item starting with a character and an alignment that doesn't parse, such as the
+    ///   type hint `A<B>`.
+    /// - `Some(Err(..))` when the item starts with a spec character but isn't a valid spec.
+    fn parse_format(item: &str) -> Option<Result<Self, String>> {
+        let mut chars = item.chars();
+        let (first, second) = (chars.next()?, chars.next());
+        let align = |c: Option<char>| matches!(c, Some('<' | '^' | '>'));
+        let starts_spec = matches!(first, '.' | '+' | '-' | '#') || first.is_ascii_digit();
+        if item.contains("..") || !(starts_spec || align(Some(first)) || align(second)) {
+            return None;
+        }
+
+        let mut rest = item;
+        if align(second) {
+            rest = &rest[first.len_utf8() + 1..];
+        } else if align(Some(first)) {
+            rest = &rest[1..];
+        }
+        rest = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+        rest = rest.strip_prefix('#').unwrap_or(rest);
+        let after_width = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+        let padded = after_width.len() < rest.len() && rest.trim_start_matches('0') != after_width;
+        rest = after_width;
+        // A `.` must be followed by the precision digits.
+        let mut valid = true;
+        if let Some(precision) = rest.strip_prefix('.') {
+            rest = precision.trim_start_matches(|c: char| c.is_ascii_digit());
+            valid = rest.len() < precision.len();
+        }
+        if !valid || !matches!(rest, "" | "x" | "X" | "b" | "o" | "e" | "E") {
+            return (starts_spec || align(Some(first))).then(|| {
+                Err(format!(
+                    "The format spec \"{}\" is invalid. expected a spec like .2, >8, or 08",
+                    item
+                ))
+            });
+        }
+        // Spaces are dropped by the trimming, while other fill characters are stripped on their own.
+        let fill = second
+            .filter(|&second| padded && first != ' ' && align(Some(second)))
+            .map(|second| (first, second));
+        Some(Ok(Modifier::Format {
+            spec: item.to_string(),
+            padded,
+            fill,
+        }))
+    }
+}
+
+/// Splits the text after `:` in a placeholder into the type hint or range and the modifiers after it.
+///
+/// # Errors
+/// - A modifier has an invalid value.
+fn split_modifiers(hint: &str) -> Result<(Option<&str>, Option<&str>), String> {
+    // Modifiers are taken from the end, so a type hint like `HashMap<String, u8>` keeps its commas.
+    let mut hint_end = hint.len();
+    while hint_end > 0 {
+        let item_start = hint[..hint_end].rfind(',').map_or(0, |index| index + 1);
+        match Modifier::parse(&hint[item_start..hint_end]) {
+            Some(Ok(_)) => hint_end = item_start.saturating_sub(1),
+            Some(Err(e)) => return Err(e),
+            None => break,
+        }
+    }
+    let modifiers = hint[hint_end..].trim_start_matches(',');
+    let type_hint = hint[..hint_end].trim();
+    Ok((
+        (!type_hint.is_empty() || modifiers.is_empty()).then_some(type_hint),
+        (!modifiers.is_empty()).then_some(modifiers),
+    ))
+}
+
+/// A built-in filter of `{field|filter}` applied to the rendered value of a placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Filter {
+    /// `upper`: converts to uppercase. Parsing can't restore the case, so the value is parsed as is.
+    Upper,
+    /// `lower`: converts to lowercase. Parsing can't restore the case, so the value is parsed as is.
+    Lower,
+    /// `trim`: removes surrounding whitespace, which is also removed from the capture when parsing.
+    Trim,
+    /// `url_encode`: percent-encodes everything but unreserved characters, decoded again when parsing.
+    UrlEncode,
+}
+
+impl Filter {
+    pub(crate) const NAMES: [&'static str; 4] = ["upper", "lower", "trim", "url_encode"];
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "upper" => Some(Filter::Upper),
+            "lower" => Some(Filter::Lower),
+            "trim" => Some(Filter::Trim),
+            "url_encode" => Some(Filter::UrlEncode),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> TemplateSegments<'a> {
+    /// Returns the field name referenced by a placeholder, include, block, or extras segment.
+    pub(crate) fn field_name(&self) -> Option<&'a str> {
+        match self {
+            TemplateSegments::Placeholder(name, _)
+            | TemplateSegments::Include(name)
+            | TemplateSegments::Block(name, _)
+            | TemplateSegments::Extras(name) => Some(name),
+            TemplateSegments::Literal(_)
+            | TemplateSegments::Group(_)
+            | TemplateSegments::Alternation(_)
+            | TemplateSegments::Env(_)
+            | TemplateSegments::Lookahead(..) => None,
+        }
+    }
+}
+
+/// Collects the modifiers written in the placeholders of a template.
+///
+/// # Parameters
+/// - template: The template string as written, before it is dedented or unescaped.
+///
+/// # Returns
+/// - Pairs of the placeholder name and a modifier, or nothing when the template doesn't parse.
+///   The errors of the template are reported when it is parsed for the generated code.
+pub(crate) fn template_modifiers(template: &str) -> Vec<(&str, Modifier)> {
+    // Without optional fields and alternations, the placeholders of `[...]` sections stay at the
+    // top level, and parentheses never contain placeholders.
+    let segments = parse_template(template, &|_| false, false).unwrap_or_default();
+    segments
+        .iter()
+        .flat_map(|segment| match segment {
+            TemplateSegments::Placeholder(name, spec) => spec
+                .modifiers()
+                .into_iter()
+                .map(|modifier| (*name, modifier))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// Returns the placeholder and include segments in template order, including those inside groups.
+///
+/// # Returns
+/// - Pairs of the segment and whether it is inside an optional group.
+pub(crate) fn field_segments<'s, 'a>(
+    segments: &'s [TemplateSegments<'a>],
+) -> Vec<(&'s TemplateSegments<'a>, bool)> {
+    let mut result = Vec::new();
+    for segment in segments {
+        match segment {
+            TemplateSegments::Group(inner) => result.extend(
+                field_segments(inner)
+                    .into_iter()
+                    .map(|(seg, _)| (seg, true)),
+            ),
+            TemplateSegments::Placeholder(..)
+            | TemplateSegments::Include(_)
+            | TemplateSegments::Block(..)
+            | TemplateSegments::Extras(_) => result.push((segment, false)),
+            TemplateSegments::Literal(_)
+            | TemplateSegments::Alternation(_)
+            | TemplateSegments::Env(_)
+            | TemplateSegments::Lookahead(..) => {}
+        }
+    }
+    result
+}
+
+/// Strips the indentation shared by all non-blank lines of a template for `#[templatia(dedent)]`.
+///
+/// A line break right after the opening quote is dropped, and a last line holding only spaces and
+/// tabs, which is the indentation of the closing quote, is emptied like the other blank lines.
+pub(crate) fn dedent(template: &str) -> String {
+    let is_blank = |line: &str| line.trim_matches([' ', '\t', '\r']).is_empty();
+    let template = template
+        .strip_prefix("\r\n")
+        .or_else(|| template.strip_prefix('\n'))
+        .unwrap_or(template);
+    let indent = template
+        .split('\n')
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0);
+
+    template
+        .split('\n')
+        .map(|line| match line {
+            // The carriage return of a CRLF line break is kept on blank lines.
+            line if is_blank(line) && line.ends_with('\r') => "\r",
+            line if is_blank(line) => "",
+            line => &line[indent..],
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Resolves the escape sequences of a template for `#[templatia(unescape)]`.
+///
+/// `\n`, `\r`, `\t`, `\0`, `\\`, `\"`, and `\u{...}` with 1 to 6 hexadecimal digits are replaced
+/// by the characters they stand for, as in Rust string literals.
+///
+/// # Errors
+/// - An unknown escape sequence, a malformed or invalid `\u{...}`, and a `\` ending the template.
+pub(crate) fn unescape(template: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        let escaped = match chars.next() {
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('0') => '\0',
+            Some('\\') => '\\',
+            Some('"') => '"',
+            Some('u') => {
+                let rest = chars.as_str();
+                let code = rest
+                    .strip_prefix('{')
+                    .and_then(|rest| rest.split_once('}'))
+                    .map(|(digits, _)| digits)
+                    .filter(|digits| (1..=6).contains(&digits.len()))
+                    .and_then(|digits| {
+                        u32::from_str_radix(digits, 16)
+                            .ok()
+                            .map(|code| (digits, code))
+                    });
+                let Some((digits, code)) = code else {
+                    return Err("expected \\u{...} with 1 to 6 hexadecimal digits".to_string());
+                };
+                let Some(escaped) = char::from_u32(code) else {
+                    return Err(format!("\\u{{{}}} is not a valid character", digits));
+                };
+                // The braces and the digits are consumed.
+                chars = rest[digits.len() + 2..].chars();
+                escaped
+            }
+            Some(other) => return Err(format!("unknown escape sequence \\{}", other)),
+            None => return Err("the template ends with an incomplete escape sequence".to_string()),
+        };
+        result.push(escaped);
+    }
+    Ok(result)
+}
+
+/// Finds the `]` closing an optional group, skipping the escaped `]]` and `[[`.
+///
+/// # Returns
+/// - The byte index of the closing `]` in `rest`, which starts after the opening `[`.
+fn find_group_end(rest: &str) -> Option<usize> {
+    let mut chars = rest.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '[' | ']' if chars.peek().is_some_and(|&(_, next)| next == c) => {
+                chars.next();
+            }
+            ']' => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the byte range removed for the `{#- ... -#}` comment opened at `open`.
+///
+/// A comment alone on its line takes the whole line with its line break, so that annotating
+/// a template doesn't leave blank lines behind.
+///
+/// # Errors
+/// - The comment isn't closed by `-#}`.
+fn comment_span(template: &str, open: usize) -> Result<(usize, usize), String> {
+    let body_start = open + "{#-".len();
+    let end = template[body_start..]
+        .find("-#}")
+        .map(|e| body_start + e + "-#}".len())
+        .ok_or_else(|| "Unclosed comment '{#-': expected '-#}'".to_string())?;
+
+    let is_blank = |text: &str| text.chars().all(|c| c == ' ' || c == '\t');
+    let line_start = template[..open].rfind('\n').map_or(0, |n| n + 1);
+    let line_end = template[end..]
+        .find('\n')
+        .map_or(template.len(), |n| end + n + 1);
+    let after = template[end..line_end].trim_end_matches(['\n', '\r']);
+    if is_blank(&template[line_start..open]) && is_blank(after) {
+        Ok((line_start, line_end))
+    } else {
+        Ok((open, end))
+    }
+}
+
+/// Returns the length of a `{% name %}` tag at the start of the text, with any spaces inside the
+/// delimiters, or `None` when the text doesn't start with that tag.
+fn tag_len(text: &str, name: &str) -> Option<usize> {
+    let inner = text.strip_prefix("{%")?;
+    let end = inner.find("%}")?;
+    (inner[..end].trim() == name).then_some(2 + end + 2)
+}
+
+/// Finds the end of a `{% raw %}` section whose text starts at `start`.
+///
+/// # Returns
+/// - The end of the raw text and the end of the closing `{% endraw %}` tag.
+///
+/// # Errors
+/// - The section isn't closed by `{% endraw %}`.
+fn raw_span(template: &str, start: usize) -> Result<(usize, usize), String> {
+    template[start..]
+        .match_indices("{%")
+        .find_map(|(offset, _)| {
+            tag_len(&template[start + offset..], "endraw")
+                .map(|len| (start + offset, start + offset + len))
+        })
+        .ok_or_else(|| "Unclosed raw section: expected {% endraw %}".to_string())
+}
+
+/// Splits the text of a placeholder like `port:u16|trim=8080` into the name and its spec.
+///
+/// The default is everything after the first `=` outside a range, kept verbatim, so it may contain `:` and `|`.
+/// The name, the type hint, and the filter names are trimmed. A hint containing `..`, like
+/// `port:1..=65535`, is a range constraint instead of a type hint. A heredoc placeholder like
+/// `body<<END` has only its terminator, and a collection placeholder like `tags*", "` has only
+/// its separator, which is kept verbatim between the quotes.
+///
+/// # Errors
+/// - A filter name that isn't one of the built-in filters.
+/// - A heredoc terminator that is empty or contains whitespace.
+/// - An empty separator.
+/// - A range whose bounds aren't number literals.
+fn parse_placeholder_spec(placeholder: &str) -> Result<(&str, PlaceholderSpec<'_>), String> {
+    if let Some((name, separator)) = placeholder.split_once("*\"")
+        && let Some(separator) = separator.trim_end().strip_suffix('"')
+    {
+        if separator.is_empty() {
+            return Err(format!(
+                "The separator of {{{}}} must not be empty",
+                placeholder
+            ));
+        }
+        let spec = PlaceholderSpec {
+            separator: Some(separator),
+            ..PlaceholderSpec::default()
+        };
+        return Ok((name.trim(), spec));
+    }
+    if let Some((name, terminator)) = placeholder.split_once("<<") {
+        let terminator = terminator.trim();
+        if terminator.is_empty() || terminator.contains(char::is_whitespace) {
+            return Err(format!(
+                "The terminator of {{{}}} must be a non-empty word without whitespace",
+                placeholder
+            ));
+        }
+        let spec = PlaceholderSpec {
+            terminator: Some(terminator),
+            ..PlaceholderSpec::default()
+        };
+        return Ok((name.trim(), spec));
+    }
+    // The `=` of an inclusive range like `1..=65535` or of a modifier like `width=6` doesn't start a default.
+    let default_start = placeholder
+        .match_indices('=')
+        .map(|(index, _)| index)
+        .find(|index| {
+            let before = &placeholder[..*index];
+            let item = before.rsplit([':', ',']).next().unwrap_or_default().trim();
+            let is_modifier = before.contains(':') && Modifier::VALUED.contains(&item);
+            !(before.ends_with("..") || is_modifier)
+        });
+    let (head, default) = match default_start {
+        Some(index) => (&placeholder[..index], Some(&placeholder[index + 1..])),
+        None => (placeholder, None),
+    };
+    let (head, filters) = match head.split_once('|') {
+        Some((head, filters)) => (head, Some(filters)),
+        None => (head, None),
+    };
+    if let Some(unknown) = filters
+        .into_iter()
+        .flat_map(|filters| filters.split('|'))
+        .find(|name| Filter::from_name(name).is_none())
+    {
+        return Err(format!(
+            "Unknown filter \"{}\" in {{{}}}. available filters: {}",
+            unknown.trim(),
+            placeholder,
+            Filter::NAMES.join(", ")
+        ));
+    }
+    let (name, type_hint, modifiers) = match head.split_once(':') {
+        Some((name, hint)) => {
+            let (type_hint, modifiers) =
+                split_modifiers(hint).map_err(|e| format!("{} in {{{}}}", e, placeholder))?;
+            (name, type_hint, modifiers)
+        }
+        None => (head, None, None),
+    };
+    // A hint with `..` is a range of the number rather than its type.
+    let (type_hint, range) = match type_hint {
+        Some(range) if range.contains("..") => {
+            if RangeBounds::parse(range).is_none() {
+                return Err(format!(
+                    "Invalid range \"{}\" in {{{}}}. expected number bounds like 1..=65535, 0.., or ..10",
+                    range, placeholder
+                ));
+            }
+            (None, Some(range))
+        }
+        type_hint => (type_hint, None),
+    };
+    let spec = PlaceholderSpec {
+        default,
+        type_hint,
+        filters,
+        terminator: None,
+        separator: None,
+        range,
+        modifiers,
+    };
+    Ok((name.trim(), spec))
+}
+
+/// Splits a template into literal, placeholder, include, optional group, block, and alternation segments.
+/// `{#- ... -#}` comments are dropped, together with their line when they stand alone on it, and the
+/// text of a `{% raw %}...{% endraw %}` section is a literal as written.
+///
+/// # Parameters
+/// - template: The template string.
+/// - is_optional: Returns true when the named field is an `Option`. A `[...]` section becomes an
+///   optional group only when it contains a placeholder of such a field; otherwise the brackets
+///   are kept as literal text. `[[` and `]]` are escaped brackets, like `{{` and `}}`.
+/// - alternation: Enables `#[templatia(alternation)]`. A `(a|b)` section without other template
+///   syntax inside is then an alternation, and `((` and `))` are escaped parentheses. Otherwise
+///   parentheses are plain literal text.
+///
+/// # Errors
+/// - Unmatched or nested braces, nested optional groups, unclosed or nested blocks, and unclosed
+///   comments and raw sections.
+pub(crate) fn parse_template<'a>(
+    template: &'a str,
+    is_optional: &dyn Fn(&str) -> bool,
+    alternation: bool,
+) -> Result<Vec<TemplateSegments<'a>>, String> {
     let mut segments = Vec::new();
     let mut last_end = 0;
     let mut chars = template.char_indices().peekable();
@@ -11,18 +629,41 @@ pub(crate) fn parse_template(template: &'_ str) -> Result<Vec<TemplateSegments<'
     while let Some((i, c)) = chars.next() {
         match c {
             '{' => {
-                if let Some(&(next_idx, next_char)) = chars.peek() {
-                    // if the next char is a `{`, it means escaped brace, so it shouldn't be treated as a placeholder.
-                    if next_char == '{' {
-                        // In escaped brace displayed as `{` in literal, not should be `{{`.
-                        if next_idx > last_end {
-                            segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
-                            last_end = next_idx + 1;
-                        }
+                // Braces pair up from the left: `{{` is an escaped brace displayed as `{`, so in `{{{field}}}`
+                // the first two braces are a literal `{` and the third one opens the placeholder.
+                if let Some(&(next_idx, '{')) = chars.peek() {
+                    segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
+                    last_end = next_idx + 1;
+                    chars.next();
+                    continue;
+                }
 
+                if let Some(open) = tag_len(&template[i..], "raw") {
+                    let (content_end, end) = raw_span(template, i + open)?;
+                    if i > last_end {
+                        segments.push(TemplateSegments::Literal(&template[last_end..i]));
+                    }
+                    if content_end > i + open {
+                        segments.push(TemplateSegments::Literal(&template[i + open..content_end]));
+                    }
+                    last_end = end;
+                    while chars.peek().is_some_and(|&(idx, _)| idx < end) {
                         chars.next();
-                        continue;
                     }
+                    continue;
+                }
+
+                if template[i + 1..].starts_with("#-") {
+                    let (start, end) = comment_span(template, i)?;
+                    let start = start.max(last_end);
+                    if start > last_end {
+                        segments.push(TemplateSegments::Literal(&template[last_end..start]));
+                    }
+                    last_end = end;
+                    while chars.peek().is_some_and(|&(idx, _)| idx < end) {
+                        chars.next();
+                    }
+                    continue;
                 }
 
                 if i > last_end {
@@ -39,7 +680,84 @@ pub(crate) fn parse_template(template: &'_ str) -> Result<Vec<TemplateSegments<'
                 if placeholder.contains('{') {
                     return Err(format!("Nested braces are not supported: {}", placeholder));
                 }
-                segments.push(TemplateSegments::Placeholder(placeholder.trim()));
+                // The text of a lookahead is kept verbatim, including its spaces.
+                let lookahead = placeholder
+                    .strip_prefix("?=")
+                    .map(|text| (text, false))
+                    .or_else(|| placeholder.strip_prefix("?!").map(|text| (text, true)));
+                let placeholder = placeholder.trim();
+                let end = if let Some((text, negated)) = lookahead {
+                    if text.is_empty() {
+                        return Err(format!("Empty lookahead {{{}}}", placeholder));
+                    }
+                    segments.push(TemplateSegments::Lookahead(text, negated));
+                    end
+                } else if let Some(block) = placeholder.strip_prefix('#') {
+                    let block = block.trim();
+                    let closing = format!("{{/{}}}", block);
+                    let body_end = template[end + 1..]
+                        .find(&closing)
+                        .map(|e| end + 1 + e)
+                        .ok_or_else(|| {
+                            format!("Unclosed block {{#{}}}: expected {}", block, closing)
+                        })?;
+                    // The placeholders of the body belong to the element, so none of them is optional.
+                    let body =
+                        parse_template(&template[end + 1..body_end], &|_| false, alternation)?;
+                    if body.iter().any(|segment| {
+                        !matches!(
+                            segment,
+                            TemplateSegments::Literal(_)
+                                | TemplateSegments::Placeholder(
+                                    _,
+                                    PlaceholderSpec {
+                                        default: None,
+                                        type_hint: None,
+                                        filters: None,
+                                        terminator: None,
+                                        separator: None,
+                                        range: None,
+                                        modifiers: None,
+                                    }
+                                )
+                        )
+                    }) {
+                        return Err(format!(
+                            "Block {{#{}}} can contain only literals and plain placeholders of the element",
+                            block
+                        ));
+                    }
+                    segments.push(TemplateSegments::Block(block, body));
+                    body_end + closing.len() - 1
+                } else if let Some(block) = placeholder.strip_prefix('/') {
+                    return Err(format!("Unmatched block end {{/{}}}", block.trim()));
+                } else if let Some(variable) = placeholder.strip_prefix('$') {
+                    let variable = variable.trim();
+                    if variable.is_empty()
+                        || !variable
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    {
+                        return Err(format!(
+                            "Invalid environment variable name in {{${}}}",
+                            variable
+                        ));
+                    }
+                    segments.push(TemplateSegments::Env(variable));
+                    end
+                } else if let Some(extras) = placeholder.strip_prefix('*') {
+                    segments.push(TemplateSegments::Extras(extras.trim()));
+                    end
+                } else {
+                    match placeholder.strip_prefix('>') {
+                        Some(included) => segments.push(TemplateSegments::Include(included.trim())),
+                        None => {
+                            let (name, spec) = parse_placeholder_spec(placeholder)?;
+                            segments.push(TemplateSegments::Placeholder(name, spec));
+                        }
+                    }
+                    end
+                };
 
                 // Proceed last_end to after the placeholder's end brace('}')
                 last_end = end + 1;
@@ -58,20 +776,101 @@ pub(crate) fn parse_template(template: &'_ str) -> Result<Vec<TemplateSegments<'
                     }
                 }
             }
-            '}' => {
-                if let Some(&(next_idx, next_char)) = chars.peek() {
-                    // if the next char is a `}`, it means escaped brace, so it shouldn't be treated as an end brace.
-                    if next_char == '}' {
-                        // In escaped brace displayed as `}` in literal, not should be `}}`.
-                        if next_idx > last_end {
-                            segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
-                            last_end = next_idx + 1;
-                        }
+            '[' => {
+                if let Some(&(next_idx, '[')) = chars.peek() {
+                    // `[[` is an escaped bracket displayed as `[`, like the escaped braces.
+                    segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
+                    last_end = next_idx + 1;
+                    chars.next();
+                    continue;
+                }
+                let Some(end) = find_group_end(&template[i + 1..]).map(|e| i + 1 + e) else {
+                    continue;
+                };
+                let inner = parse_template(&template[i + 1..end], is_optional, alternation)?;
+                let is_group = inner.iter().any(|segment| {
+                    matches!(segment, TemplateSegments::Placeholder(name, _) if is_optional(name))
+                });
+                if !is_group {
+                    // Brackets without an optional placeholder are plain literal text.
+                    continue;
+                }
+                if inner
+                    .iter()
+                    .any(|segment| matches!(segment, TemplateSegments::Group(_)))
+                {
+                    return Err(format!(
+                        "Nested optional groups are not supported: [{}]",
+                        &template[i + 1..end]
+                    ));
+                }
 
+                if i > last_end {
+                    segments.push(TemplateSegments::Literal(&template[last_end..i]));
+                }
+                segments.push(TemplateSegments::Group(inner));
+
+                last_end = end + 1;
+                while let Some((idx, _)) = chars.peek().copied() {
+                    if idx <= end {
                         chars.next();
-                        continue;
+                    } else {
+                        break;
                     }
                 }
+            }
+            '(' if alternation => {
+                if let Some(&(next_idx, '(')) = chars.peek() {
+                    // `((` is an escaped parenthesis displayed as `(`, like the escaped brackets.
+                    segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
+                    last_end = next_idx + 1;
+                    chars.next();
+                    continue;
+                }
+                let Some(end) = template[i + 1..].find(')').map(|e| i + 1 + e) else {
+                    continue;
+                };
+                let inner = &template[i + 1..end];
+                if !inner.contains('|') || inner.contains(['{', '}', '[', ']', '(', '\n']) {
+                    // Parentheses without alternatives, or around other template syntax, are plain literal text.
+                    continue;
+                }
+
+                if i > last_end {
+                    segments.push(TemplateSegments::Literal(&template[last_end..i]));
+                }
+                segments.push(TemplateSegments::Alternation(inner.split('|').collect()));
+
+                last_end = end + 1;
+                while chars.peek().is_some_and(|&(idx, _)| idx <= end) {
+                    chars.next();
+                }
+            }
+            ')' if alternation => {
+                if let Some(&(next_idx, ')')) = chars.peek() {
+                    // `))` is an escaped parenthesis displayed as `)`. A single `)` is kept as is.
+                    segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
+                    last_end = next_idx + 1;
+                    chars.next();
+                }
+            }
+            ']' => {
+                if let Some(&(next_idx, ']')) = chars.peek() {
+                    // `]]` is an escaped bracket displayed as `]`. A single `]` outside a group is kept as is.
+                    segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
+                    last_end = next_idx + 1;
+                    chars.next();
+                }
+            }
+            '}' => {
+                // A placeholder ends at its first `}`, so the braces left after it pair up as escaped braces
+                // displayed as `}`, like the last two braces of `{{{field}}}`.
+                if let Some(&(next_idx, '}')) = chars.peek() {
+                    segments.push(TemplateSegments::Literal(&template[last_end..next_idx]));
+                    last_end = next_idx + 1;
+                    chars.next();
+                    continue;
+                }
                 return Err("Unmatched closing brace '}'".to_string());
             }
             _ => {}
@@ -84,3 +883,135 @@ pub(crate) fn parse_template(template: &'_ str) -> Result<Vec<TemplateSegments<'
 
     Ok(segments)
 }
+
+/// Replaces the placeholder names written in the template with the names of the fields they refer to.
+///
+/// # Parameters
+/// - segments: The parsed template segments.
+/// - resolve: Maps a placeholder name to its field name, or fails with a message.
+///
+/// # Errors
+/// - The message from `resolve` for the first placeholder that can't be resolved.
+pub(crate) fn resolve_placeholder_names<'a>(
+    segments: Vec<TemplateSegments<'a>>,
+    resolve: &dyn Fn(&'a str) -> Result<&'a str, String>,
+) -> Result<Vec<TemplateSegments<'a>>, String> {
+    segments
+        .into_iter()
+        .map(|segment| {
+            Ok(match segment {
+                TemplateSegments::Placeholder(name, spec) => {
+                    TemplateSegments::Placeholder(resolve(name)?, spec)
+                }
+                TemplateSegments::Include(name) => TemplateSegments::Include(resolve(name)?),
+                TemplateSegments::Extras(name) => TemplateSegments::Extras(resolve(name)?),
+                TemplateSegments::Group(inner) => {
+                    TemplateSegments::Group(resolve_placeholder_names(inner, resolve)?)
+                }
+                // The placeholders of a block body name the fields of the element, which aren't resolved here.
+                TemplateSegments::Block(name, body) => {
+                    TemplateSegments::Block(resolve(name)?, body)
+                }
+                literal => literal,
+            })
+        })
+        .collect()
+}
+
+/// Turns each template line whose placeholders all belong to omittable `Option` fields into an
+/// implicit optional group for `#[templatia(omit_none)]`, so the whole line is left out when a
+/// value is `None`.
+///
+/// # Parameters
+/// - segments: The parsed template segments with resolved field names.
+/// - is_omittable: Returns true when the named field can be used inside an optional group.
+///
+/// # Returns
+/// - The segments with such lines wrapped in groups. A group takes the line break before its
+///   line, and a group on the first line takes the one after it.
+///
+/// # Notes
+/// - Lines with includes, blocks, explicit groups, alternations, or environment variables, and
+///   lines with a placeholder used more than once in the template, are kept as they are.
+pub(crate) fn omit_none_lines<'a>(
+    segments: Vec<TemplateSegments<'a>>,
+    is_omittable: &dyn Fn(&str) -> bool,
+) -> Vec<TemplateSegments<'a>> {
+    let names = field_segments(&segments)
+        .into_iter()
+        .filter_map(|(segment, _)| segment.field_name())
+        .collect::<Vec<_>>();
+    let occurrences = |name: &str| names.iter().filter(|other| **other == name).count();
+
+    let mut lines: Vec<Vec<TemplateSegments<'a>>> = vec![Vec::new()];
+    let mut breaks = Vec::new();
+    for segment in segments {
+        let TemplateSegments::Literal(lit) = segment else {
+            lines
+                .last_mut()
+                .expect("lines is never empty")
+                .push(segment);
+            continue;
+        };
+        for piece in lit.split_inclusive('\n') {
+            let line = lines.last_mut().expect("lines is never empty");
+            let Some(text) = piece.strip_suffix('\n') else {
+                line.push(TemplateSegments::Literal(piece));
+                continue;
+            };
+            let cut = text.strip_suffix('\r').unwrap_or(text).len();
+            if cut > 0 {
+                line.push(TemplateSegments::Literal(&piece[..cut]));
+            }
+            breaks.push(&piece[cut..]);
+            lines.push(Vec::new());
+        }
+    }
+    let omittable = lines
+        .iter()
+        .map(|line| {
+            line.iter()
+                .any(|segment| matches!(segment, TemplateSegments::Placeholder(..)))
+                && line.iter().all(|segment| match segment {
+                    TemplateSegments::Literal(_) => true,
+                    TemplateSegments::Placeholder(name, _) => {
+                        is_omittable(name) && occurrences(name) == 1
+                    }
+                    _ => false,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    let mut result = Vec::new();
+    let mut pending = None;
+    for (index, line) in lines.into_iter().enumerate() {
+        let next_omittable = omittable.get(index + 1).copied().unwrap_or(false);
+        // The line break after this line belongs to the next line's group when there is one.
+        let line_break = breaks.get(index).map(|lb| TemplateSegments::Literal(lb));
+        let (trailing, next_leading) = if next_omittable {
+            (None, line_break)
+        } else {
+            (line_break, None)
+        };
+        if !omittable[index] {
+            result.extend(line);
+            result.extend(trailing);
+            pending = next_leading;
+            continue;
+        }
+        // A group takes the line break before it, so only a first line takes the one after it.
+        let mut group = Vec::new();
+        if let Some(leading) = pending.take() {
+            group.push(leading);
+            group.extend(line);
+            result.push(TemplateSegments::Group(group));
+            result.extend(trailing);
+        } else {
+            group.extend(line);
+            group.extend(trailing);
+            result.push(TemplateSegments::Group(group));
+        }
+        pending = next_leading;
+    }
+    result
+}
@@ -1,8 +1,10 @@
+use crate::case::ValueCase;
 use crate::error::{
     generate_not_found_placeholder_compile_error, generate_unsupported_compile_error,
 };
-use crate::fields::{FieldKind, Fields};
-use crate::parser::TemplateSegments;
+use crate::fields::{FieldKind, Fields, FloatStyle};
+use crate::parser::{Filter, TemplateSegments, field_segments};
+use crate::utils::{boxed_inner, get_type_name, option_inner};
 use proc_macro2::TokenStream;
 use quote::quote;
 
@@ -10,12 +12,21 @@ pub(super) fn generate_format_string_args(
     segments: &[TemplateSegments<'_>],
     fields: &Fields,
 ) -> (String, Vec<TokenStream>) {
+    let krate = fields.crate_path();
     // Generate format string like "key = {}, key2 = {}"
     let format_string = segments
         .iter()
         .map(|segment| match segment {
-            TemplateSegments::Literal(lit) => lit.replace("{", "{{").replace("}", "}}"),
-            TemplateSegments::Placeholder(_) => "{}".to_string(),
+            TemplateSegments::Literal(_) | TemplateSegments::Alternation(_) => {
+                literal_format(segment, fields)
+            }
+            TemplateSegments::Lookahead(..) => String::new(),
+            TemplateSegments::Placeholder(..)
+            | TemplateSegments::Include(_)
+            | TemplateSegments::Group(_)
+            | TemplateSegments::Block(..)
+            | TemplateSegments::Env(_)
+            | TemplateSegments::Extras(_) => "{}".to_string(),
         })
         // This collect works because the String implements FromIterator.
         .collect::<String>();
@@ -23,8 +34,10 @@ pub(super) fn generate_format_string_args(
     // Generate code for placeholder completion the format_string it used the self keys
     let format_args = segments
         .iter()
-        .filter_map(|segment| match segment {
-            TemplateSegments::Placeholder(name) => {
+        .enumerate()
+        .filter_map(|(index, segment)| match segment {
+            TemplateSegments::Placeholder(name, spec) => {
+                let next = segments.get(index + 1);
                 let field_ident = syn::Ident::new(name, proc_macro2::Span::call_site());
 
                 // &self.#field_ident means the field of the struct named `field_ident`
@@ -35,33 +48,156 @@ pub(super) fn generate_format_string_args(
                 // then the field_ident is `x` or `y`.
                 // The token stream indicates &self.x or &self.y.
                 // Please note: the #field_ident is not `field_ident` but `x` or `y`.
+                if fields.is_skip_render(&field_ident) {
+                    return Some(quote! { "" });
+                }
+                if let Some(mask) = fields.get_secret(&field_ident) {
+                    // An absent optional value stays empty so that the mask doesn't suggest a value.
+                    return Some(match fields.get_field_kind(&field_ident) {
+                        Some(FieldKind::Option(_)) => {
+                            let none_value = none_value(fields, &field_ident, spec.default);
+                            quote! {
+                                &self.#field_ident.as_ref().map(|_| #mask.to_string()).unwrap_or_else(|| #none_value)
+                            }
+                        },
+                        _ => quote! { #mask },
+                    });
+                }
+                if let Some(with) = fields.get_with(&field_ident) {
+                    return Some(quote! { &#with::render(&self.#field_ident) });
+                }
+
                 match fields.get_field_kind(&field_ident) {
                     Some(ty) => match ty {
+                        FieldKind::Option(inner) if boxed_inner(inner).is_some() => {
+                            let none_value = none_value(fields, &field_ident, spec.default);
+                            Some(quote! {
+                                &self.#field_ident.as_ref().map(|v| #krate::RenderTemplate::render_string(&**v)).unwrap_or_else(|| #none_value)
+                            })
+                        },
                         FieldKind::Option(_) => {
+                            let value = format_value(fields, &field_ident, quote! { v });
+                            let value = escape_value(fields, &field_ident, value, next);
+                            let none_value = none_value(fields, &field_ident, spec.default);
                             Some(quote! {
-                                &self.#field_ident.as_ref().map(|v| v.to_string()).unwrap_or_else(|| String::new())
+                                &self.#field_ident.as_ref().map(|v| #value).unwrap_or_else(|| #none_value)
                             })
                         },
-                        FieldKind::Vec(_) => {
+                        FieldKind::Vec(ty) => {
+                            let separator = spec.separator.unwrap_or_else(|| fields.get_separator(&field_ident));
+                            let value = element_value(fields, &field_ident, ty, separator, next);
                             Some(quote! {
-                                &self.#field_ident.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+                                &self.#field_ident.iter().map(|v| #value).collect::<Vec<_>>().join(#separator)
                             })
                         },
+                        // A `HashSet` iterates in an arbitrary order, so its elements are sorted by their rendered text.
                         FieldKind::HashSet(_) => {
+                            let separator = spec.separator.unwrap_or_else(|| fields.get_separator(&field_ident));
+                            let value = format_value(fields, &field_ident, quote! { v });
+                            let value = escape_element(fields, &field_ident, value, separator, next);
                             Some(quote! {
-                                &self.#field_ident.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+                                &{
+                                    let mut values = self.#field_ident.iter().map(|v| #value).collect::<Vec<_>>();
+                                    values.sort();
+                                    values.join(#separator)
+                                }
                             })
                         },
                         FieldKind::BTreeSet(_) => {
+                            let separator = spec.separator.unwrap_or_else(|| fields.get_separator(&field_ident));
+                            let value = format_value(fields, &field_ident, quote! { v });
+                            let value = escape_element(fields, &field_ident, value, separator, next);
                             Some(quote! {
-                                &self.#field_ident.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+                                &self.#field_ident.iter().map(|v| #value).collect::<Vec<_>>().join(#separator)
                             })
                         },
-                        FieldKind::Primitive(_) => {
+                        FieldKind::Result(..) => {
+                            let (ok_prefix, err_prefix) = fields.get_result_prefixes(&field_ident);
+                            Some(quote! {
+                                &match &self.#field_ident {
+                                    Ok(v) => format!("{}{}", #ok_prefix, v),
+                                    Err(e) => format!("{}{}", #err_prefix, e),
+                                }
+                            })
+                        },
+                        FieldKind::VecDeque(ty)
+                        | FieldKind::LinkedList(ty)
+                        | FieldKind::IndexSet(ty)
+                        | FieldKind::SmallVec(ty)
+                        | FieldKind::ArrayVec(ty) => {
+                            let separator = spec.separator.unwrap_or_else(|| fields.get_separator(&field_ident));
+                            let value = element_value(fields, &field_ident, ty, separator, next);
+                            Some(quote! {
+                                &self.#field_ident.iter().map(|v| #value).collect::<Vec<_>>().join(#separator)
+                            })
+                        },
+                        // A `BinaryHeap` iterates in an arbitrary order, so its values are rendered in ascending order.
+                        FieldKind::BinaryHeap(_) => {
+                            let separator = spec.separator.unwrap_or_else(|| fields.get_separator(&field_ident));
+                            let value = format_value(fields, &field_ident, quote! { v });
+                            let value = escape_element(fields, &field_ident, value, separator, next);
                             Some(quote! {
-                                &self.#field_ident
+                                &{
+                                    let mut values = self.#field_ident.iter().collect::<Vec<_>>();
+                                    values.sort();
+                                    values.into_iter().map(|v| #value).collect::<Vec<_>>().join(#separator)
+                                }
                             })
                         },
+                        // The entries of a `HashMap` are sorted so that it renders the same text every time.
+                        FieldKind::HashMap(..) | FieldKind::BTreeMap(..) | FieldKind::IndexMap(..) => {
+                            let separator = spec.separator.unwrap_or_else(|| fields.get_separator(&field_ident));
+                            let kv_separator = fields.get_kv_separator(&field_ident);
+                            let sort = matches!(ty, FieldKind::HashMap(..)).then(|| quote! {
+                                let mut entries = entries;
+                                entries.sort();
+                            });
+                            // With `escape`, the delimiters inside the keys and the values are escaped.
+                            let (key, value) = match fields.get_escape(&field_ident) {
+                                Some(escape) => {
+                                    let krate = fields.crate_path();
+                                    let literals = next_literals(next);
+                                    let escape_text = |text: TokenStream| quote! {
+                                        #krate::__private::escape(
+                                            &#text.to_string(),
+                                            #escape,
+                                            &[#separator, #kv_separator, #(#literals),*],
+                                        )
+                                    };
+                                    (escape_text(quote! { key }), escape_text(quote! { value }))
+                                }
+                                None => (quote! { key }, quote! { value }),
+                            };
+                            Some(quote! {
+                                &{
+                                    let entries = self.#field_ident
+                                        .iter()
+                                        .map(|(key, value)| format!("{}{}{}", #key, #kv_separator, #value))
+                                        .collect::<Vec<_>>();
+                                    #sort
+                                    entries.join(#separator)
+                                }
+                            })
+                        },
+                        FieldKind::Primitive(_) => {
+                            if fields.get_format(&field_ident).is_some()
+                                || fields.get_case(&field_ident).is_some()
+                                || fields.get_escape(&field_ident).is_some()
+                                || fields.is_quoted(&field_ident)
+                                || fields.get_bool_literals(&field_ident).is_some()
+                                || fields.get_float(&field_ident).is_some()
+                                || fields.get_datetime_format(&field_ident).is_some()
+                                || fields.is_bytesize(&field_ident)
+                                || fields.get_uuid_format(&field_ident).is_some()
+                                || ty.lossy_display().is_some()
+                            {
+                                let value = format_value(fields, &field_ident, quote! { self.#field_ident });
+                                let value = escape_value(fields, &field_ident, value, next);
+                                Some(quote! { &#value })
+                            } else {
+                                Some(quote! { &self.#field_ident })
+                            }
+                        },
                         _ => {
                             Some(generate_unsupported_compile_error(&field_ident, ty))
                         },
@@ -69,8 +205,483 @@ pub(super) fn generate_format_string_args(
                     _ => Some(generate_not_found_placeholder_compile_error("struct", name))
                 }
             },
-            TemplateSegments::Literal(_) => None,
+            TemplateSegments::Include(name) => {
+                let field_ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+
+                match fields.get_field_kind(&field_ident) {
+                    Some(_) => {
+                        let value = include_value(fields, &field_ident, quote! { self.#field_ident });
+                        Some(quote! { &#value })
+                    },
+                    None => Some(generate_not_found_placeholder_compile_error("struct", name)),
+                }
+            },
+            TemplateSegments::Group(inner) => Some(generate_group_arg(inner, fields)),
+            TemplateSegments::Block(name, body) => Some(generate_block_arg(name, body, fields)),
+            TemplateSegments::Env(variable) => Some(env_value(variable)),
+            // The entries are sorted so that a `HashMap` renders the same text every time.
+            TemplateSegments::Extras(name) => {
+                let field_ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+                let line_break = fields.render_literal("\n");
+                Some(quote! {
+                    {
+                        let mut lines = self.#field_ident
+                            .iter()
+                            .map(|(key, value)| format!("{} = {}{}", key, value, #line_break))
+                            .collect::<Vec<_>>();
+                        lines.sort();
+                        lines.concat()
+                    }
+                })
+            },
+            TemplateSegments::Literal(_)
+            | TemplateSegments::Alternation(_)
+            | TemplateSegments::Lookahead(..) => None,
         }).collect::<Vec<_>>();
+    // Every segment but the literal ones and lookaheads has an argument, in the same order.
+    let format_args = segments
+        .iter()
+        .filter(|segment| {
+            !matches!(
+                segment,
+                TemplateSegments::Literal(_)
+                    | TemplateSegments::Alternation(_)
+                    | TemplateSegments::Lookahead(..)
+            )
+        })
+        .zip(format_args)
+        .map(|(segment, arg)| apply_spec(segment, arg, krate))
+        .collect::<Vec<_>>();
 
     (format_string, format_args)
 }
+
+/// Returns the format string text of a literal, or of the first spelling of an alternation,
+/// with the braces escaped for `format!`.
+fn literal_format(segment: &TemplateSegments, fields: &Fields) -> String {
+    let lit = match segment {
+        TemplateSegments::Literal(lit) => lit,
+        TemplateSegments::Alternation(alternatives) => alternatives.first().unwrap_or(&""),
+        _ => "",
+    };
+    fields
+        .render_literal(lit)
+        .replace("{", "{{")
+        .replace("}", "}}")
+}
+
+/// Applies the `{field|filter}` filters of a placeholder to its rendered argument, in the order written,
+/// and puts the terminator line after the value of a `{field<<END}` heredoc.
+fn apply_spec(segment: &TemplateSegments, arg: TokenStream, krate: &syn::Path) -> TokenStream {
+    let TemplateSegments::Placeholder(_, spec) = segment else {
+        return arg;
+    };
+    let filters = spec.filters();
+    if filters.is_empty() && spec.terminator.is_none() {
+        return arg;
+    }
+    let steps = filters.iter().map(|filter| match filter {
+        Filter::Upper => quote! { let value = value.to_uppercase(); },
+        Filter::Lower => quote! { let value = value.to_lowercase(); },
+        Filter::Trim => quote! { let value = value.trim().to_string(); },
+        Filter::UrlEncode => quote! { let value = #krate::__private::url_encode(&value); },
+    });
+    // A terminator line inside the value would end the heredoc early, so the output wouldn't parse.
+    let terminator = spec.terminator.map(|terminator| {
+        quote! {
+            debug_assert!(
+                !value.lines().any(|line| line == #terminator),
+                "the heredoc value {:?} contains its terminator line {:?}",
+                value,
+                #terminator,
+            );
+            let value = format!("{}\n{}", value, #terminator);
+        }
+    });
+
+    quote! {
+        &{
+            let value = (#arg).to_string();
+            #(#steps)*
+            #terminator
+            value
+        }
+    }
+}
+
+/// Generates the expression reading `{$NAME}` from the process environment, which is empty when
+/// the variable isn't set or isn't valid Unicode.
+fn env_value(variable: &str) -> TokenStream {
+    quote! { &::std::env::var(#variable).unwrap_or_default() }
+}
+
+/// Generates the argument rendering an optional group.
+///
+/// The group is rendered only when all of its `Option` fields are `Some`, and the values are
+/// bound to locals with the field names so that the placeholders inside refer to the unwrapped values.
+/// A value rendering as empty text is omitted like `None` when an empty capture parses as `None`.
+/// Only placeholders of `Option` fields are allowed in groups, which the validator enforces.
+fn generate_group_arg(segments: &[TemplateSegments<'_>], fields: &Fields) -> TokenStream {
+    let krate = fields.crate_path();
+    let mut group_fields = Vec::new();
+    for (segment, _) in field_segments(segments) {
+        if let Some(name) = segment.field_name()
+            && matches!(
+                fields.get_type_kind_by_name(name),
+                Some(FieldKind::Option(_))
+            )
+            && !group_fields.contains(&name)
+        {
+            group_fields.push(name);
+        }
+    }
+    let group_idents = group_fields
+        .iter()
+        .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()))
+        .collect::<Vec<_>>();
+
+    let format_string = segments
+        .iter()
+        .map(|segment| match segment {
+            TemplateSegments::Literal(_) | TemplateSegments::Alternation(_) => {
+                literal_format(segment, fields)
+            }
+            TemplateSegments::Lookahead(..) => String::new(),
+            _ => "{}".to_string(),
+        })
+        .collect::<String>();
+    let format_args = segments.iter().enumerate().filter_map(|(index, segment)| {
+        if let TemplateSegments::Env(variable) = segment {
+            return Some(env_value(variable));
+        }
+        let name = segment.field_name()?;
+        let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+        match fields.get_field_kind(&ident) {
+            Some(FieldKind::Option(_)) if fields.is_skip_render(&ident) => {
+                Some(quote! { { let _ = #ident; "" } })
+            }
+            Some(FieldKind::Option(_)) if fields.get_secret(&ident).is_some() => {
+                // The binding is still consumed to avoid an unused variable warning in the generated code.
+                fields
+                    .get_secret(&ident)
+                    .map(|mask| quote! { { let _ = #ident; #mask } })
+            }
+            Some(FieldKind::Option(inner)) if boxed_inner(inner).is_some() => {
+                Some(quote! { #krate::RenderTemplate::render_string(&**#ident) })
+            }
+            Some(FieldKind::Option(_)) => Some(escape_value(
+                fields,
+                &ident,
+                format_value(fields, &ident, quote! { #ident }),
+                segments.get(index + 1),
+            )),
+            // Other fields are rejected by the validator; they are rendered as is to avoid extra errors.
+            _ => Some(quote! { &self.#ident }),
+        }
+        .map(|arg| apply_spec(segment, arg, krate))
+    });
+
+    // An empty value would parse back as `None`, so the group collapses to `None` for it.
+    let non_empty = group_idents
+        .iter()
+        .filter(|ident| {
+            fields.is_empty_capture_none(ident)
+                && !fields.is_skip_render(ident)
+                && fields.get_secret(ident).is_none()
+        })
+        .map(|ident| match fields.get_field_kind(ident) {
+            Some(FieldKind::Option(inner)) if boxed_inner(inner).is_some() => {
+                quote! { !#krate::RenderTemplate::render_string(&**#ident).is_empty() }
+            }
+            _ => {
+                let value = format_value(fields, ident, quote! { #ident });
+                quote! { !#value.is_empty() }
+            }
+        })
+        .collect::<Vec<_>>();
+    let guard = (!non_empty.is_empty()).then(|| quote! { if #(#non_empty)&&* });
+
+    quote! {
+        &match (#(&self.#group_idents,)*) {
+            (#(Some(#group_idents),)*) #guard => format!(#format_string, #(#format_args),*),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Generates the argument rendering a `{#field}...{/field}` block, which formats the body with the
+/// fields of each element through `Display` and concatenates the results.
+fn generate_block_arg(name: &str, body: &[TemplateSegments], fields: &Fields) -> TokenStream {
+    let ident = syn::Ident::new(name, proc_macro2::Span::call_site());
+    let format_string = body
+        .iter()
+        .map(|segment| match segment {
+            TemplateSegments::Literal(_) => literal_format(segment, fields),
+            _ => "{}".to_string(),
+        })
+        .collect::<String>();
+    let element_fields = body
+        .iter()
+        .filter_map(TemplateSegments::field_name)
+        .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()));
+
+    quote! {
+        &self.#ident
+            .iter()
+            .map(|element| format!(#format_string, #(element.#element_fields),*))
+            .collect::<String>()
+    }
+}
+
+/// Generates the expression rendering `None` of an `Option` field, which is the `render_none` token,
+/// the inline `{field=default}` text, or an empty string. A fixed-width `None` is padded so that the following fields stay aligned.
+fn none_value(fields: &Fields, ident: &syn::Ident, default: Option<&str>) -> TokenStream {
+    let token = fields
+        .get_render_none(ident)
+        .or(default)
+        .unwrap_or_default();
+    match fields.get_width(ident) {
+        Some(width) => quote! { format!("{:1$}", #token, #width) },
+        None => quote! { #token.to_string() },
+    }
+}
+
+/// Generates the expression converting a single value of the field to a string,
+/// using `#[templatia(format = "...")]` when given and `ToString` otherwise.
+/// The letter case of `#[templatia(uppercase)]` or `#[templatia(lowercase)]` is applied afterwards,
+/// and a `#[templatia(width = N)]` value is then cut to its width.
+fn format_value(fields: &Fields, ident: &syn::Ident, value: TokenStream) -> TokenStream {
+    let value = match lossy_display(fields, ident) {
+        Some(display) => quote! { #value #display },
+        None => value,
+    };
+    let radix_format = signed_radix(fields, ident);
+    let formatted = match (fields.get_format(ident), radix_format) {
+        (_, _) if fields.get_uuid_format(ident).is_some() => {
+            // SAFETY: The guard checked that the representation is given.
+            let method = fields.get_uuid_format(ident).unwrap().method();
+            let method = syn::Ident::new(method, proc_macro2::Span::call_site());
+            let formatted = quote! { #value.#method().to_string() };
+            match fields.get_width(ident) {
+                Some(width) => quote! { format!("{:>1$}", #formatted, #width) },
+                None => formatted,
+            }
+        }
+        (_, _) if fields.is_bytesize(ident) => {
+            let krate = fields.crate_path();
+            let formatted = quote! { #krate::__private::render_bytesize(#value) };
+            match fields.get_width(ident) {
+                Some(width) => quote! { format!("{:>1$}", #formatted, #width) },
+                None => formatted,
+            }
+        }
+        (_, _) if fields.get_datetime_format(ident).is_some() => {
+            let krate = fields.crate_path();
+            // SAFETY: The guard checked that the format is given.
+            let datetime_format = fields.get_datetime_format(ident).unwrap();
+            // The method call dereferences the element references of options and collections.
+            let formatted = quote! {
+                {
+                    use #krate::__private::DateTimeFormat as _;
+                    #value.format_datetime(#datetime_format)
+                }
+            };
+            match fields.get_width(ident) {
+                Some(width) => quote! { format!("{:>1$}", #formatted, #width) },
+                None => formatted,
+            }
+        }
+        // The radix formats print negative signed values as two's complement, which doesn't parse back,
+        // so the sign is written separately. An explicit `format` is used as is.
+        (Some(_), Some(radix_format)) if fields.get_explicit_format(ident).is_none() => {
+            let padding = match fields.get_width(ident) {
+                Some(width) => format!("{{:>{}}}", width),
+                None => "{}".to_string(),
+            };
+            quote! {
+                format!(
+                    #padding,
+                    if #value.is_negative() {
+                        format!(concat!("-", #radix_format), #value.unsigned_abs())
+                    } else {
+                        format!(#radix_format, #value)
+                    }
+                )
+            }
+        }
+        // Both notations read back to the same bits, so the shorter one is picked for each value.
+        (_, _) if fields.get_float(ident) == Some(FloatStyle::Shortest) => {
+            let shortest = quote! {
+                {
+                    let (plain, exponent) = (format!("{}", #value), format!("{:e}", #value));
+                    if exponent.len() < plain.len() { exponent } else { plain }
+                }
+            };
+            match fields.get_width(ident) {
+                Some(width) => quote! { format!("{:>1$}", #shortest, #width) },
+                None => shortest,
+            }
+        }
+        (Some(format), _) => quote! { format!(#format, #value) },
+        (None, _) => match fields.get_bool_literals(ident) {
+            Some((true_values, false_values)) => {
+                let (true_value, false_value) = (&true_values[0], &false_values[0]);
+                quote! { #value.then_some(#true_value).unwrap_or(#false_value).to_string() }
+            }
+            None => quote! { #value.to_string() },
+        },
+    };
+
+    let formatted = match fields.get_case(ident) {
+        Some(ValueCase::Upper) => quote! { #formatted.to_uppercase() },
+        Some(ValueCase::Lower) => quote! { #formatted.to_lowercase() },
+        None => formatted,
+    };
+    // A fixed-width value is parsed as exactly its width, so longer values are cut loudly.
+    match fields.get_width(ident) {
+        Some(width) => {
+            let krate = fields.crate_path();
+            quote! { #krate::__private::fit_width(#formatted, #width) }
+        }
+        None => formatted,
+    }
+}
+
+/// Returns the method call rendering the `PathBuf` or `OsString` values of the field, if it holds them.
+///
+/// The call is appended to a value so that the result implements `Display`.
+pub(crate) fn lossy_display(fields: &Fields, ident: &syn::Ident) -> Option<TokenStream> {
+    let method = fields.get_field_kind(ident)?.lossy_display()?;
+    let method = syn::Ident::new(method, proc_macro2::Span::call_site());
+    Some(quote! { .#method() })
+}
+
+/// Returns the rendered text of the `{>field}` include holding `value`.
+///
+/// A sequence of `Template` elements renders each element through its own template, joined by the
+/// separator of the field.
+pub(crate) fn include_value(
+    fields: &Fields,
+    ident: &syn::Ident,
+    value: TokenStream,
+) -> TokenStream {
+    let krate = fields.crate_path();
+    match fields
+        .get_field_kind(ident)
+        .and_then(FieldKind::sequence_element)
+    {
+        Some(_) => {
+            let separator = fields.get_separator(ident);
+            quote! {
+                #value
+                    .iter()
+                    .map(#krate::RenderTemplate::render_string)
+                    .collect::<::std::vec::Vec<_>>()
+                    .join(#separator)
+            }
+        }
+        None => quote! { #krate::RenderTemplate::render_string(&#value) },
+    }
+}
+
+/// Returns the rendered text of the element `v` of a sequence field, where a `None` element of a
+/// sparse sequence like `Vec<Option<T>>` is an empty slot between the separators.
+/// With `#[templatia(escape = '...')]`, the separator inside the element is escaped.
+fn element_value(
+    fields: &Fields,
+    ident: &syn::Ident,
+    ty: &syn::Type,
+    separator: &str,
+    next: Option<&TemplateSegments<'_>>,
+) -> TokenStream {
+    let value = format_value(fields, ident, quote! { v });
+    let value = escape_element(fields, ident, value, separator, next);
+    if option_inner(ty).is_some() {
+        quote! { v.as_ref().map(|v| #value).unwrap_or_default() }
+    } else {
+        value
+    }
+}
+
+/// Returns the radix format like `{:x}` of a signed integer field with `#[templatia(radix = N)]`, if any.
+fn signed_radix(fields: &Fields, ident: &syn::Ident) -> Option<&'static str> {
+    let ty = match fields.get_field_kind(ident)? {
+        FieldKind::Primitive(ty)
+        | FieldKind::Option(ty)
+        | FieldKind::Vec(ty)
+        | FieldKind::HashSet(ty)
+        | FieldKind::BTreeSet(ty)
+        | FieldKind::VecDeque(ty)
+        | FieldKind::LinkedList(ty)
+        | FieldKind::BinaryHeap(ty)
+        | FieldKind::IndexSet(ty)
+        | FieldKind::SmallVec(ty)
+        | FieldKind::ArrayVec(ty) => ty,
+        _ => return None,
+    };
+    if !get_type_name(ty).starts_with('i') {
+        return None;
+    }
+    match fields.get_radix(ident)? {
+        2 => Some("{:b}"),
+        8 => Some("{:o}"),
+        16 => Some("{:x}"),
+        _ => None,
+    }
+}
+
+/// Wraps a rendered value so that the escape character and the literal right after the placeholder
+/// are escaped for `#[templatia(escape = '...')]`, or the value is quoted for `#[templatia(quoted)]`.
+/// Returns the value unchanged otherwise.
+fn escape_value(
+    fields: &Fields,
+    ident: &syn::Ident,
+    value: TokenStream,
+    next: Option<&TemplateSegments<'_>>,
+) -> TokenStream {
+    let krate = fields.crate_path();
+    if fields.is_quoted(ident) {
+        return quote! { #krate::__private::quote(&#value) };
+    }
+    let Some(escape) = fields.get_escape(ident) else {
+        return value;
+    };
+    let literals = next_literals(next);
+
+    quote! { #krate::__private::escape(&#value, #escape, &[#(#literals),*]) }
+}
+
+/// Wraps the rendered element `value` of a collection field with `#[templatia(escape = '...')]`,
+/// so that the separator and the literal right after the placeholder inside it are escaped.
+/// The separator inside a `Url` element is percent-encoded instead. Returns the value unchanged otherwise.
+fn escape_element(
+    fields: &Fields,
+    ident: &syn::Ident,
+    value: TokenStream,
+    separator: &str,
+    next: Option<&TemplateSegments<'_>>,
+) -> TokenStream {
+    let krate = fields.crate_path();
+    let Some(escape) = fields.get_escape(ident) else {
+        // A serialized `Url` is percent-encoded already, so the separator is encoded the same way.
+        if fields
+            .get_field_kind(ident)
+            .is_some_and(|kind| kind.is_url())
+        {
+            return quote! { #krate::__private::percent_encode_separator(&#value, #separator) };
+        }
+        return value;
+    };
+    let literals = next_literals(next);
+
+    quote! { #krate::__private::escape(&#value, #escape, &[#separator, #(#literals),*]) }
+}
+
+/// Returns the literals that can directly follow a placeholder, which an escaped value must not end at.
+fn next_literals<'a>(next: Option<&TemplateSegments<'a>>) -> Vec<&'a str> {
+    match next {
+        Some(TemplateSegments::Literal(lit)) => vec![*lit],
+        Some(TemplateSegments::Alternation(alternatives)) => alternatives.clone(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,62 @@
+use crate::case::RenameRule;
+use darling::FromMeta;
+use syn::Token;
+
+/// Returns the name that serde uses for a field, given with `#[serde(rename = "...")]` or
+/// `#[serde(rename(serialize = "..."))]`, for `#[templatia(serde_compat)]`.
+///
+/// # Errors
+/// - The `serde` attributes are malformed.
+pub(crate) fn serde_rename(attrs: &[syn::Attribute]) -> syn::Result<Option<String>> {
+    Ok(serde_value(attrs, "rename")?.map(|name| name.value()))
+}
+
+/// Returns the casing rule given with `#[serde(rename_all = "...")]` or
+/// `#[serde(rename_all(serialize = "..."))]` on the container, for `#[templatia(serde_compat)]`.
+///
+/// # Errors
+/// - The `serde` attributes are malformed, or the rule is unknown.
+pub(crate) fn serde_rename_all(attrs: &[syn::Attribute]) -> syn::Result<Option<RenameRule>> {
+    serde_value(attrs, "rename_all")?
+        .map(|rule| {
+            RenameRule::from_string(&rule.value())
+                .map_err(|e| syn::Error::new(rule.span(), e.to_string()))
+        })
+        .transpose()
+}
+
+/// Finds the string of `key = "..."` or of `serialize` in `key(serialize = "...")` in the `serde`
+/// attributes, skipping all other serde options.
+fn serde_value(attrs: &[syn::Attribute], key: &str) -> syn::Result<Option<syn::LitStr>> {
+    let mut found = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident(key) {
+                return skip_meta_value(&meta);
+            }
+            if meta.input.peek(Token![=]) {
+                found = Some(meta.value()?.parse()?);
+                return Ok(());
+            }
+            meta.parse_nested_meta(|inner| {
+                if inner.path.is_ident("serialize") {
+                    found = Some(inner.value()?.parse()?);
+                    Ok(())
+                } else {
+                    skip_meta_value(&inner)
+                }
+            })
+        })?;
+    }
+    Ok(found)
+}
+
+/// Consumes the value of a serde option that isn't read, such as `= "..."` or `(...)`.
+fn skip_meta_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+        meta.input.parse::<proc_macro2::TokenTree>()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use syn::DeriveInput;
+
+/// Rewrites a union into a named struct with the same fields so that the struct options can parse it.
+///
+/// # Parameters
+/// - ast: The derive input. It is modified in place when it is a union.
+///
+/// # Returns
+/// - true when the input was a union.
+pub(crate) fn union_as_struct(ast: &mut DeriveInput) -> bool {
+    let syn::Data::Union(data_union) = &ast.data else {
+        return false;
+    };
+
+    ast.data = syn::Data::Struct(syn::DataStruct {
+        struct_token: syn::Token![struct](data_union.union_token.span),
+        fields: syn::Fields::Named(data_union.fields.clone()),
+        semi_token: None,
+    });
+    true
+}
+
+/// Selects the fields used by the template of a struct or a union.
+///
+/// A union value can only be rendered and parsed through the single field that is always initialized,
+/// so the template of a union only sees the field designated by `#[templatia(active = "...")]`.
+///
+/// # Parameters
+/// - name: The identifier of the target type.
+/// - fields: All fields of the target type.
+/// - is_union: Whether the target type is a union.
+/// - active: The field designated by `#[templatia(active = "...")]`, if any.
+/// - unsafe_union: Whether `#[templatia(unsafe_union)]` is given.
+///
+/// # Returns
+/// - The fields the template can reference.
+///
+/// # Errors
+/// - `active` is missing for a union, names a non-existent field, or is used on a struct.
+/// - `unsafe_union` is missing for a union or is used on a struct.
+pub(crate) fn select_template_fields(
+    name: &syn::Ident,
+    fields: &[syn::Field],
+    is_union: bool,
+    active: Option<&syn::Ident>,
+    unsafe_union: bool,
+) -> Result<Vec<syn::Field>, syn::Error> {
+    match (is_union, active) {
+        (false, _) if unsafe_union => Err(syn::Error::new_spanned(
+            name,
+            "templatia(unsafe_union) is supported only for unions",
+        )),
+        (false, None) => Ok(fields.to_vec()),
+        (false, Some(active)) => Err(syn::Error::new_spanned(
+            active,
+            "templatia(active = \"...\") is supported only for unions",
+        )),
+        (true, Some(_)) if !unsafe_union => Err(syn::Error::new_spanned(
+            name,
+            format!(
+                "Template for the union {} requires #[templatia(unsafe_union)]. \
+                reading the active field can't be checked, so the union is rendered \
+                through an unsafe render_string instead of RenderTemplate",
+                name
+            ),
+        )),
+        (true, None) => Err(syn::Error::new_spanned(
+            name,
+            format!(
+                "Template for the union {} requires #[templatia(active = \"field\")]. \
+                render_string reads the designated field and from_str initializes it, \
+                so it must be the field that is always initialized",
+                name
+            ),
+        )),
+        (true, Some(active)) => fields
+            .iter()
+            .find(|field| field.ident.as_ref() == Some(active))
+            .map(|field| vec![field.clone()])
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    active,
+                    format!("{} has no field named \"{}\"", name, active),
+                )
+            }),
+    }
+}
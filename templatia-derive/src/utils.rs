@@ -18,10 +18,126 @@ pub(crate) fn get_type_name(ty: &syn::Type) -> String {
         syn::Type::Path(path) => {
             if let Some(ident) = &path.path.get_ident() {
                 ident.to_string()
+            } else if let Some(last_segment) = path.path.segments.last() {
+                // Qualified paths like `std::num::NonZeroU16` are named by their last segment,
+                // and generic arguments like `NonZero<u16>` are kept to stay distinguishable.
+                match &last_segment.arguments {
+                    syn::PathArguments::None => last_segment.ident.to_string(),
+                    syn::PathArguments::AngleBracketed(args) => {
+                        let args = args
+                            .args
+                            .iter()
+                            .map(|arg| match arg {
+                                syn::GenericArgument::Type(ty) => get_type_name(ty),
+                                _ => "unrecognized".to_string(),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("{}<{}>", last_segment.ident, args)
+                    }
+                    syn::PathArguments::Parenthesized(_) => "unrecognized".to_string(),
+                }
             } else {
                 "unrecognized".to_string()
             }
         }
+        syn::Type::Reference(reference) => format!("&{}", get_type_name(&reference.elem)),
         _ => "unrecognized".to_string(),
     }
 }
+
+/// Returns true when the type is written as the given full path, with or without a leading `::`,
+/// such as `url::Url` for `["url", "Url"]`. A type named by its last segment alone doesn't match,
+/// since any type of that name may be in scope.
+pub(crate) fn is_full_path(ty: &syn::Type, segments: &[&str]) -> bool {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            path.path.segments.len() == segments.len()
+                && path
+                    .path
+                    .segments
+                    .iter()
+                    .zip(segments)
+                    .all(|(segment, name)| segment.arguments.is_none() && segment.ident == name)
+        }
+        _ => false,
+    }
+}
+
+/// Returns true when the type is a shared `&str` reference, which is parsed by borrowing from the input.
+pub(crate) fn is_borrowed_str(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Reference(reference) => {
+            reference.mutability.is_none()
+                && matches!(&*reference.elem, syn::Type::Path(path) if path.path.is_ident("str"))
+        }
+        _ => false,
+    }
+}
+
+/// Returns true when the type is a primitive integer, which has `from_str_radix`.
+pub(crate) fn is_integer_type(ty: &syn::Type) -> bool {
+    const INTEGER_TYPES: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+    INTEGER_TYPES.contains(&get_type_name(ty).as_str())
+}
+
+/// Returns true when the type is a primitive integer or floating-point number.
+pub(crate) fn is_number_type(ty: &syn::Type) -> bool {
+    is_integer_type(ty) || matches!(get_type_name(ty).as_str(), "f32" | "f64")
+}
+
+/// Returns true when the type is one of the `std::num::NonZero*` integers or `NonZero<T>`.
+pub(crate) fn is_nonzero_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident.to_string().starts_with("NonZero")),
+        _ => false,
+    }
+}
+
+/// Returns the `T` of a `Box<T>` type. Boxed fields delegate to the `Template` implementation of `T`.
+pub(crate) fn boxed_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    single_argument(ty, "Box")
+}
+
+/// Returns the `T` of an `Option<T>` type, used for the optional elements of sparse collections.
+pub(crate) fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    single_argument(ty, "Option")
+}
+
+/// Returns the type argument of a path type like `Name<T>` whose last segment is `name`.
+fn single_argument<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first() {
+                Some(syn::GenericArgument::Type(inner)) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns true when the type refers to the deriving type itself, either as `Self` or by its name.
+pub(crate) fn is_self_type(ty: &syn::Type, name: &syn::Ident) -> bool {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Self" || segment.ident == *name),
+        _ => false,
+    }
+}
@@ -0,0 +1,56 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Escaped braces directly around placeholders, like "{{{field}}}".
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{{{name}}}")]
+struct Wrapped {
+    name: String,
+}
+
+#[test]
+fn braces_around_a_placeholder_round_trip() {
+    let value = Wrapped {
+        name: "alice".to_string(),
+    };
+    assert_eq!(value.render_string(), "{alice}");
+    assert_eq!(Wrapped::from_str("{alice}").unwrap(), value);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{{{{{a}}}}}:{{{b}}}{{}}{c}")]
+struct Nested {
+    a: u32,
+    b: String,
+    c: char,
+}
+
+#[test]
+fn doubled_and_empty_braces_round_trip() {
+    let value = Nested {
+        a: 1,
+        b: "x".to_string(),
+        c: 'z',
+    };
+    assert_eq!(value.render_string(), "{{1}}:{x}{}z");
+    assert_eq!(Nested::from_str("{{1}}:{x}{}z").unwrap(), value);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "set {key}={{{value}}};")]
+struct Assignment {
+    key: String,
+    value: Option<u32>,
+}
+
+#[test]
+fn braces_after_a_literal_and_around_an_option() {
+    let value = Assignment {
+        key: "k".to_string(),
+        value: None,
+    };
+    assert_eq!(value.render_string(), "set k={};");
+    assert_eq!(Assignment::from_str("set k={};").unwrap(), value);
+    assert_eq!(Assignment::from_str("set k={5};").unwrap().value, Some(5));
+}
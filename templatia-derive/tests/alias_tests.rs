@@ -0,0 +1,71 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(alias = "...")]` accepts alternative key spellings before a placeholder when parsing.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name}\nhost={host}\nport = {port}")]
+struct Config {
+    name: String,
+    #[templatia(alias = "hostname")]
+    #[templatia(alias = "server")]
+    host: String,
+    #[templatia(alias = "listen_port")]
+    port: u16,
+}
+
+fn expected() -> Config {
+    Config {
+        name: "app".to_string(),
+        host: "localhost".to_string(),
+        port: 8080,
+    }
+}
+
+#[test]
+fn canonical_keys_parse() {
+    assert_eq!(
+        Config::from_str("name=app\nhost=localhost\nport = 8080").unwrap(),
+        expected()
+    );
+}
+
+#[test]
+fn alias_keys_parse() {
+    assert_eq!(
+        Config::from_str("name=app\nhostname=localhost\nlisten_port = 8080").unwrap(),
+        expected()
+    );
+    assert_eq!(
+        Config::from_str("name=app\nserver=localhost\nport = 8080").unwrap(),
+        expected()
+    );
+}
+
+#[test]
+fn render_uses_template_keys() {
+    assert_eq!(
+        expected().render_string(),
+        "name=app\nhost=localhost\nport = 8080"
+    );
+}
+
+#[test]
+fn unknown_key_is_an_error() {
+    assert!(Config::from_str("name=app\nhst=localhost\nport = 8080").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "db: {db}")]
+struct Database {
+    #[templatia(rename = "db", alias = "database")]
+    url: String,
+}
+
+#[test]
+fn alias_replaces_renamed_key() {
+    assert_eq!(
+        Database::from_str("database: postgres://x").unwrap().url,
+        "postgres://x"
+    );
+}
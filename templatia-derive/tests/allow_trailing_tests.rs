@@ -0,0 +1,47 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// allow_trailing: the input left after the end of the template is ignored when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{level}] code={code};", allow_trailing)]
+struct LogHead {
+    level: String,
+    code: u32,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{level}] code={code};")]
+struct StrictLogHead {
+    level: String,
+    code: u32,
+}
+
+#[test]
+fn trailing_input_is_ignored() {
+    let parsed = LogHead::from_str("[warn] code=42; disk almost full").unwrap();
+    assert_eq!(
+        parsed,
+        LogHead {
+            level: "warn".into(),
+            code: 42
+        }
+    );
+}
+
+#[test]
+fn input_without_trailing_text_still_parses() {
+    let parsed = LogHead::from_str("[info] code=0;").unwrap();
+    assert_eq!(parsed.code, 0);
+    assert_eq!(parsed.render_string(), "[info] code=0;");
+}
+
+#[test]
+fn template_must_still_match_fully() {
+    assert!(LogHead::from_str("[info] code=0").is_err());
+}
+
+#[test]
+fn trailing_input_fails_without_the_attribute() {
+    assert!(StrictLogHead::from_str("[warn] code=42; disk almost full").is_err());
+}
@@ -0,0 +1,60 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Alternative templates are tried in order after the primary template fails to match.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "https://{host}:{port}",
+    alt_template = "{host}:{port}",
+    alt_template = "{host}"
+)]
+struct Endpoint {
+    host: String,
+    port: Option<u16>,
+}
+
+#[test]
+fn primary_template_is_used_first() {
+    let parsed = Endpoint::from_str("https://example.com:443").unwrap();
+    assert_eq!(parsed.host, "example.com");
+    assert_eq!(parsed.port, Some(443));
+}
+
+#[test]
+fn alternatives_are_tried_in_order() {
+    let without_scheme = Endpoint::from_str("example.com:8443").unwrap();
+    assert_eq!(without_scheme.host, "example.com");
+    assert_eq!(without_scheme.port, Some(8443));
+
+    let host_only = Endpoint::from_str("example.com").unwrap();
+    assert_eq!(host_only.host, "example.com");
+    assert_eq!(host_only.port, None);
+}
+
+#[test]
+fn render_uses_primary_template() {
+    let endpoint = Endpoint {
+        host: "example.com".into(),
+        port: Some(443),
+    };
+    assert_eq!(endpoint.render_string(), "https://example.com:443");
+}
+
+#[test]
+fn primary_error_is_reported_when_nothing_matches() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port}", alt_template = "p={port}")]
+    struct Port {
+        port: u16,
+    }
+
+    assert_eq!(Port::from_str("p=80").unwrap().port, 80);
+
+    let err = Port::from_str("port=abc").expect_err("no template matches");
+    match err {
+        TemplateError::ParseToType { placeholder, .. } => assert_eq!(placeholder, "port"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
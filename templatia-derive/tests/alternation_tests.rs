@@ -0,0 +1,86 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// #[templatia(alternation)]: (a|b) literal alternatives accepted when parsing, rendered as the first one.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "(http|https)://{host}:{port}", alternation)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn every_spelling_is_accepted() {
+    let expected = Endpoint {
+        host: "example.com".to_string(),
+        port: 443,
+    };
+    assert_eq!(
+        Endpoint::from_str("http://example.com:443").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Endpoint::from_str("https://example.com:443").unwrap(),
+        expected
+    );
+    assert!(Endpoint::from_str("ftp://example.com:443").is_err());
+}
+
+#[test]
+fn rendering_uses_the_first_spelling() {
+    let endpoint = Endpoint {
+        host: "example.com".to_string(),
+        port: 80,
+    };
+    assert_eq!(endpoint.render_string(), "http://example.com:80");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{count} item(s|) (in|at) ({place})", alternation)]
+struct Stock {
+    count: u32,
+    place: String,
+}
+
+#[test]
+fn alternation_ends_a_capture_and_may_be_empty() {
+    let expected = Stock {
+        count: 3,
+        place: "shelf".to_string(),
+    };
+    assert_eq!(Stock::from_str("3 items in (shelf)").unwrap(), expected);
+    assert_eq!(Stock::from_str("3 item at (shelf)").unwrap(), expected);
+    assert_eq!(expected.render_string(), "3 items in (shelf)");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "mode=((a|b)) {mode}", alternation)]
+struct Escaped {
+    mode: String,
+}
+
+#[test]
+fn doubled_parentheses_are_literal() {
+    let value = Escaped {
+        mode: "a".to_string(),
+    };
+    assert_eq!(value.render_string(), "mode=(a|b) a");
+    assert_eq!(Escaped::from_str("mode=(a|b) a").unwrap(), value);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "f(x|y)=((({value})))")]
+struct Plain {
+    value: String,
+}
+
+#[test]
+fn parentheses_are_literal_without_the_attribute() {
+    let value = Plain {
+        value: "1".to_string(),
+    };
+    assert_eq!(value.render_string(), "f(x|y)=(((1)))");
+    assert_eq!(Plain::from_str("f(x|y)=(((1)))").unwrap(), value);
+    assert!(Plain::from_str("fx=(((1)))").is_err());
+}
@@ -0,0 +1,94 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Repeated blocks: `{#field}...{/field}` repeats its body for each element of a Vec field.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Debug, PartialEq, Clone)]
+struct ServerEntry {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name}\n{#servers}host={host}:{port}\n{/servers}end")]
+struct Cluster {
+    name: String,
+    servers: Vec<ServerEntry>,
+}
+
+fn cluster(servers: &[(&str, u16)]) -> Cluster {
+    Cluster {
+        name: "main".into(),
+        servers: servers
+            .iter()
+            .map(|(host, port)| ServerEntry {
+                host: host.to_string(),
+                port: *port,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn block_renders_each_element() {
+    assert_eq!(
+        cluster(&[("a", 1), ("b", 2)]).render_string(),
+        "name=main\nhost=a:1\nhost=b:2\nend"
+    );
+    assert_eq!(cluster(&[]).render_string(), "name=main\nend");
+}
+
+#[test]
+fn block_parses_repeated_elements() {
+    let value = cluster(&[("a", 1), ("b", 2), ("c", 3)]);
+    assert_eq!(Cluster::from_str(&value.render_string()).unwrap(), value);
+    assert_eq!(Cluster::from_str("name=main\nend").unwrap(), cluster(&[]));
+}
+
+#[test]
+fn element_type_errors_name_the_element_field() {
+    match Cluster::from_str("name=main\nhost=a:http\nend") {
+        Err(TemplateError::ParseToType {
+            placeholder,
+            value,
+            type_name,
+        }) => {
+            assert_eq!(placeholder, "servers.port");
+            assert_eq!(value, "http");
+            assert_eq!(type_name, "u16");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Pair<T> {
+    key: String,
+    value: T,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{#items}{key}={value};{/items}")]
+struct Pairs {
+    items: Vec<Pair<u8>>,
+}
+
+#[test]
+fn block_at_the_end_consumes_all_elements() {
+    let value = Pairs {
+        items: vec![
+            Pair {
+                key: "a".into(),
+                value: 1,
+            },
+            Pair {
+                key: "b".into(),
+                value: 2,
+            },
+        ],
+    };
+    assert_eq!(value.render_string(), "a=1;b=2;");
+    assert_eq!(Pairs::from_str("a=1;b=2;").unwrap(), value);
+    assert!(Pairs::from_str("a=1;b=2").is_err());
+}
@@ -0,0 +1,103 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(true_values = [...], false_values = [...])]` parses alternative spellings of bool values.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "enabled={enabled} verbose={verbose}")]
+struct Switches {
+    #[templatia(true_values = ["yes", "on"], false_values = ["no", "off"])]
+    enabled: bool,
+    #[templatia(true_values = ["1"])]
+    verbose: bool,
+}
+
+#[test]
+fn first_spelling_is_rendered() {
+    let switches = Switches {
+        enabled: true,
+        verbose: false,
+    };
+    assert_eq!(switches.render_string(), "enabled=yes verbose=false");
+    assert_eq!(
+        Switches::from_str(&switches.render_string()).unwrap(),
+        switches
+    );
+
+    let switches = Switches {
+        enabled: false,
+        verbose: true,
+    };
+    assert_eq!(switches.render_string(), "enabled=no verbose=1");
+}
+
+#[test]
+fn every_spelling_parses() {
+    let on = Switches::from_str("enabled=on verbose=1").unwrap();
+    assert_eq!(
+        on,
+        Switches {
+            enabled: true,
+            verbose: true
+        }
+    );
+    let off = Switches::from_str("enabled=off verbose=false").unwrap();
+    assert_eq!(
+        off,
+        Switches {
+            enabled: false,
+            verbose: false
+        }
+    );
+}
+
+#[test]
+fn standard_spelling_is_replaced_when_listed() {
+    assert!(matches!(
+        Switches::from_str("enabled=true verbose=1"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{left}{right}")]
+struct Adjacent {
+    #[templatia(true_values = ["y"], false_values = ["n"])]
+    left: bool,
+    #[templatia(true_values = ["yes"], false_values = ["no"])]
+    right: bool,
+}
+
+#[test]
+fn consecutive_bool_fields_round_trip() {
+    let adjacent = Adjacent {
+        left: false,
+        right: true,
+    };
+    assert_eq!(adjacent.render_string(), "nyes");
+    assert_eq!(Adjacent::from_str("nyes").unwrap(), adjacent);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "flags={flags} maybe={maybe}")]
+struct Collected {
+    #[templatia(true_values = ["on"], false_values = ["off"])]
+    flags: Vec<bool>,
+    #[templatia(true_values = ["on"], false_values = ["off"])]
+    maybe: Option<bool>,
+}
+
+#[test]
+fn collections_and_options_use_the_spellings() {
+    let collected = Collected {
+        flags: vec![true, false],
+        maybe: Some(false),
+    };
+    assert_eq!(collected.render_string(), "flags=on,off maybe=off");
+    assert_eq!(
+        Collected::from_str(&collected.render_string()).unwrap(),
+        collected
+    );
+    assert_eq!(Collected::from_str("flags=on maybe=").unwrap().maybe, None);
+}
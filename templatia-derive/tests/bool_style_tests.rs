@@ -0,0 +1,63 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// bool_style: built-in spellings for rendering bools, with every style accepted on parse.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(bool_style = "on_off")]
+struct Settings {
+    enabled: bool,
+    verbose: Option<bool>,
+    #[templatia(bool_style = "one_zero")]
+    debug: bool,
+    #[templatia(true_values = ["Y"], false_values = ["N"])]
+    legacy: bool,
+    level: u8,
+}
+
+#[test]
+fn styles_control_rendering() {
+    let settings = Settings {
+        enabled: true,
+        verbose: Some(false),
+        debug: true,
+        legacy: false,
+        level: 1,
+    };
+    assert_eq!(
+        settings.render_string(),
+        "enabled = on\nverbose = off\ndebug = 1\nlegacy = N\nlevel = 1"
+    );
+}
+
+#[test]
+fn parsing_accepts_every_style() {
+    let settings =
+        Settings::from_str("enabled = yes\nverbose = false\ndebug = on\nlegacy = Y\nlevel = 0")
+            .unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            enabled: true,
+            verbose: Some(false),
+            debug: true,
+            legacy: true,
+            level: 0,
+        }
+    );
+}
+
+#[test]
+fn unknown_spellings_fail() {
+    assert!(
+        Settings::from_str("enabled = maybe\nverbose = \ndebug = 1\nlegacy = Y\nlevel = 0")
+            .is_err()
+    );
+}
+
+#[test]
+fn explicit_values_take_precedence_over_the_struct_style() {
+    assert!(
+        Settings::from_str("enabled = on\nverbose = \ndebug = 1\nlegacy = yes\nlevel = 0").is_err()
+    );
+}
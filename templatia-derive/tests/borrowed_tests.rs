@@ -0,0 +1,88 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Structs with `&str` fields implement BorrowedTemplate and parse by borrowing from the input.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "user={name};role={role};age={age}")]
+struct View<'a> {
+    name: &'a str,
+    role: Option<&'a str>,
+    age: u8,
+}
+
+#[test]
+fn borrowed_struct_renders() {
+    let view = View {
+        name: "alice",
+        role: Some("admin"),
+        age: 30,
+    };
+    assert_eq!(view.render_string(), "user=alice;role=admin;age=30");
+}
+
+#[test]
+fn borrowed_struct_parses_slices_of_the_input() {
+    let input = String::from("user=bob;role=;age=41");
+    let view = View::from_str(&input).unwrap();
+    assert_eq!(
+        view,
+        View {
+            name: "bob",
+            role: None,
+            age: 41,
+        }
+    );
+    assert!(
+        input
+            .as_bytes()
+            .as_ptr_range()
+            .contains(&view.name.as_ptr())
+    );
+}
+
+#[test]
+fn borrowed_struct_reports_errors() {
+    let err = View::from_str("user=bob;role=;age=old").expect_err("age is not a number");
+    assert!(matches!(
+        err,
+        TemplateError::ParseToType { ref placeholder, .. } if placeholder == "age"
+    ));
+}
+
+#[test]
+fn borrowed_struct_with_multiple_lifetimes_and_duplicates() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{key}={value} ({key})")]
+    struct Pair<'k, 'v> {
+        key: &'k str,
+        value: &'v str,
+    }
+
+    let pair = Pair::from_str("mode=fast (mode)").unwrap();
+    assert_eq!(pair.key, "mode");
+    assert_eq!(pair.value, "fast");
+    assert!(matches!(
+        Pair::from_str("mode=fast (other)"),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+}
+
+#[test]
+fn lifetime_struct_without_borrowed_fields_implements_template() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{count}", allow_missing_placeholders)]
+    struct Counter<'a> {
+        count: u32,
+        label: std::marker::PhantomData<&'a str>,
+    }
+
+    fn parse<T: Template>(s: &str) -> Option<T> {
+        T::from_str(s).ok()
+    }
+
+    let counter: Counter<'static> = parse("5").unwrap();
+    assert_eq!(counter.count, 5);
+    assert_eq!(counter.label, std::marker::PhantomData);
+}
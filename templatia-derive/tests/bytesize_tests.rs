@@ -0,0 +1,114 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(bytesize)]` renders and parses u64 fields as byte sizes like 512KiB or 2MB.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "memory={memory}; disk={disk}")]
+struct Limits {
+    #[templatia(bytesize)]
+    memory: u64,
+    #[templatia(bytesize)]
+    disk: u64,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "swap={swap}; tiers={tiers}")]
+struct Tiers {
+    #[templatia(bytesize)]
+    swap: Option<u64>,
+    #[templatia(bytesize, separator = " ")]
+    tiers: Vec<u64>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "max={max}")]
+struct Bounded {
+    #[templatia(bytesize, max = 1048576)]
+    max: u64,
+}
+
+fn limits(memory: u64, disk: u64) -> Limits {
+    Limits { memory, disk }
+}
+
+#[test]
+fn renders_with_largest_exact_unit() {
+    assert_eq!(
+        limits(512 * 1024, 2_000_000).render_string(),
+        "memory=512KiB; disk=2MB"
+    );
+    assert_eq!(
+        limits(1024 * 1000, 3 << 30).render_string(),
+        "memory=1000KiB; disk=3GiB"
+    );
+    assert_eq!(limits(100, 0).render_string(), "memory=100B; disk=0B");
+}
+
+#[test]
+fn parses_decimal_and_binary_units() {
+    assert_eq!(
+        Limits::from_str("memory=512KiB; disk=2MB").unwrap(),
+        limits(524_288, 2_000_000)
+    );
+    assert_eq!(
+        Limits::from_str("memory=1 gib; disk=4tb").unwrap(),
+        limits(1 << 30, 4_000_000_000_000)
+    );
+    assert_eq!(
+        Limits::from_str("memory=100; disk=7B").unwrap(),
+        limits(100, 7)
+    );
+}
+
+#[test]
+fn parses_fractions_of_whole_bytes() {
+    assert_eq!(
+        Limits::from_str("memory=1.5GiB; disk=0.5KB").unwrap(),
+        limits(1_610_612_736, 500)
+    );
+}
+
+#[test]
+fn rejects_invalid_sizes() {
+    for input in [
+        "memory=12XB; disk=1B",
+        "memory=1.0001KB; disk=1B",
+        "memory=20EiB; disk=1B",
+        "memory=KiB; disk=1B",
+    ] {
+        let err = Limits::from_str(input).unwrap_err();
+        assert!(
+            matches!(err, TemplateError::ParseToType { ref placeholder, .. } if placeholder == "memory"),
+            "{}: {:?}",
+            input,
+            err
+        );
+    }
+}
+
+#[test]
+fn option_and_collection_elements_round_trip() {
+    let tiers = Tiers {
+        swap: Some(8 << 30),
+        tiers: vec![64 << 20, 1_000, 5],
+    };
+    let rendered = tiers.render_string();
+    assert_eq!(rendered, "swap=8GiB; tiers=64MiB 1KB 5B");
+    assert_eq!(Tiers::from_str(&rendered).unwrap(), tiers);
+    assert_eq!(
+        Tiers::from_str("swap=; tiers=").unwrap(),
+        Tiers {
+            swap: None,
+            tiers: vec![]
+        }
+    );
+}
+
+#[test]
+fn range_applies_to_the_parsed_bytes() {
+    assert_eq!(Bounded::from_str("max=1MiB").unwrap().max, 1 << 20);
+    let err = Bounded::from_str("max=2MiB").unwrap_err();
+    assert!(matches!(err, TemplateError::OutOfRange { .. }), "{:?}", err);
+}
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(uppercase)]` / `#[templatia(lowercase)]` normalize the rendered case and parse either case.
+
+#[derive(Debug, PartialEq)]
+enum Level {
+    Info,
+    Warn,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Info => write!(f, "info"),
+            Level::Warn => write!(f, "warn"),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Level::Info),
+            "warn" => Ok(Level::Warn),
+            _ => Err(format!("unknown level {}", s)),
+        }
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{level}] {message}")]
+struct LogLine {
+    #[templatia(uppercase)]
+    level: Level,
+    message: String,
+}
+
+#[test]
+fn uppercase_renders_upper_case() {
+    let line = LogLine {
+        level: Level::Warn,
+        message: "disk full".to_string(),
+    };
+    assert_eq!(line.render_string(), "[WARN] disk full");
+}
+
+#[test]
+fn uppercase_parses_either_case() {
+    let upper = LogLine::from_str("[INFO] started").unwrap();
+    assert_eq!(upper.level, Level::Info);
+
+    let lower = LogLine::from_str("[info] started").unwrap();
+    assert_eq!(lower.level, Level::Info);
+}
+
+#[test]
+fn uppercase_round_trip() {
+    let line = LogLine {
+        level: Level::Info,
+        message: "ok".to_string(),
+    };
+    assert_eq!(LogLine::from_str(&line.render_string()).unwrap(), line);
+}
+
+#[test]
+fn unknown_value_is_still_an_error() {
+    assert!(LogLine::from_str("[DEBUG] started").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "env={env};tags={tags}")]
+struct Deploy {
+    #[templatia(lowercase)]
+    env: Option<String>,
+    #[templatia(lowercase)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn lowercase_applies_to_option_and_collection_values() {
+    let deploy = Deploy {
+        env: Some("PROD".to_string()),
+        tags: vec!["Web".to_string(), "API".to_string()],
+    };
+    assert_eq!(deploy.render_string(), "env=prod;tags=web,api");
+}
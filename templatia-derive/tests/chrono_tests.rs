@@ -0,0 +1,125 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// With the chrono feature, DateTime fields are supported and datetime_format controls both directions.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{at}] {message}")]
+struct LogLine {
+    #[templatia(datetime_format = "%d/%m/%Y %H:%M:%S")]
+    at: NaiveDateTime,
+    message: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "since={since}; at={at}")]
+struct Defaults {
+    since: NaiveDate,
+    at: DateTime<Utc>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "ts={ts}")]
+struct Stamp {
+    #[templatia(datetime_format = "%Y%m%dT%H%M%S")]
+    ts: DateTime<Utc>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "ts={ts}")]
+struct Offset {
+    #[templatia(datetime_format = "%Y-%m-%d %H:%M %z")]
+    ts: DateTime<FixedOffset>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "due={due}; slots={slots}")]
+struct Schedule {
+    #[templatia(datetime_format = "%b %e %Y")]
+    due: Option<NaiveDate>,
+    #[templatia(datetime_format = "%H.%M", separator = " ")]
+    slots: Vec<NaiveTime>,
+}
+
+fn datetime(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, s)
+        .unwrap()
+}
+
+#[test]
+fn datetime_format_renders_and_parses_naive_datetime() {
+    let line = LogLine {
+        at: datetime(2026, 10, 18, 9, 30, 5),
+        message: "started".into(),
+    };
+    assert_eq!(line.render_string(), "[18/10/2026 09:30:05] started");
+    assert_eq!(
+        LogLine::from_str("[18/10/2026 09:30:05] started").unwrap(),
+        line
+    );
+}
+
+#[test]
+fn chrono_types_use_display_and_from_str_without_format() {
+    let defaults = Defaults {
+        since: NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        at: Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap(),
+    };
+    let rendered = defaults.render_string();
+    assert_eq!(rendered, "since=2024-02-29; at=2026-01-02 03:04:05 UTC");
+    assert_eq!(Defaults::from_str(&rendered).unwrap(), defaults);
+}
+
+#[test]
+fn utc_format_without_offset_reads_as_utc() {
+    let stamp = Stamp {
+        ts: Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap(),
+    };
+    assert_eq!(stamp.render_string(), "ts=20261018T120000");
+    assert_eq!(Stamp::from_str("ts=20261018T120000").unwrap(), stamp);
+}
+
+#[test]
+fn fixed_offset_round_trips() {
+    let offset = FixedOffset::east_opt(9 * 3600).unwrap();
+    let value = Offset {
+        ts: offset.with_ymd_and_hms(2026, 10, 18, 21, 15, 0).unwrap(),
+    };
+    assert_eq!(value.render_string(), "ts=2026-10-18 21:15 +0900");
+    assert_eq!(
+        Offset::from_str("ts=2026-10-18 21:15 +0900").unwrap(),
+        value
+    );
+}
+
+#[test]
+fn option_and_collection_elements_use_the_format() {
+    let schedule = Schedule {
+        due: NaiveDate::from_ymd_opt(2026, 3, 7),
+        slots: vec![
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(13, 30, 0).unwrap(),
+        ],
+    };
+    let rendered = schedule.render_string();
+    assert_eq!(rendered, "due=Mar  7 2026; slots=09.00 13.30");
+    assert_eq!(Schedule::from_str(&rendered).unwrap(), schedule);
+
+    let empty = Schedule::from_str("due=; slots=").unwrap();
+    assert_eq!(empty.due, None);
+    assert!(empty.slots.is_empty());
+}
+
+#[test]
+fn value_not_matching_the_format_is_a_type_error() {
+    let err = LogLine::from_str("[2026-10-18 09:30:05] started").unwrap_err();
+    assert!(
+        matches!(err, TemplateError::ParseToType { .. }),
+        "{:?}",
+        err
+    );
+}
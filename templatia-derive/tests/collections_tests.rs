@@ -1,5 +1,5 @@
 use std::collections::{BTreeSet, HashSet};
-use templatia::Template;
+use templatia::prelude::*;
 
 // Tests follow AGENTS.md policy. They express intended behavior derived from current docs and
 // observable patterns in existing tests. For collections, values are represented as
@@ -180,3 +180,28 @@ fn collections_not_in_template_default_when_allowed() {
     assert!(s.tags.is_empty());
     assert!(s.ord.is_empty());
 }
+
+// ---------------------- Single empty element ----------------------
+
+#[test]
+fn single_empty_element_parses_back_as_empty_collection() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "items={items};slots={slots};tags={tags}")]
+    struct S {
+        items: Vec<String>,
+        slots: Vec<Option<u32>>,
+        tags: BTreeSet<String>,
+    }
+
+    let value = S {
+        items: vec![String::new()],
+        slots: vec![None],
+        tags: BTreeSet::from([String::new()]),
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "items=;slots=;tags=");
+    let parsed = S::from_str(&rendered).expect("empty segments parse");
+    assert!(parsed.items.is_empty());
+    assert!(parsed.slots.is_empty());
+    assert!(parsed.tags.is_empty());
+}
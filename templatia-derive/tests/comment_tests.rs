@@ -0,0 +1,60 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// comment: comment lines and blank lines of the input are skipped when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(comment = "#")]
+struct Config {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn comments_and_blank_lines_are_skipped() {
+    let input =
+        "# server settings\n\nhost = localhost\n   # the port to listen on\nport = 8080\n\n";
+    assert_eq!(
+        Config::from_str(input).unwrap(),
+        Config {
+            host: "localhost".into(),
+            port: 8080,
+        }
+    );
+}
+
+#[test]
+fn marker_inside_a_value_is_kept() {
+    assert_eq!(
+        Config::from_str("host = a#b\nport = 1").unwrap(),
+        Config {
+            host: "a#b".into(),
+            port: 1,
+        }
+    );
+}
+
+#[test]
+fn rendering_is_unchanged() {
+    let config = Config {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(config.render_string(), "host = localhost\nport = 8080");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name: {name}\n", comment = "//", ignore_unknown_lines)]
+struct Combined {
+    name: String,
+}
+
+#[test]
+fn comments_combine_with_unknown_lines() {
+    assert_eq!(
+        Combined::from_str("// header\nversion: 2\nname: demo\n").unwrap(),
+        Combined {
+            name: "demo".into()
+        }
+    );
+}
@@ -0,0 +1,11 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{host}:{port}")]
+struct Server {
+    #[templatia(alias = "hostname")]
+    host: String,
+    port: u16,
+}
+
+fn main() {}
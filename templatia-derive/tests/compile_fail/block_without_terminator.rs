@@ -0,0 +1,14 @@
+use templatia::prelude::*;
+
+struct Entry {
+    key: String,
+    value: u32,
+}
+
+#[derive(Template)]
+#[templatia(template = "{#entries}{key}={value}{/entries}")]
+struct Entries {
+    entries: Vec<Entry>,
+}
+
+fn main() {}
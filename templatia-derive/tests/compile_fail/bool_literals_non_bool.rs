@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "level={level}")]
+struct Level {
+    #[templatia(true_values = ["yes"])]
+    level: u8,
+}
+
+fn main() {}
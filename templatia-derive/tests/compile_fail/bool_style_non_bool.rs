@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{a}")]
+struct Value {
+    #[templatia(bool_style = "yes_no")]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "size={size}")]
+struct Size {
+    #[templatia(bytesize)]
+    size: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(comment = " ")]
+struct Config {
+    host: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "name={name:rest,width=4}")]
+struct Conflict {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use chrono::NaiveDate;
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "day={day}")]
+struct Day {
+    #[templatia(datetime_format = "%Y-%m-%d", format = "{:>12}")]
+    day: NaiveDate,
+}
+
+fn main() {}
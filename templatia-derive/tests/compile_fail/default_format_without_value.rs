@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(default_format = "{key}")]
+struct Server {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "name={name}")]
+struct Named {
+    #[templatia(empty_str_is_none)]
+    name: String,
+}
+
+fn main() {}
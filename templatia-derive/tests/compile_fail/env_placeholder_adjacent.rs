@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{$HOME}{name}")]
+struct Home {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{*extras}\nhost = {host}")]
+struct Config {
+    host: String,
+    extras: HashMap<String, String>,
+}
+
+fn main() {}
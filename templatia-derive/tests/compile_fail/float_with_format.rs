@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{a}")]
+struct Value {
+    #[templatia(float = "shortest", format = "{:.3}")]
+    a: f64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{id}")]
+struct Item {
+    #[templatia(format = "{}-{}")]
+    id: u32,
+}
+
+fn main() {}
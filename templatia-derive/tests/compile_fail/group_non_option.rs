@@ -0,0 +1,11 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{host}[:{port}-{id}]")]
+struct Endpoint {
+    host: String,
+    port: Option<u16>,
+    id: u8,
+}
+
+fn main() {}
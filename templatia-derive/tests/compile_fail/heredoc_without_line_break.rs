@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{body<<END}{count}")]
+struct Message {
+    body: String,
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "user={name}", impl_from_str)]
+struct Borrowed<'a> {
+    name: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "tags={tags=a,b}")]
+struct Tagged {
+    tags: Vec<String>,
+}
+
+fn main() {}
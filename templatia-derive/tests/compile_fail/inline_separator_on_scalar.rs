@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = r#"port={port*", "}"#)]
+struct Server {
+    port: u16,
+}
+
+fn main() {}
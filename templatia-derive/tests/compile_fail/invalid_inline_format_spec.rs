@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "price={price:.x2}")]
+struct Item {
+    price: f64,
+}
+
+fn main() {}
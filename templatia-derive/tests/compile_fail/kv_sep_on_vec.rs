@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "tags={tags}")]
+struct Tags {
+    #[templatia(kv_sep = ":")]
+    tags: Vec<String>,
+}
+
+fn main() {}
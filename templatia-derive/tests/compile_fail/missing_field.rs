@@ -1,4 +1,4 @@
-use templatia::Template;
+use templatia::prelude::*;
 
 #[derive(Template)]
 #[templatia(template = "host={host}, port={port}, user={username}")]
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{first}{?!-}{second}")]
+struct Pair {
+    first: String,
+    second: String,
+}
+
+fn main() {}
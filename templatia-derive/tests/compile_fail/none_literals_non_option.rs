@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{a}")]
+struct Value {
+    #[templatia(none_literals = ["null"])]
+    a: u32,
+}
+
+fn main() {}
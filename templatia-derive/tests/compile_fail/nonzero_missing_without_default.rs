@@ -0,0 +1,11 @@
+use std::num::NonZeroU16;
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "host={host}", allow_missing_placeholders)]
+struct Server {
+    host: String,
+    port: NonZeroU16,
+}
+
+fn main() {}
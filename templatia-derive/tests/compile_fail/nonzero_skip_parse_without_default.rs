@@ -0,0 +1,11 @@
+use std::num::NonZeroU8;
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "workers={workers}")]
+struct Pool {
+    #[templatia(skip_parse)]
+    workers: NonZeroU8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{0}:{2}")]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "name={name}")]
+struct Name {
+    #[templatia(max = 10)]
+    name: String,
+}
+
+fn main() {}
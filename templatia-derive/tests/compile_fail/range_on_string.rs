@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "name={name:1..=8}")]
+struct User {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "port={port}", rename_all = "kebab-case")]
+struct Server {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{database_url}")]
+struct Settings {
+    #[templatia(rename = "db")]
+    database_url: String,
+}
+
+fn main() {}
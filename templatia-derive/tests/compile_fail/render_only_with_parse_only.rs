@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{host}:{port}", render_only, parse_only)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{body}\nend")]
+struct Document {
+    #[templatia(rest)]
+    body: String,
+}
+
+fn main() {}
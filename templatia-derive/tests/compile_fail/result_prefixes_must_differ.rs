@@ -1,7 +1,10 @@
-use templatia::Template;
+use templatia::prelude::*;
 
 #[derive(Template)]
 #[templatia(template = "res={res}")]
 struct HasResult {
+    #[templatia(ok_prefix = "=", err_prefix = "=")]
     res: Result<i32, String>,
 }
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "items={items}")]
+struct Items {
+    #[templatia(separator = "")]
+    items: Vec<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "value={value}")]
+struct Value {
+    #[templatia(skip_render, skip_parse)]
+    value: u32,
+}
+
+fn main() {}
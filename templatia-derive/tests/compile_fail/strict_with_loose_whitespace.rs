@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "a={a}", strict, loose_whitespace)]
+struct Frame {
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template_file = "tests/templates/does_not_exist.tpl")]
+struct Missing {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "port={port:u32}")]
+struct Server {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{% raw %}{\"id\": {id}")]
+struct Payload {
+    id: u32,
+}
+
+fn main() {}
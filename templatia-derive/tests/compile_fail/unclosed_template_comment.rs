@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "{#- a note about {value}")]
+struct Note {
+    value: String,
+}
+
+fn main() {}
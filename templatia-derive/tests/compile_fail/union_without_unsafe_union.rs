@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template, Clone, Copy)]
+#[templatia(template = "{bits}", active = "bits")]
+union Word {
+    bits: u32,
+    bytes: [u8; 4],
+}
+
+fn main() {}
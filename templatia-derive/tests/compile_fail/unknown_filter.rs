@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "name={name|capitalize}")]
+struct Person {
+    name: String,
+}
+
+fn main() {}
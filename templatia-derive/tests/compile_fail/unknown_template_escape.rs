@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = r"{key}\q{value}", unescape)]
+struct Row {
+    key: String,
+    value: String,
+}
+
+fn main() {}
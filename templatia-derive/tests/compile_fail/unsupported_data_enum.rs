@@ -0,0 +1,9 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+enum Shape {
+    Circle(f64),
+    Square { side: f64 },
+}
+
+fn main() {}
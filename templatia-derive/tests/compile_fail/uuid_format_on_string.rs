@@ -0,0 +1,10 @@
+use templatia::prelude::*;
+
+#[derive(Template)]
+#[templatia(template = "id={id}")]
+struct Id {
+    #[templatia(uuid_format = "simple")]
+    id: String,
+}
+
+fn main() {}
@@ -1,4 +1,5 @@
-use templatia::{Template, TemplateError};
+use templatia::TemplateError;
+use templatia::prelude::*;
 // Tests follow AGENTS.md policy. They express intended behavior from docs.
 
 /// Tests for default template behavior and edge cases
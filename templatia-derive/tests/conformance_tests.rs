@@ -0,0 +1,129 @@
+use templatia::TemplateError;
+use templatia::conformance::{
+    Arbitrary, ArbitraryResult, ConformanceCheck, ConformanceConfig, Unstructured, check,
+};
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// The conformance runner reports round-trip, re-render, duplicate, and error classification issues.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "id={id};enabled={enabled};id_again={id}")]
+struct Flags {
+    id: u32,
+    enabled: bool,
+}
+
+impl<'a> Arbitrary<'a> for Flags {
+    fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+        Ok(Flags {
+            id: u.arbitrary()?,
+            enabled: u.arbitrary()?,
+        })
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name};port={port}")]
+struct Service {
+    name: String,
+    port: u16,
+}
+
+impl<'a> Arbitrary<'a> for Service {
+    fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+        // Deliberately allows the separator inside the name to produce non-round-trippable values.
+        let name = if u.arbitrary::<bool>()? {
+            "a;port=1".to_string()
+        } else {
+            "svc".to_string()
+        };
+        Ok(Service {
+            name,
+            port: u.arbitrary()?,
+        })
+    }
+}
+
+#[test]
+fn conformant_type_passes_all_checks() {
+    let report = check::<Flags>();
+    assert_eq!(report.cases, 256);
+    report.assert_success();
+}
+
+#[test]
+fn config_controls_case_count() {
+    let report = ConformanceConfig::new()
+        .with_cases(10)
+        .with_seed(7)
+        .run::<Flags>();
+    assert_eq!(report.cases, 10);
+    assert!(report.is_success());
+}
+
+#[test]
+fn non_round_trippable_values_are_reported() {
+    let report = ConformanceConfig::new()
+        .with_cases(32)
+        .with_max_failures(4)
+        .run::<Service>();
+    assert!(!report.is_success());
+    assert!(report.failures.len() <= 4);
+    assert!(
+        report
+            .failures
+            .iter()
+            .any(|failure| failure.check == ConformanceCheck::RoundTrip)
+    );
+    assert!(report.to_string().contains("round-trip"));
+}
+
+#[test]
+fn truncated_input_is_an_error_not_a_panic() {
+    assert!(Flags::from_str("").is_err());
+    assert!(Flags::from_str("d=5;enabled=true;id_again=5").is_err());
+}
+
+/// Reports a doubled separator through the catch-all `Parse` error.
+#[derive(Debug, PartialEq)]
+struct Pair(u8, u8);
+
+impl RenderTemplate for Pair {
+    fn render_string(&self) -> String {
+        format!("{},{}", self.0, self.1)
+    }
+}
+
+impl ParseTemplate for Pair {
+    type Error = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Error> {
+        if s.contains(",,") {
+            return Err(TemplateError::Parse("doubled separator".to_string()));
+        }
+        let invalid = || TemplateError::UnexpectedInput {
+            expected_next_literal: ",".to_string(),
+            remaining_text: s.to_string(),
+        };
+        let (first, second) = s.split_once(',').ok_or_else(invalid)?;
+        Ok(Pair(
+            first.parse().map_err(|_| invalid())?,
+            second.parse().map_err(|_| invalid())?,
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Pair {
+    fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+        Ok(Pair(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+#[test]
+fn mutations_at_literal_boundaries_are_classified() {
+    let report = ConformanceConfig::new().with_cases(4).run::<Pair>();
+    assert!(report.failures.iter().any(|failure| {
+        failure.check == ConformanceCheck::ErrorClassification && failure.input.contains(",,")
+    }));
+}
@@ -0,0 +1,59 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(crate = "...")]` redirects the generated paths to a re-exported templatia.
+
+mod facade {
+    pub use ::templatia as engine;
+}
+
+use facade::engine::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(crate = "crate::facade::engine", template = "{host}:{port}")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(crate = "crate::facade::engine", impl_display, impl_from_str)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    crate = "crate::facade::engine",
+    template = "server={>server} mode={mode}"
+)]
+struct Deployment {
+    server: Server,
+    mode: Mode,
+}
+
+#[test]
+fn re_exported_crate_path_round_trips() {
+    let deployment = Deployment {
+        server: Server {
+            host: "localhost".to_string(),
+            port: 8080,
+        },
+        mode: Mode::Fast,
+    };
+    assert_eq!(
+        deployment.render_string(),
+        "server=localhost:8080 mode=Fast"
+    );
+    assert_eq!(
+        Deployment::from_str(&deployment.render_string()).unwrap(),
+        deployment
+    );
+}
+
+#[test]
+fn re_exported_crate_path_reports_errors() {
+    assert!(matches!(
+        Server::from_str("localhost:http"),
+        Err(facade::engine::TemplateError::ParseToType { .. })
+    ));
+}
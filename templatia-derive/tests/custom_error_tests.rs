@@ -0,0 +1,80 @@
+use std::fmt;
+use std::str::FromStr;
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(error = "...")]` uses a custom error type converted from `TemplateError`.
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    Template(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Template(message) => write!(f, "invalid config: {}", message),
+        }
+    }
+}
+
+impl From<TemplateError> for ConfigError {
+    fn from(error: TemplateError) -> Self {
+        ConfigError::Template(error.to_string())
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{host}:{port}", error = "ConfigError")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn custom_error_type_is_returned() {
+    let server = Server::from_str("localhost:8080").unwrap();
+    assert_eq!(server.port, 8080);
+
+    let error: ConfigError = Server::from_str("localhost:http").unwrap_err();
+    let ConfigError::Template(message) = error;
+    assert!(message.contains("port"));
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(error = "ConfigError", impl_display, impl_from_str)]
+enum Mode {
+    Fast,
+    Slow,
+}
+
+#[test]
+fn unit_enum_uses_custom_error_type() {
+    assert_eq!(
+        <Mode as ParseTemplate>::from_str("Fast").unwrap(),
+        Mode::Fast
+    );
+    assert!(matches!(
+        <Mode as FromStr>::from_str("Medium"),
+        Err(ConfigError::Template(_))
+    ));
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "server={>server} mode={mode}")]
+struct Deployment {
+    server: Server,
+    mode: Mode,
+}
+
+#[test]
+fn custom_error_types_can_be_nested() {
+    let deployment = Deployment::from_str("server=example.com:443 mode=Slow").unwrap();
+    assert_eq!(deployment.server.host, "example.com");
+    assert_eq!(deployment.mode, Mode::Slow);
+    assert!(matches!(
+        Deployment::from_str("server=example.com:x mode=Slow"),
+        Err(TemplateError::Include { .. })
+    ));
+}
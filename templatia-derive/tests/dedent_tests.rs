@@ -0,0 +1,76 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// dedent: the common indentation of multi-line templates is stripped at macro time.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "
+        [server]
+        host = {host}
+          port = {port}
+        ",
+    dedent
+)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "
+        name: {name}
+
+        age: {age}",
+    alt_template = "
+        {name}
+        {age}",
+    dedent
+)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+#[test]
+fn indentation_is_stripped_on_render() {
+    let server = Server {
+        host: "localhost".into(),
+        port: 80,
+    };
+    assert_eq!(
+        server.render_string(),
+        "[server]\nhost = localhost\n  port = 80\n"
+    );
+}
+
+#[test]
+fn dedented_template_round_trips() {
+    let server = Server {
+        host: "localhost".into(),
+        port: 80,
+    };
+    assert_eq!(Server::from_str(&server.render_string()).unwrap(), server);
+}
+
+#[test]
+fn blank_lines_are_kept_empty() {
+    let person = Person {
+        name: "alice".into(),
+        age: 30,
+    };
+    assert_eq!(person.render_string(), "name: alice\n\nage: 30");
+}
+
+#[test]
+fn alt_templates_are_dedented() {
+    let parsed = Person::from_str("bob\n42").unwrap();
+    assert_eq!(
+        parsed,
+        Person {
+            name: "bob".into(),
+            age: 42
+        }
+    );
+}
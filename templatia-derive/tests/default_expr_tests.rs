@@ -0,0 +1,75 @@
+use std::time::Duration;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(default = "...")]` builds a field from an expression when it is missing or empty.
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Timeout(Duration);
+
+impl std::fmt::Display for Timeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.as_secs())
+    }
+}
+
+impl std::str::FromStr for Timeout {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(|secs| Timeout(Duration::from_secs(secs)))
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host={host};timeout={timeout}")]
+struct Client {
+    host: String,
+    #[templatia(default = "Timeout(Duration::from_secs(30))")]
+    timeout: Timeout,
+    #[templatia(default = "default_retries()")]
+    retries: u8,
+    #[templatia(default = "Some(\"eu\".to_string())")]
+    region: Option<String>,
+}
+
+#[test]
+fn missing_fields_use_default_expressions() {
+    let client = Client::from_str("host=example.com;timeout=5").unwrap();
+    assert_eq!(client.timeout, Timeout(Duration::from_secs(5)));
+    assert_eq!(client.retries, 3);
+    assert_eq!(client.region.as_deref(), Some("eu"));
+}
+
+#[test]
+fn empty_capture_uses_default_expression() {
+    let client = Client::from_str("host=example.com;timeout=").unwrap();
+    assert_eq!(client.timeout, Timeout(Duration::from_secs(30)));
+}
+
+#[test]
+fn empty_capture_before_literal_uses_default_expression() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port};host={host}")]
+    struct Server {
+        #[templatia(default = "8080")]
+        port: u16,
+        host: String,
+    }
+
+    let server = Server::from_str("port=;host=localhost").unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(Server::from_str("port=1;host=a").unwrap().port, 1);
+}
+
+#[test]
+fn invalid_values_are_still_errors() {
+    assert!(matches!(
+        Client::from_str("host=example.com;timeout=soon"),
+        Err(templatia::TemplateError::ParseToType { ref placeholder, .. }) if placeholder == "timeout"
+    ));
+}
@@ -0,0 +1,63 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `default_format` and `default_separator` change the layout of the default template.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(default_format = "{key}: {value}", default_separator = "; ")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn default_layout_is_customized() {
+    let server = Server {
+        host: "localhost".to_string(),
+        port: 8080,
+    };
+    assert_eq!(server.render_string(), "host: localhost; port: 8080");
+    assert_eq!(
+        Server::from_str("host: example.com; port: 443")
+            .unwrap()
+            .port,
+        443
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    default_format = "--{key}={value}",
+    default_separator = " ",
+    rename_all = "kebab-case"
+)]
+struct Args {
+    max_jobs: u32,
+    dry_run: bool,
+}
+
+#[test]
+fn default_layout_combines_with_rename_all() {
+    let args = Args {
+        max_jobs: 4,
+        dry_run: true,
+    };
+    assert_eq!(args.render_string(), "--max-jobs=4 --dry-run=true");
+    assert_eq!(Args::from_str(&args.render_string()).unwrap(), args);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(default_format = "[{value}]")]
+struct Values {
+    first: u32,
+    second: u32,
+}
+
+#[test]
+fn key_is_optional_in_default_format() {
+    let values = Values {
+        first: 1,
+        second: 2,
+    };
+    assert_eq!(values.render_string(), "[1]\n[2]");
+}
@@ -1,4 +1,4 @@
-use templatia::Template;
+use templatia::prelude::*;
 // Tests follow AGENTS.md policy. They express intended behavior from docs.
 
 #[test]
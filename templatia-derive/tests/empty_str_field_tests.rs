@@ -0,0 +1,54 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(empty_str_is_none)]` / `#[templatia(empty_str_is_some)]` override the empty string policy per field.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "a={a};b={b};c={c}")]
+struct DefaultPolicy {
+    a: Option<String>,
+    #[templatia(empty_str_is_some)]
+    b: Option<String>,
+    #[templatia(empty_str_is_none)]
+    c: Option<String>,
+}
+
+#[test]
+fn field_overrides_default_policy() {
+    let parsed = DefaultPolicy::from_str("a=;b=;c=").unwrap();
+    assert_eq!(
+        parsed,
+        DefaultPolicy {
+            a: None,
+            b: Some(String::new()),
+            c: None,
+        }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "a={a};b={b}", empty_str_option_not_none)]
+struct SomePolicy {
+    a: Option<String>,
+    #[templatia(empty_str_is_none)]
+    b: Option<String>,
+}
+
+#[test]
+fn field_overrides_struct_policy() {
+    let parsed = SomePolicy::from_str("a=;b=").unwrap();
+    assert_eq!(
+        parsed,
+        SomePolicy {
+            a: Some(String::new()),
+            b: None,
+        }
+    );
+}
+
+#[test]
+fn non_empty_values_are_unaffected() {
+    let parsed = SomePolicy::from_str("a=x;b=y").unwrap();
+    assert_eq!(parsed.a.as_deref(), Some("x"));
+    assert_eq!(parsed.b.as_deref(), Some("y"));
+}
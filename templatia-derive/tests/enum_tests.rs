@@ -0,0 +1,113 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Fieldless enums render as their variant name and can be used as placeholder field types.
+
+#[derive(Template, Debug, PartialEq, Clone, Copy)]
+#[templatia(impl_display, impl_from_str)]
+enum Level {
+    Debug,
+    Info,
+    Warn,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(rename_all = "kebab-case", impl_display, impl_from_str)]
+enum Mode {
+    ReadOnly,
+    ReadWrite,
+}
+
+#[test]
+fn unit_enum_renders_variant_name() {
+    assert_eq!(Level::Debug.render_string(), "Debug");
+    assert_eq!(Level::Warn.render_string(), "Warn");
+}
+
+#[test]
+fn unit_enum_parses_variant_name() {
+    assert_eq!(Level::from_str("Info").unwrap(), Level::Info);
+}
+
+#[test]
+fn unit_enum_unknown_variant_is_error() {
+    let err = Level::from_str("Trace").expect_err("unknown variant should fail");
+    match err {
+        TemplateError::Parse(msg) => {
+            assert!(msg.contains("Trace"));
+            assert!(msg.contains("Debug, Info, Warn"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn unit_enum_rename_all_applies_to_render_and_parse() {
+    assert_eq!(Mode::ReadOnly.render_string(), "read-only");
+    assert_eq!(Mode::from_str("read-write").unwrap(), Mode::ReadWrite);
+    assert!(Mode::from_str("ReadWrite").is_err());
+}
+
+#[test]
+fn unit_enum_implements_display_and_from_str() {
+    assert_eq!(format!("{}", Mode::ReadWrite), "read-write");
+    assert_eq!("Warn".parse::<Level>().unwrap(), Level::Warn);
+}
+
+#[test]
+fn unit_enum_as_placeholder_field() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "[{level}] {message}")]
+    struct LogLine {
+        level: Level,
+        message: String,
+    }
+
+    let line = LogLine {
+        level: Level::Warn,
+        message: "disk almost full".into(),
+    };
+    let rendered = line.render_string();
+    assert_eq!(rendered, "[Warn] disk almost full");
+    assert_eq!(LogLine::from_str(&rendered).unwrap(), line);
+
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "level={level}")]
+    struct LevelOnly {
+        level: Level,
+    }
+
+    let err = LevelOnly::from_str("level=Fatal").expect_err("unknown level");
+    match err {
+        TemplateError::ParseToType {
+            placeholder,
+            value,
+            type_name,
+        } => {
+            assert_eq!(placeholder, "level");
+            assert_eq!(value, "Fatal");
+            assert_eq!(type_name, "Level");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn unit_enum_in_option_and_collection_fields() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "mode={mode};levels={levels}")]
+    struct Settings {
+        mode: Option<Mode>,
+        levels: Vec<Level>,
+    }
+
+    let parsed = Settings::from_str("mode=read-only;levels=Debug,Warn").unwrap();
+    assert_eq!(parsed.mode, Some(Mode::ReadOnly));
+    assert_eq!(parsed.levels, vec![Level::Debug, Level::Warn]);
+
+    let empty = Settings::from_str("mode=;levels=").unwrap();
+    assert_eq!(empty.mode, None);
+    assert!(empty.levels.is_empty());
+    assert_eq!(empty.render_string(), "mode=;levels=");
+}
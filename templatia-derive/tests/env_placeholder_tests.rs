@@ -0,0 +1,59 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {$NAME}: environment variables substituted on render and skipped on parse.
+
+use templatia::prelude::*;
+
+const VARIABLE: &str = "TEMPLATIA_ENV_PLACEHOLDER_TEST_DIR";
+
+fn set_variable() {
+    // SAFETY: Every test sets the same value, so concurrent tests never observe another one.
+    unsafe { std::env::set_var(VARIABLE, "/srv/app") };
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "root={$TEMPLATIA_ENV_PLACEHOLDER_TEST_DIR}/{name}\nport={port}")]
+struct Service {
+    name: String,
+    port: u16,
+}
+
+#[test]
+fn render_reads_the_environment() {
+    set_variable();
+    let service = Service {
+        name: "api".to_string(),
+        port: 8080,
+    };
+    assert_eq!(service.render_string(), "root=/srv/app/api\nport=8080");
+}
+
+#[test]
+fn parse_skips_the_environment_text() {
+    set_variable();
+    let expected = Service {
+        name: "api".to_string(),
+        port: 8080,
+    };
+    assert_eq!(
+        Service::from_str("root=/srv/app/api\nport=8080").unwrap(),
+        expected
+    );
+    // Text other than the current value is skipped up to the next literal.
+    assert_eq!(
+        Service::from_str("root=elsewhere/api\nport=8080").unwrap(),
+        expected
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "user={$TEMPLATIA_ENV_PLACEHOLDER_TEST_UNSET};id={id}")]
+struct Session {
+    id: u32,
+}
+
+#[test]
+fn unset_variable_renders_empty() {
+    let session = Session { id: 7 };
+    assert_eq!(session.render_string(), "user=;id=7");
+    assert_eq!(Session::from_str("user=;id=7").unwrap(), session);
+}
@@ -0,0 +1,65 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(escape = '\\')]` escapes the following literal in values so they round-trip.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name},{city},{age}")]
+struct Row {
+    #[templatia(escape = '\\')]
+    name: String,
+    #[templatia(escape = '\\')]
+    city: Option<String>,
+    age: u32,
+}
+
+#[test]
+fn render_escapes_following_literal_and_escape_char() {
+    let row = Row {
+        name: "Doe, John".to_string(),
+        city: Some("C:\\Temp,Dir".to_string()),
+        age: 42,
+    };
+    assert_eq!(row.render_string(), "Doe\\, John,C:\\\\Temp\\,Dir,42");
+}
+
+#[test]
+fn parse_unescapes_values() {
+    let row = Row::from_str("Doe\\, John,Paris,7").unwrap();
+    assert_eq!(
+        row,
+        Row {
+            name: "Doe, John".to_string(),
+            city: Some("Paris".to_string()),
+            age: 7,
+        }
+    );
+}
+
+#[test]
+fn escaped_values_round_trip() {
+    let row = Row {
+        name: "a,b,,c\\".to_string(),
+        city: None,
+        age: 1,
+    };
+    assert_eq!(Row::from_str(&row.render_string()).unwrap(), row);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{key}=>{value}")]
+struct Pair {
+    #[templatia(escape = '%')]
+    key: String,
+    value: String,
+}
+
+#[test]
+fn multi_character_literal_is_escaped() {
+    let pair = Pair {
+        key: "a=>b".to_string(),
+        value: "c".to_string(),
+    };
+    assert_eq!(pair.render_string(), "a%=>b=>c");
+    assert_eq!(Pair::from_str("a%=>b=>c").unwrap(), pair);
+}
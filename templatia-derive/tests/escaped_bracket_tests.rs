@@ -0,0 +1,73 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Escaped brackets: `[[` and `]]` are literal brackets, like `{{` and `}}`.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[[{section}]]\nport={port}")]
+struct Section {
+    section: Option<String>,
+    port: u16,
+}
+
+#[test]
+fn escaped_brackets_render_as_literals() {
+    let value = Section {
+        section: Some("server".into()),
+        port: 80,
+    };
+    assert_eq!(value.render_string(), "[server]\nport=80");
+    let empty = Section {
+        section: None,
+        port: 80,
+    };
+    assert_eq!(empty.render_string(), "[]\nport=80");
+}
+
+#[test]
+fn escaped_brackets_parse_as_literals() {
+    assert_eq!(
+        Section::from_str("[server]\nport=80").unwrap(),
+        Section {
+            section: Some("server".into()),
+            port: 80,
+        }
+    );
+    assert!(Section::from_str("port=80").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{host}[ [[{port}]]]")]
+struct Endpoint {
+    host: String,
+    port: Option<u16>,
+}
+
+#[test]
+fn escaped_brackets_inside_a_group() {
+    let value = Endpoint {
+        host: "a".into(),
+        port: Some(1),
+    };
+    assert_eq!(value.render_string(), "a [1]");
+    assert_eq!(Endpoint::from_str("a [1]").unwrap(), value);
+    let none = Endpoint {
+        host: "a".into(),
+        port: None,
+    };
+    assert_eq!(none.render_string(), "a");
+    assert_eq!(Endpoint::from_str("a").unwrap(), none);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[x] {value}")]
+struct Plain {
+    value: u8,
+}
+
+#[test]
+fn single_brackets_without_optional_placeholders_stay_literal() {
+    let value = Plain { value: 3 };
+    assert_eq!(value.render_string(), "[x] 3");
+    assert_eq!(Plain::from_str("[x] 3").unwrap(), value);
+}
@@ -0,0 +1,107 @@
+use std::collections::{BTreeMap, BTreeSet};
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `escape` on collection and map fields escapes separators inside elements so they round-trip.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "tags={tags};")]
+struct Tags {
+    #[templatia(escape = '\\')]
+    tags: Vec<String>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{names}]")]
+struct Names {
+    #[templatia(escape = '^', separator = " | ")]
+    names: BTreeSet<String>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{slots}")]
+struct Slots {
+    #[templatia(escape = '\\')]
+    slots: Vec<Option<String>>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "env={env};")]
+struct Env {
+    #[templatia(escape = '\\')]
+    env: BTreeMap<String, String>,
+}
+
+#[test]
+fn separator_inside_element_is_escaped_on_render() {
+    let tags = Tags {
+        tags: vec!["a,b".into(), "c".into()],
+    };
+    assert_eq!(tags.render_string(), "tags=a\\,b,c;");
+}
+
+#[test]
+fn escaped_separator_is_kept_inside_element_on_parse() {
+    let parsed = Tags::from_str("tags=a\\,b,c;").unwrap();
+    assert_eq!(parsed.tags, vec!["a,b".to_string(), "c".to_string()]);
+}
+
+#[test]
+fn escape_character_and_next_literal_round_trip() {
+    let tags = Tags {
+        tags: vec!["x\\y".into(), "semi;colon".into(), "".into()],
+    };
+    let rendered = tags.render_string();
+    assert_eq!(rendered, r"tags=x\\y,semi\;colon,;");
+    assert_eq!(Tags::from_str(&rendered).unwrap(), tags);
+}
+
+#[test]
+fn custom_escape_and_separator_round_trip() {
+    let names = Names {
+        names: BTreeSet::from(["a | b".to_string(), "c^d".to_string()]),
+    };
+    let rendered = names.render_string();
+    assert_eq!(rendered, "[a^ | b | c^^d]");
+    assert_eq!(Names::from_str(&rendered).unwrap(), names);
+}
+
+#[test]
+fn sparse_elements_with_escaped_separators_round_trip() {
+    let slots = Slots {
+        slots: vec![Some("1,2".into()), None, Some("3".into())],
+    };
+    let rendered = slots.render_string();
+    assert_eq!(rendered, "1\\,2,,3");
+    assert_eq!(Slots::from_str(&rendered).unwrap(), slots);
+}
+
+#[test]
+fn empty_collection_round_trips() {
+    let tags = Tags { tags: vec![] };
+    assert_eq!(tags.render_string(), "tags=;");
+    assert_eq!(Tags::from_str("tags=;").unwrap(), tags);
+}
+
+#[test]
+fn map_delimiters_inside_keys_and_values_round_trip() {
+    let env = Env {
+        env: BTreeMap::from([
+            ("a=b".to_string(), "c,d".to_string()),
+            ("e".to_string(), "f;g\\h".to_string()),
+            ("".to_string(), "=".to_string()),
+        ]),
+    };
+    let rendered = env.render_string();
+    assert_eq!(rendered, r"env==\=,a\=b=c\,d,e=f\;g\\h;");
+    assert_eq!(Env::from_str(&rendered).unwrap(), env);
+}
+
+#[test]
+fn unescaped_kv_separator_splits_map_entry() {
+    let parsed = Env::from_str(r"env=a\=b=c=d;").unwrap();
+    assert_eq!(
+        parsed.env,
+        BTreeMap::from([("a=b".to_string(), "c=d".to_string())])
+    );
+}
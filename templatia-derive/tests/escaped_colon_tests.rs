@@ -1,4 +1,5 @@
-use templatia::{Template, TemplateError};
+use templatia::TemplateError;
+use templatia::prelude::*;
 
 // Tests focusing on internal escaping of ':' used in error handling paths.
 // These ensure that user-facing error values correctly contain ':' characters
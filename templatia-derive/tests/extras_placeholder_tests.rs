@@ -0,0 +1,92 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {*extras}: unknown key = value lines are collected into a map field and rendered back.
+
+use std::collections::{BTreeMap, HashMap};
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host = {host}\nport = {port}\n{*extras}")]
+struct Config {
+    host: String,
+    port: u16,
+    extras: HashMap<String, String>,
+}
+
+#[test]
+fn unknown_lines_are_collected() {
+    let config = Config::from_str("host = db\ntimeout = 30\nport = 5432\nmode=fast\n").unwrap();
+    assert_eq!(config.host, "db");
+    assert_eq!(config.port, 5432);
+    assert_eq!(
+        config.extras,
+        HashMap::from([
+            ("timeout".to_string(), "30".to_string()),
+            ("mode".to_string(), "fast".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn extras_render_sorted_after_known_lines() {
+    let config = Config {
+        host: "db".into(),
+        port: 5432,
+        extras: HashMap::from([
+            ("timeout".to_string(), "30".to_string()),
+            ("mode".to_string(), "fast".to_string()),
+        ]),
+    };
+    let rendered = config.render_string();
+    assert_eq!(
+        rendered,
+        "host = db\nport = 5432\nmode = fast\ntimeout = 30\n"
+    );
+    assert_eq!(Config::from_str(&rendered).unwrap(), config);
+}
+
+#[test]
+fn empty_extras_render_nothing() {
+    let config = Config {
+        host: "db".into(),
+        port: 5432,
+        extras: HashMap::new(),
+    };
+    assert_eq!(config.render_string(), "host = db\nport = 5432\n");
+    assert_eq!(
+        Config::from_str("host = db\nport = 5432\n").unwrap(),
+        config
+    );
+}
+
+#[test]
+fn unknown_last_line_without_a_line_break_is_collected() {
+    let config = Config::from_str("host = db\nport = 5432\nmode=fast").unwrap();
+    assert_eq!(config.port, 5432);
+    assert_eq!(
+        config.extras,
+        HashMap::from([("mode".to_string(), "fast".to_string())])
+    );
+}
+
+#[test]
+fn lines_without_a_key_still_fail() {
+    assert!(Config::from_str("host = db\nport = 5432\nnot a setting\n").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name: {name}\n{*rest}")]
+struct Named {
+    name: String,
+    rest: BTreeMap<String, String>,
+}
+
+#[test]
+fn btree_map_collects_extras() {
+    let named = Named::from_str("name: app\nlevel = debug\n").unwrap();
+    assert_eq!(named.name, "app");
+    assert_eq!(
+        named.rest,
+        BTreeMap::from([("level".to_string(), "debug".to_string())])
+    );
+    assert_eq!(named.render_string(), "name: app\nlevel = debug\n");
+}
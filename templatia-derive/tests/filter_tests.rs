@@ -0,0 +1,86 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {field|filter}: built-in filters applied on render and undone on parse where possible.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name|upper}")]
+struct Shout {
+    name: String,
+}
+
+#[test]
+fn case_filter_renders_and_parses_as_captured() {
+    let value = Shout {
+        name: "Alice".to_string(),
+    };
+    assert_eq!(value.render_string(), "name=ALICE");
+    assert_eq!(Shout::from_str("name=ALICE").unwrap().name, "ALICE");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "https://example.com/search?q={query|url_encode}&page={page|url_encode}")]
+struct Search {
+    query: String,
+    page: Option<u32>,
+}
+
+#[test]
+fn url_encode_round_trips() {
+    let search = Search {
+        query: "a b&c=d/é".to_string(),
+        page: Some(2),
+    };
+    let rendered = search.render_string();
+    assert_eq!(
+        rendered,
+        "https://example.com/search?q=a%20b%26c%3Dd%2F%C3%A9&page=2"
+    );
+    assert_eq!(Search::from_str(&rendered).unwrap(), search);
+}
+
+#[test]
+fn empty_filtered_option_is_none() {
+    let parsed = Search::from_str("https://example.com/search?q=x&page=").unwrap();
+    assert_eq!(parsed.page, None);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{tag|trim|lower}] {count|trim}")]
+struct Tagged {
+    tag: String,
+    count: u32,
+}
+
+#[test]
+fn filters_apply_in_order() {
+    let value = Tagged {
+        tag: "  Release ".to_string(),
+        count: 3,
+    };
+    assert_eq!(value.render_string(), "[release] 3");
+}
+
+#[test]
+fn trim_is_undone_when_parsing() {
+    assert_eq!(
+        Tagged::from_str("[ beta ]   7  ").unwrap(),
+        Tagged {
+            tag: "beta".to_string(),
+            count: 7,
+        }
+    );
+}
+
+#[test]
+fn type_errors_report_the_decoded_value() {
+    match Tagged::from_str("[beta]  seven ").unwrap_err() {
+        templatia::TemplateError::ParseToType {
+            placeholder, value, ..
+        } => {
+            assert_eq!(placeholder, "count");
+            assert_eq!(value, "seven");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
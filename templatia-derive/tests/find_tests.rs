@@ -0,0 +1,49 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// find: the first part of a larger text matching the template is parsed.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "user={name} id={id};", find)]
+struct Login {
+    name: String,
+    id: u32,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{code}ms;", find)]
+struct Latency {
+    code: u32,
+}
+
+#[test]
+fn finds_the_template_inside_text() {
+    let login = Login::find("12:00 INFO user=alice id=7; session opened").unwrap();
+    assert_eq!(
+        login,
+        Login {
+            name: "alice".into(),
+            id: 7
+        }
+    );
+}
+
+#[test]
+fn skips_candidates_that_fail_to_parse() {
+    let login = Login::find("user=bob id=x; then user=carol id=3;").unwrap();
+    assert_eq!(login.name, "carol");
+    assert_eq!(login.id, 3);
+}
+
+#[test]
+fn returns_none_without_a_match() {
+    assert_eq!(Login::find("nothing to see here"), None);
+}
+
+#[test]
+fn finds_templates_starting_with_a_placeholder() {
+    assert_eq!(
+        Latency::find("GET /health took 42ms; ok").unwrap(),
+        Latency { code: 42 }
+    );
+}
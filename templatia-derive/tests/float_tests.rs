@@ -0,0 +1,68 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// float: round-trip-safe representations of f32 and f64 values.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{a};{b};{c}", float = "shortest")]
+struct Shortest {
+    a: f64,
+    b: f32,
+    #[templatia(float = "exponent")]
+    c: f64,
+}
+
+#[test]
+fn shortest_picks_the_shorter_notation() {
+    let value = Shortest {
+        a: 1e300,
+        b: 0.1,
+        c: 0.1,
+    };
+    assert_eq!(value.render_string(), "1e300;0.1;1e-1");
+}
+
+#[test]
+fn rendered_floats_read_back_to_the_same_bits() {
+    let samples = [
+        0.1,
+        -0.0,
+        1.0 / 3.0,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        5e-324,
+        123456789.12345679,
+        f64::INFINITY,
+    ];
+    for a in samples {
+        let value = Shortest {
+            a,
+            b: a as f32,
+            c: a,
+        };
+        let parsed = Shortest::from_str(&value.render_string()).unwrap();
+        assert_eq!(parsed.a.to_bits(), a.to_bits());
+        assert_eq!(parsed.b.to_bits(), (a as f32).to_bits());
+        assert_eq!(parsed.c.to_bits(), a.to_bits());
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{values}] {padded}|")]
+struct Collections {
+    #[templatia(float = "exponent")]
+    values: Vec<f64>,
+    #[templatia(float = "shortest", width = 8)]
+    padded: f64,
+}
+
+#[test]
+fn styles_apply_to_collections_and_widths() {
+    let value = Collections {
+        values: vec![1500.0, 0.25],
+        padded: 2.5,
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "[1.5e3,2.5e-1]      2.5|");
+    assert_eq!(Collections::from_str(&rendered).unwrap(), value);
+}
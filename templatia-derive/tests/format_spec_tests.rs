@@ -0,0 +1,77 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(format = "...")]` renders values with a custom format spec while parsing stays tolerant.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "id={id};price={price}")]
+struct Item {
+    #[templatia(format = "{:08}")]
+    id: u32,
+    #[templatia(format = "{:.2}")]
+    price: f64,
+}
+
+#[test]
+fn render_uses_format_spec() {
+    let item = Item { id: 42, price: 3.5 };
+    assert_eq!(item.render_string(), "id=00000042;price=3.50");
+}
+
+#[test]
+fn parse_accepts_formatted_and_plain_values() {
+    let formatted = Item::from_str("id=00000042;price=3.50").unwrap();
+    assert_eq!(formatted, Item { id: 42, price: 3.5 });
+
+    let plain = Item::from_str("id=42;price=3.5").unwrap();
+    assert_eq!(plain, Item { id: 42, price: 3.5 });
+}
+
+#[test]
+fn round_trip_with_format_spec() {
+    let item = Item { id: 7, price: 10.0 };
+    let rendered = item.render_string();
+    assert_eq!(Item::from_str(&rendered).unwrap(), item);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "code={code};values={values}")]
+struct Wrapped {
+    #[templatia(format = "{:>4}")]
+    code: Option<u16>,
+    #[templatia(format = "{:03}")]
+    values: Vec<u8>,
+}
+
+#[test]
+fn format_applies_to_option_and_collection_values() {
+    let wrapped = Wrapped {
+        code: Some(7),
+        values: vec![1, 20],
+    };
+    assert_eq!(wrapped.render_string(), "code=   7;values=001,020");
+
+    let none = Wrapped {
+        code: None,
+        values: vec![],
+    };
+    assert_eq!(none.render_string(), "code=;values=");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{host}[:{port}]")]
+struct Endpoint {
+    host: String,
+    #[templatia(format = "{:05}")]
+    port: Option<u16>,
+}
+
+#[test]
+fn format_applies_inside_optional_group() {
+    let endpoint = Endpoint {
+        host: "localhost".to_string(),
+        port: Some(80),
+    };
+    assert_eq!(endpoint.render_string(), "localhost:00080");
+    assert_eq!(Endpoint::from_str("localhost:00080").unwrap(), endpoint);
+}
@@ -0,0 +1,50 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// from_reader parses the text read out of a BufRead like from_str parses a string.
+
+use std::io::{BufReader, Read};
+use templatia::prelude::*;
+use templatia::{ReadError, TemplateError};
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[server]\nhost = {host}\nport = {port}\n")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn from_reader_parses_the_whole_input() {
+    let input = "[server]\nhost = localhost\nport = 8080\n";
+    let server = Server::from_reader(input.as_bytes()).unwrap();
+    assert_eq!(server, Server::from_str(input).unwrap());
+}
+
+#[test]
+fn from_reader_joins_input_arriving_in_pieces() {
+    let pieces = "[server]\nho"
+        .as_bytes()
+        .chain("st = example.com\npo".as_bytes())
+        .chain("rt = 443\n".as_bytes());
+    let server = Server::from_reader(BufReader::with_capacity(4, pieces)).unwrap();
+    assert_eq!(server.host, "example.com");
+    assert_eq!(server.port, 443);
+}
+
+#[test]
+fn from_reader_reports_template_errors() {
+    let err =
+        Server::from_reader("[server]\nhost = localhost\nport = http\n".as_bytes()).unwrap_err();
+    assert!(matches!(
+        err,
+        ReadError::Template(TemplateError::ParseToType { .. })
+    ));
+}
+
+#[test]
+fn from_reader_rejects_invalid_utf8_as_io_error() {
+    let input: &[u8] = b"[server]\nhost = \xff\nport = 1\n";
+    match Server::from_reader(input).unwrap_err() {
+        ReadError::Io(err) => assert_eq!(err.kind(), std::io::ErrorKind::InvalidData),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
@@ -0,0 +1,78 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {field<<END}: multi-line values captured up to a terminator line.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name}\nsql:\n{query<<END}\ntimeout={timeout}")]
+struct Job {
+    name: String,
+    query: String,
+    timeout: u32,
+}
+
+#[test]
+fn heredoc_round_trips_text_with_separators() {
+    let job = Job {
+        name: "report".to_string(),
+        query: "SELECT a, b\nFROM t\nWHERE c = 'timeout=1'".to_string(),
+        timeout: 30,
+    };
+    let rendered = job.render_string();
+    assert_eq!(
+        rendered,
+        "name=report\nsql:\nSELECT a, b\nFROM t\nWHERE c = 'timeout=1'\nEND\ntimeout=30"
+    );
+    assert_eq!(Job::from_str(&rendered).unwrap(), job);
+}
+
+#[test]
+fn terminator_must_fill_its_line() {
+    let parsed = Job::from_str("name=x\nsql:\nENDING\nEND\ntimeout=1").unwrap();
+    assert_eq!(parsed.query, "ENDING");
+}
+
+#[test]
+fn empty_heredoc() {
+    let parsed = Job::from_str("name=x\nsql:\n\nEND\ntimeout=1").unwrap();
+    assert_eq!(parsed.query, "");
+}
+
+#[test]
+fn missing_terminator_is_an_error() {
+    assert!(Job::from_str("name=x\nsql:\nSELECT 1\ntimeout=1").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "count={count}\n{message<<EOF}")]
+struct Payload {
+    message: String,
+    count: u8,
+}
+
+#[test]
+fn heredoc_can_end_the_input() {
+    let payload = Payload {
+        message: "a\nb".to_string(),
+        count: 2,
+    };
+    let rendered = payload.render_string();
+    assert_eq!(rendered, "count=2\na\nb\nEOF");
+    assert_eq!(Payload::from_str(&rendered).unwrap(), payload);
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    should_panic(expected = "contains its terminator line")
+)]
+fn value_ending_with_the_terminator_line_is_rejected() {
+    let job = Job {
+        name: "x".to_string(),
+        query: "ok:\nEND".to_string(),
+        timeout: 1,
+    };
+    let rendered = job.render_string();
+    assert_eq!(rendered, "name=x\nsql:\nok:\nEND\nEND\ntimeout=1");
+    assert!(Job::from_str(&rendered).is_err());
+}
@@ -0,0 +1,83 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// ignore_unknown_lines: input lines with keys outside the template are skipped.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(ignore_unknown_lines)]
+struct Config {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn unknown_lines_are_skipped() {
+    let input =
+        "# generated\nhost = localhost\nhostname = other\ncolor = blue\nport = 8080\ndebug = true";
+    assert_eq!(
+        Config::from_str(input).unwrap(),
+        Config {
+            host: "localhost".into(),
+            port: 8080,
+        }
+    );
+}
+
+#[test]
+fn rendering_is_unchanged() {
+    let config = Config {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(config.render_string(), "host = localhost\nport = 8080");
+}
+
+#[test]
+fn missing_known_lines_still_fail() {
+    assert!(Config::from_str("host = localhost\ncolor = blue").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(ignore_unknown_lines, loose_whitespace)]
+struct Loose {
+    name: String,
+}
+
+#[test]
+fn keys_match_regardless_of_spacing() {
+    assert_eq!(
+        Loose::from_str("other=1\nname=demo").unwrap(),
+        Loose {
+            name: "demo".into()
+        }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "<server>\nhost: {host}\n", ignore_unknown_lines)]
+struct Section {
+    host: String,
+}
+
+#[test]
+fn literal_lines_and_trailing_newline_are_kept() {
+    assert_eq!(
+        Section::from_str("<server>\ntimeout: 5\nhost: example.com\n").unwrap(),
+        Section {
+            host: "example.com".into()
+        }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(ignore_unknown_lines, lossy)]
+struct Lossy {
+    count: u32,
+}
+
+#[test]
+fn lossy_parse_skips_unknown_lines() {
+    let (value, errors) = Lossy::from_str_lossy("extra = x\ncount = 3");
+    assert_eq!(value, Lossy { count: 3 });
+    assert!(errors.is_empty());
+}
@@ -0,0 +1,102 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `{>field}` splices the template of a field whose type implements Template.
+
+#[derive(Template, Debug, PartialEq, Clone)]
+#[templatia(template = "app={app} v{version}")]
+struct Header {
+    app: String,
+    version: u32,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "# {>header}\nport={port}")]
+struct ServerConfig {
+    header: Header,
+    port: u16,
+}
+
+#[test]
+fn include_renders_nested_template() {
+    let config = ServerConfig {
+        header: Header {
+            app: "web".into(),
+            version: 2,
+        },
+        port: 8080,
+    };
+    assert_eq!(config.render_string(), "# app=web v2\nport=8080");
+}
+
+#[test]
+fn include_parses_nested_template() {
+    let parsed = ServerConfig::from_str("# app=api v7\nport=9000").unwrap();
+    assert_eq!(
+        parsed.header,
+        Header {
+            app: "api".into(),
+            version: 7,
+        }
+    );
+    assert_eq!(parsed.port, 9000);
+}
+
+#[test]
+fn include_at_end_of_template() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "id={id};{>header}")]
+    struct Tagged {
+        id: u8,
+        header: Header,
+    }
+
+    let tagged = Tagged {
+        id: 1,
+        header: Header {
+            app: "cli".into(),
+            version: 3,
+        },
+    };
+    let rendered = tagged.render_string();
+    assert_eq!(rendered, "id=1;app=cli v3");
+    assert_eq!(Tagged::from_str(&rendered).unwrap(), tagged);
+}
+
+#[test]
+fn include_errors_are_reported_with_field_name() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port};{>header}")]
+    struct Trailing {
+        port: u16,
+        header: Header,
+    }
+
+    let err = Trailing::from_str("port=1;app=web vX").expect_err("nested parse must fail");
+    match err {
+        TemplateError::Include {
+            placeholder,
+            message,
+        } => {
+            assert_eq!(placeholder, "header");
+            assert!(message.contains("version"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn duplicate_includes_must_match() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "[{>header}]({>header})")]
+    struct Twice {
+        header: Header,
+    }
+
+    assert!(Twice::from_str("[app=a v1](app=a v1)").is_ok());
+    assert!(matches!(
+        Twice::from_str("[app=a v1](app=b v1)"),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+}
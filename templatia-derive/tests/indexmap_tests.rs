@@ -0,0 +1,33 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// With the `indexmap` feature, IndexMap and IndexSet fields keep their insertion order across render and parse.
+
+use indexmap::{IndexMap, IndexSet};
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "order={order}\nsettings={settings}")]
+struct Config {
+    order: IndexSet<String>,
+    #[templatia(entry_sep = ";")]
+    settings: IndexMap<String, u32>,
+}
+
+#[test]
+fn insertion_order_is_rendered() {
+    let config = Config {
+        order: IndexSet::from(["zeta".to_string(), "alpha".to_string()]),
+        settings: IndexMap::from([("timeout".to_string(), 30), ("retries".to_string(), 3)]),
+    };
+    assert_eq!(
+        config.render_string(),
+        "order=zeta,alpha\nsettings=timeout=30;retries=3"
+    );
+}
+
+#[test]
+fn parsing_keeps_the_input_order() {
+    let config = Config::from_str("order=b,a,c\nsettings=z=1;a=2").unwrap();
+    assert_eq!(config.order.iter().collect::<Vec<_>>(), vec!["b", "a", "c"]);
+    assert_eq!(config.settings.keys().collect::<Vec<_>>(), vec!["z", "a"]);
+    assert_eq!(Config::from_str(&config.render_string()).unwrap(), config);
+}
@@ -0,0 +1,104 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {field=default}: inline default values written in the template.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host={host}\nport={port=8080}")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn empty_capture_uses_the_inline_default() {
+    let parsed = Server::from_str("host=localhost\nport=").unwrap();
+    assert_eq!(
+        parsed,
+        Server {
+            host: "localhost".to_string(),
+            port: 8080,
+        }
+    );
+}
+
+#[test]
+fn present_value_is_parsed_as_usual() {
+    let parsed = Server::from_str("host=localhost\nport=9000").unwrap();
+    assert_eq!(parsed.port, 9000);
+}
+
+#[test]
+fn rendering_writes_the_value_not_the_default() {
+    let server = Server {
+        host: "localhost".to_string(),
+        port: 9000,
+    };
+    assert_eq!(server.render_string(), "host=localhost\nport=9000");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name = guest}:{greeting= hello there}")]
+struct Spaced {
+    name: String,
+    greeting: String,
+}
+
+#[test]
+fn default_text_is_used_verbatim() {
+    let parsed = Spaced::from_str(":").unwrap();
+    assert_eq!(parsed.name, " guest");
+    assert_eq!(parsed.greeting, " hello there");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "timeout={timeout=30};retries={retries}")]
+struct Optional {
+    timeout: Option<u32>,
+    retries: Option<u32>,
+}
+
+#[test]
+fn option_default_becomes_some() {
+    let parsed = Optional::from_str("timeout=;retries=").unwrap();
+    assert_eq!(
+        parsed,
+        Optional {
+            timeout: Some(30),
+            retries: None,
+        }
+    );
+}
+
+#[test]
+fn none_renders_as_the_default_text() {
+    let value = Optional {
+        timeout: None,
+        retries: None,
+    };
+    assert_eq!(value.render_string(), "timeout=30;retries=");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "limit={limit=unlimited}")]
+struct BadDefault {
+    limit: u32,
+}
+
+#[test]
+fn default_that_does_not_parse_is_a_type_error() {
+    let err = BadDefault::from_str("limit=").unwrap_err();
+    match err {
+        TemplateError::ParseToType {
+            placeholder,
+            value,
+            type_name,
+        } => {
+            assert_eq!(placeholder, "limit");
+            assert_eq!(value, "unlimited");
+            assert_eq!(type_name, "u32");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
@@ -0,0 +1,120 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `{field:spec}` format specs in placeholders render through `format!` while parsing stays tolerant.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "id={id:>8} price={price:.2}")]
+struct Item {
+    id: u32,
+    price: f64,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{name:*^9}] code={code:04} mask={mask:#x}")]
+struct Tagged {
+    name: String,
+    code: u16,
+    mask: u8,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "total={total:+.1};levels={levels:<3}")]
+struct Summary {
+    total: Option<f32>,
+    levels: Vec<u8>,
+}
+
+#[test]
+fn inline_specs_render_aligned_and_fixed_precision_values() {
+    let item = Item { id: 42, price: 3.5 };
+    assert_eq!(item.render_string(), "id=      42 price=3.50");
+}
+
+#[test]
+fn padded_values_parse_back() {
+    let item = Item::from_str("id=      42 price=3.50").unwrap();
+    assert_eq!(item, Item { id: 42, price: 3.5 });
+}
+
+#[test]
+fn unpadded_values_are_accepted_too() {
+    let item = Item::from_str("id=42 price=3.5").unwrap();
+    assert_eq!(item, Item { id: 42, price: 3.5 });
+}
+
+#[test]
+fn fill_characters_zero_padding_and_alternate_forms_render() {
+    let tagged = Tagged {
+        name: "abc".to_string(),
+        code: 7,
+        mask: 255,
+    };
+    assert_eq!(tagged.render_string(), "[***abc***] code=0007 mask=0xff");
+}
+
+#[test]
+fn specs_apply_to_each_value_of_options_and_collections() {
+    let summary = Summary {
+        total: Some(2.0),
+        levels: vec![1, 2],
+    };
+    assert_eq!(summary.render_string(), "total=+2.0;levels=1  ,2  ");
+    assert_eq!(
+        Summary::from_str("total=+2.0;levels=1  ,2  ").unwrap(),
+        summary
+    );
+}
+
+#[test]
+fn ranges_are_still_ranges() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port:1..=65535}")]
+    struct Port {
+        port: u32,
+    }
+
+    assert!(matches!(
+        Port::from_str("port=0"),
+        Err(templatia::TemplateError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn specs_combine_with_modifiers() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{name:<6,uppercase}|{id:u32,>4}")]
+    struct Row {
+        name: String,
+        id: u32,
+    }
+
+    let row = Row {
+        name: "AB".to_string(),
+        id: 9,
+    };
+    assert_eq!(row.render_string(), "AB    |   9");
+    assert_eq!(Row::from_str("AB    |   9").unwrap(), row);
+}
+
+#[test]
+fn fill_characters_are_stripped_when_parsing() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{center:*^10}|{left:-<6}|{right:_>6}")]
+    struct Filled {
+        center: String,
+        left: String,
+        right: u32,
+    }
+
+    let filled = Filled {
+        center: "ab".to_string(),
+        left: "cd".to_string(),
+        right: 42,
+    };
+    assert_eq!(filled.render_string(), "****ab****|cd----|____42");
+    assert_eq!(
+        Filled::from_str("****ab****|cd----|____42").unwrap(),
+        filled
+    );
+}
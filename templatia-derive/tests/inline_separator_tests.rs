@@ -0,0 +1,48 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Inline separators: {field*", "} joins and splits collection elements with the quoted text.
+
+use std::collections::BTreeSet;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = r#"tags={tags*", "}"#)]
+struct Post {
+    tags: Vec<String>,
+}
+
+#[test]
+fn inline_separator_joins_on_render() {
+    let post = Post {
+        tags: vec!["a".into(), "b".into(), "c".into()],
+    };
+    assert_eq!(post.render_string(), "tags=a, b, c");
+}
+
+#[test]
+fn inline_separator_splits_on_parse() {
+    assert_eq!(
+        Post::from_str("tags=a, b, c").unwrap(),
+        Post {
+            tags: vec!["a".into(), "b".into(), "c".into()],
+        }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = r#"ports={ports*" | "};hosts={hosts}"#, separator = ";")]
+struct Mixed {
+    ports: BTreeSet<u16>,
+    #[templatia(separator = "+")]
+    hosts: Vec<String>,
+}
+
+#[test]
+fn inline_separator_overrides_the_separator_attribute() {
+    let mixed = Mixed {
+        ports: BTreeSet::from([80, 443]),
+        hosts: vec!["a".into(), "b".into()],
+    };
+    let rendered = mixed.render_string();
+    assert_eq!(rendered, "ports=80 | 443;hosts=a+b");
+    assert_eq!(Mixed::from_str(&rendered).unwrap(), mixed);
+}
@@ -0,0 +1,58 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Localized templates: template(en = "...", ja = "...") with render_localized and parsing of any locale.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template(
+        en = "Copied {count} files to {dest}",
+        ja = "{dest}に{count}件コピーしました",
+        de = "{count} Dateien nach {dest} kopiert"
+    ),
+    alt_template = "{count}->{dest}"
+)]
+struct Status {
+    count: u32,
+    dest: String,
+}
+
+fn status() -> Status {
+    Status {
+        count: 3,
+        dest: "/tmp".into(),
+    }
+}
+
+#[test]
+fn render_string_uses_the_first_locale() {
+    assert_eq!(status().render_string(), "Copied 3 files to /tmp");
+}
+
+#[test]
+fn render_localized_selects_the_locale() {
+    assert_eq!(status().render_localized("ja"), "/tmpに3件コピーしました");
+    assert_eq!(
+        status().render_localized("de"),
+        "3 Dateien nach /tmp kopiert"
+    );
+    assert_eq!(status().render_localized("en"), "Copied 3 files to /tmp");
+}
+
+#[test]
+fn unknown_locale_falls_back_to_the_default() {
+    assert_eq!(status().render_localized("fr"), "Copied 3 files to /tmp");
+}
+
+#[test]
+fn every_locale_parses() {
+    for locale in ["en", "ja", "de"] {
+        let rendered = status().render_localized(locale);
+        assert_eq!(Status::from_str(&rendered).unwrap(), status());
+    }
+}
+
+#[test]
+fn alt_templates_are_tried_after_the_locales() {
+    assert_eq!(Status::from_str("3->/tmp").unwrap(), status());
+}
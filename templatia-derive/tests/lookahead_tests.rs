@@ -0,0 +1,57 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Lookaheads: {?=text} requires and {?!text} rejects the text ahead without consuming it.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{title}{?=---}{body}")]
+struct Document {
+    title: String,
+    body: String,
+}
+
+#[test]
+fn positive_lookahead_ends_the_previous_capture() {
+    assert_eq!(
+        Document::from_str("Hi---there").unwrap(),
+        Document {
+            title: "Hi".into(),
+            body: "---there".into(),
+        }
+    );
+}
+
+#[test]
+fn positive_lookahead_renders_nothing() {
+    let document = Document {
+        title: "Hi".into(),
+        body: "---there".into(),
+    };
+    assert_eq!(document.render_string(), "Hi---there");
+    assert_eq!(
+        Document::from_str(&document.render_string()).unwrap(),
+        document
+    );
+}
+
+#[test]
+fn positive_lookahead_requires_the_text() {
+    assert!(Document::from_str("no sentinel").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "key={?!\"}{value}")]
+struct Unquoted {
+    value: String,
+}
+
+#[test]
+fn negative_lookahead_rejects_the_text() {
+    assert_eq!(
+        Unquoted::from_str("key=plain").unwrap(),
+        Unquoted {
+            value: "plain".into()
+        }
+    );
+    assert!(Unquoted::from_str("key=\"quoted\"").is_err());
+}
@@ -0,0 +1,83 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(loose_whitespace)]` accepts runs of spaces and tabs around literals and separators.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(loose_whitespace)]
+struct Config {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn uneven_spacing_around_literals_parses() {
+    let expected = Config {
+        host: "localhost".to_string(),
+        port: 8080,
+    };
+    assert_eq!(
+        Config::from_str("host = localhost\nport = 8080").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Config::from_str("host=localhost\nport=8080").unwrap(),
+        expected
+    );
+    assert_eq!(
+        Config::from_str("  host \t=   localhost \t\n\tport   =\t8080  ").unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn rendering_is_unchanged() {
+    let config = Config {
+        host: "localhost".to_string(),
+        port: 8080,
+    };
+    assert_eq!(config.render_string(), "host = localhost\nport = 8080");
+}
+
+#[test]
+fn inner_spaces_of_values_are_kept() {
+    let config = Config::from_str("host =  my  host \nport = 1").unwrap();
+    assert_eq!(config.host, "my  host");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{left} and {right}", loose_whitespace)]
+struct Pair {
+    left: String,
+    right: String,
+}
+
+#[test]
+fn word_literals_keep_their_boundaries() {
+    let pair = Pair::from_str("band   and\tbrand").unwrap();
+    assert_eq!(pair.left, "band");
+    assert_eq!(pair.right, "brand");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "tags: {tags}", loose_whitespace)]
+struct Tags {
+    tags: Vec<String>,
+}
+
+#[test]
+fn spaces_around_separators_are_dropped() {
+    let tags = Tags::from_str("tags:a ,  b,\tc").unwrap();
+    assert_eq!(tags.tags, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn blanks_at_both_ends_of_the_input_are_skipped() {
+    let pair = Pair::from_str(" x and y ").unwrap();
+    assert_eq!(pair.left, "x");
+    assert_eq!(pair.right, "y");
+
+    let pair = Pair::from_str("\t x and y \t").unwrap();
+    assert_eq!(pair.left, "x");
+    assert_eq!(pair.right, "y");
+}
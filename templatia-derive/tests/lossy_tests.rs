@@ -0,0 +1,102 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// lossy: from_str_lossy fills values failing to parse with defaults and reports every problem.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "host={host};port={port};retries={retries};tags={tags}",
+    lossy
+)]
+struct Config {
+    host: String,
+    port: u16,
+    #[templatia(default = "3")]
+    retries: u8,
+    tags: Vec<u32>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{id}/{id}", lossy)]
+struct Twice {
+    id: u32,
+}
+
+#[test]
+fn valid_input_has_no_errors() {
+    let (config, errors) = Config::from_str_lossy("host=db;port=5432;retries=5;tags=1,2");
+    assert!(errors.is_empty());
+    assert_eq!(
+        config,
+        Config {
+            host: "db".into(),
+            port: 5432,
+            retries: 5,
+            tags: vec![1, 2]
+        }
+    );
+}
+
+#[test]
+fn failing_values_take_defaults_and_are_all_reported() {
+    let (config, errors) = Config::from_str_lossy("host=db;port=http;retries=x;tags=1,a");
+    assert_eq!(
+        config,
+        Config {
+            host: "db".into(),
+            port: 0,
+            retries: 3,
+            tags: Vec::new()
+        }
+    );
+    assert_eq!(errors.len(), 3);
+    assert!(
+        errors
+            .iter()
+            .all(|e| matches!(e, TemplateError::ParseToType { .. }))
+    );
+    assert!(
+        matches!(&errors[0], TemplateError::ParseToType { placeholder, value, .. } if placeholder == "port" && value == "http")
+    );
+}
+
+#[test]
+fn unmatched_literals_give_all_defaults() {
+    let (config, errors) = Config::from_str_lossy("nothing like the template");
+    assert_eq!(config.host, "");
+    assert_eq!(config.retries, 3);
+    assert!(!errors.is_empty());
+}
+
+#[test]
+fn conflicts_keep_the_first_occurrence() {
+    let (value, errors) = Twice::from_str_lossy("1/2");
+    assert_eq!(value.id, 1);
+    assert!(matches!(
+        errors[..],
+        [TemplateError::InconsistentValues { .. }]
+    ));
+}
+
+#[test]
+fn from_str_still_fails_on_the_first_problem() {
+    assert!(Config::from_str("host=db;port=http;retries=x;tags=1").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{a}:{b}", lossy)]
+struct Ratio {
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn unmatched_literals_report_no_field_errors() {
+    let (ratio, errors) = Ratio::from_str_lossy("garbage");
+    assert_eq!(ratio, Ratio { a: 0, b: 0 });
+    assert!(matches!(
+        errors[..],
+        [TemplateError::UnexpectedInput { .. }]
+    ));
+}
@@ -0,0 +1,89 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Map fields render as key-value entries joined by kv_sep and entry_sep, and parse accordingly.
+
+use std::collections::{BTreeMap, HashMap};
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "env={env}")]
+struct Defaults {
+    env: BTreeMap<String, u32>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "limits={limits}", entry_sep = ";", kv_sep = ":")]
+struct Container {
+    limits: HashMap<String, u32>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "tags={tags} headers={headers}")]
+struct PerField {
+    tags: Vec<String>,
+    #[templatia(entry_sep = "&", kv_sep = ":")]
+    headers: BTreeMap<String, String>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "ports={ports}", separator = "|")]
+struct SeparatorFallback {
+    ports: BTreeMap<String, u16>,
+}
+
+#[test]
+fn maps_use_equals_and_comma_by_default() {
+    let value = Defaults {
+        env: BTreeMap::from([("b".to_string(), 2), ("a".to_string(), 1)]),
+    };
+    assert_eq!(value.render_string(), "env=a=1,b=2");
+    assert_eq!(Defaults::from_str("env=a=1,b=2").unwrap(), value);
+    assert_eq!(Defaults::from_str("env=").unwrap().env, BTreeMap::new());
+}
+
+#[test]
+fn container_delimiters_apply_to_map_fields() {
+    let value = Container {
+        limits: HashMap::from([("cpu".to_string(), 2), ("mem".to_string(), 512)]),
+    };
+    assert_eq!(value.render_string(), "limits=cpu:2;mem:512");
+    assert_eq!(Container::from_str("limits=mem:512;cpu:2").unwrap(), value);
+}
+
+#[test]
+fn field_delimiters_apply_to_that_field_only() {
+    let value = PerField {
+        tags: vec!["x".into(), "y".into()],
+        headers: BTreeMap::from([
+            ("accept".to_string(), "json".to_string()),
+            ("host".to_string(), "example.com".to_string()),
+        ]),
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "tags=x,y headers=accept:json&host:example.com");
+    assert_eq!(PerField::from_str(&rendered).unwrap(), value);
+}
+
+#[test]
+fn entries_fall_back_to_the_separator() {
+    let value = SeparatorFallback {
+        ports: BTreeMap::from([("http".to_string(), 80), ("https".to_string(), 443)]),
+    };
+    assert_eq!(value.render_string(), "ports=http=80|https=443");
+    assert_eq!(
+        SeparatorFallback::from_str("ports=http=80|https=443").unwrap(),
+        value
+    );
+}
+
+#[test]
+fn invalid_entries_fail_to_parse() {
+    assert!(matches!(
+        Defaults::from_str("env=a=x"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+    assert!(matches!(
+        Defaults::from_str("env=a"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+}
@@ -0,0 +1,69 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(match = "greedy")]` captures up to the last usable occurrence of the next literal.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name}.{ext}")]
+struct FileName {
+    #[templatia(match = "greedy")]
+    name: String,
+    ext: String,
+}
+
+#[test]
+fn greedy_consumes_up_to_last_occurrence() {
+    let file = FileName::from_str("archive.tar.gz").unwrap();
+    assert_eq!(file.name, "archive.tar");
+    assert_eq!(file.ext, "gz");
+}
+
+#[test]
+fn greedy_with_single_occurrence() {
+    let file = FileName::from_str("notes.txt").unwrap();
+    assert_eq!(file.name, "notes");
+    assert_eq!(file.ext, "txt");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name}.{ext}")]
+struct LazyFileName {
+    #[templatia(match = "lazy")]
+    name: String,
+    ext: String,
+}
+
+#[test]
+fn lazy_stops_at_first_occurrence() {
+    let file = LazyFileName::from_str("archive.tar.gz").unwrap();
+    assert_eq!(file.name, "archive");
+    assert_eq!(file.ext, "tar.gz");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{message} - {user} - {code}")]
+struct Entry {
+    #[templatia(match = "greedy")]
+    message: String,
+    user: String,
+    code: u32,
+}
+
+#[test]
+fn greedy_leaves_occurrences_for_later_literals() {
+    let entry = Entry::from_str("a - b - c - alice - 42").unwrap();
+    assert_eq!(
+        entry,
+        Entry {
+            message: "a - b - c".to_string(),
+            user: "alice".to_string(),
+            code: 42,
+        }
+    );
+    assert_eq!(Entry::from_str(&entry.render_string()).unwrap(), entry);
+}
+
+#[test]
+fn greedy_without_enough_occurrences_is_an_error() {
+    assert!(Entry::from_str("a - 42").is_err());
+}
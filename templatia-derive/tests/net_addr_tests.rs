@@ -0,0 +1,85 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// IP and socket address fields are scanned by their own shape, so `:` inside them doesn't end the capture.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{ip}:{port}")]
+struct HostPort {
+    ip: IpAddr,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "listen={addr}: ready")]
+struct Listen {
+    addr: SocketAddr,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "v4={v4}:{s4} v6={v6} {s6}")]
+struct Specific {
+    v4: Ipv4Addr,
+    v6: Ipv6Addr,
+    s4: SocketAddrV4,
+    s6: SocketAddrV6,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "gateway={gateway}:end")]
+struct Gateway {
+    gateway: Option<IpAddr>,
+}
+
+#[test]
+fn ipv4_host_and_port_split_at_the_colon() {
+    let parsed = HostPort::from_str("127.0.0.1:8080").unwrap();
+    assert_eq!(parsed.ip, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    assert_eq!(parsed.port, 8080);
+    assert_eq!(parsed.render_string(), "127.0.0.1:8080");
+}
+
+#[test]
+fn socket_addr_followed_by_colon_literal() {
+    for text in ["127.0.0.1:80", "[::1]:8080", "[2001:db8::1]:443"] {
+        let input = format!("listen={}: ready", text);
+        let parsed = Listen::from_str(&input).unwrap();
+        assert_eq!(parsed.addr, text.parse::<SocketAddr>().unwrap());
+        assert_eq!(parsed.render_string(), input);
+    }
+}
+
+#[test]
+fn specific_address_types_round_trip() {
+    let specific = Specific {
+        v4: Ipv4Addr::new(10, 0, 0, 1),
+        v6: "fe80::1".parse().unwrap(),
+        s4: "192.168.1.2:22".parse().unwrap(),
+        s6: "[::1]:9000".parse().unwrap(),
+    };
+    let rendered = specific.render_string();
+    assert_eq!(rendered, "v4=10.0.0.1:192.168.1.2:22 v6=fe80::1 [::1]:9000");
+    assert_eq!(Specific::from_str(&rendered).unwrap(), specific);
+}
+
+#[test]
+fn ipv6_with_embedded_ipv4_is_scanned_as_ipv6() {
+    let parsed = Gateway::from_str("gateway=64:ff9b::1.2.3.4:end").unwrap();
+    assert_eq!(parsed.gateway, Some("64:ff9b::1.2.3.4".parse().unwrap()));
+}
+
+#[test]
+fn empty_optional_address_is_none() {
+    assert_eq!(Gateway::from_str("gateway=:end").unwrap().gateway, None);
+}
+
+#[test]
+fn invalid_address_reports_type_error() {
+    let err = HostPort::from_str("localhost:80").unwrap_err();
+    assert!(
+        matches!(err, templatia::TemplateError::ParseToType { ref placeholder, .. } if placeholder == "ip"),
+        "{:?}",
+        err
+    );
+}
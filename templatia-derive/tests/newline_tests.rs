@@ -0,0 +1,70 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// newline: the line ending of the rendered text, with both line endings accepted when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(newline = "crlf")]
+struct WindowsConfig {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[server]\nname={name}\nport={port}", newline = "lf")]
+struct UnixConfig {
+    name: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "a={a}\nb={b}", newline = "crlf", loose_whitespace)]
+struct LooseConfig {
+    a: u32,
+    b: u32,
+}
+
+#[test]
+fn crlf_renders_carriage_returns() {
+    let value = WindowsConfig {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(value.render_string(), "host = localhost\r\nport = 8080");
+}
+
+#[test]
+fn crlf_round_trips() {
+    let value = WindowsConfig {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(
+        WindowsConfig::from_str(&value.render_string()).unwrap(),
+        value
+    );
+}
+
+#[test]
+fn crlf_accepts_lf_input() {
+    let parsed = WindowsConfig::from_str("host = localhost\nport = 8080").unwrap();
+    assert_eq!(parsed.host, "localhost");
+}
+
+#[test]
+fn lf_renders_line_feeds_and_accepts_crlf_input() {
+    let value = UnixConfig {
+        name: "api".into(),
+        port: 80,
+    };
+    assert_eq!(value.render_string(), "[server]\nname=api\nport=80");
+    let parsed = UnixConfig::from_str("[server]\r\nname=api\r\nport=80").unwrap();
+    assert_eq!(parsed, value);
+}
+
+#[test]
+fn loose_whitespace_accepts_either_line_ending() {
+    let parsed = LooseConfig::from_str("a= 1 \r\n  b=2").unwrap();
+    assert_eq!(parsed, LooseConfig { a: 1, b: 2 });
+    assert_eq!(parsed.render_string(), "a=1\r\nb=2");
+}
@@ -0,0 +1,55 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// none_literals: listed tokens parse to None for Option fields.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{a},{b}", none_literals = ["null", "~", "-"])]
+struct Pair {
+    a: Option<u32>,
+    b: Option<String>,
+}
+
+#[test]
+fn every_listed_token_parses_to_none() {
+    for input in ["null,~", "~,-", "-,null"] {
+        assert_eq!(Pair::from_str(input).unwrap(), Pair { a: None, b: None });
+    }
+}
+
+#[test]
+fn other_values_still_parse() {
+    assert_eq!(
+        Pair::from_str("7,none").unwrap(),
+        Pair {
+            a: Some(7),
+            b: Some("none".into()),
+        }
+    );
+}
+
+#[test]
+fn none_renders_empty_without_render_none() {
+    assert_eq!(Pair { a: None, b: None }.render_string(), ",");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{a},{b}", none_literals = ["~"], render_none = "null")]
+struct Visible {
+    a: Option<u32>,
+    #[templatia(none_literals = ["n/a"])]
+    b: Option<u32>,
+}
+
+#[test]
+fn render_none_token_and_literals_are_accepted() {
+    let none = Visible { a: None, b: None };
+    assert_eq!(none.render_string(), "null,null");
+    assert_eq!(Visible::from_str("null,null").unwrap(), none);
+    assert_eq!(Visible::from_str("~,n/a").unwrap(), none);
+}
+
+#[test]
+fn field_list_replaces_the_struct_list() {
+    assert!(Visible::from_str("~,~").is_err());
+}
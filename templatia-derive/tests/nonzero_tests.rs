@@ -0,0 +1,179 @@
+use std::collections::BTreeSet;
+use std::num::{NonZero, NonZeroU8, NonZeroU16};
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// NonZero integers are treated as primitives, and a zero value is reported as `ZeroValue`.
+
+#[test]
+fn nonzero_roundtrip() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port}")]
+    struct Port {
+        port: NonZeroU16,
+    }
+
+    let port = Port {
+        port: NonZeroU16::new(8080).unwrap(),
+    };
+    let rendered = port.render_string();
+    assert_eq!(rendered, "port=8080");
+    assert_eq!(Port::from_str(&rendered).unwrap(), port);
+}
+
+#[test]
+fn nonzero_zero_value_reports_dedicated_error() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port}")]
+    struct Port {
+        port: NonZeroU16,
+    }
+
+    let err = Port::from_str("port=0").expect_err("zero must be rejected");
+    match err {
+        TemplateError::ZeroValue {
+            placeholder,
+            value,
+            type_name,
+        } => {
+            assert_eq!(placeholder, "port");
+            assert_eq!(value, "0");
+            assert_eq!(type_name, "NonZeroU16");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn nonzero_non_numeric_value_is_parse_to_type() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port}")]
+    struct Port {
+        port: std::num::NonZeroU16,
+    }
+
+    let err = Port::from_str("port=abc").expect_err("non numeric must be rejected");
+    match err {
+        TemplateError::ParseToType { type_name, .. } => assert_eq!(type_name, "NonZeroU16"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn generic_nonzero_is_supported() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "workers={workers}")]
+    struct Pool {
+        workers: NonZero<u32>,
+    }
+
+    let parsed = Pool::from_str("workers=4").unwrap();
+    assert_eq!(parsed.workers.get(), 4);
+
+    let err = Pool::from_str("workers=0").expect_err("zero must be rejected");
+    match err {
+        TemplateError::ZeroValue { type_name, .. } => assert_eq!(type_name, "NonZero<u32>"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn nonzero_after_consecutive_allowed_type() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{flag}{count}")]
+    struct Flagged {
+        flag: bool,
+        count: NonZeroU8,
+    }
+
+    let value = Flagged {
+        flag: true,
+        count: NonZeroU8::new(12).unwrap(),
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "true12");
+    assert_eq!(Flagged::from_str(&rendered).unwrap(), value);
+}
+
+#[test]
+fn option_nonzero_supports_empty_and_zero() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "retries={retries}")]
+    struct Retry {
+        retries: Option<NonZeroU8>,
+    }
+
+    assert_eq!(Retry::from_str("retries=").unwrap().retries, None);
+    assert_eq!(
+        Retry::from_str("retries=3").unwrap().retries,
+        NonZeroU8::new(3)
+    );
+    assert!(matches!(
+        Retry::from_str("retries=0"),
+        Err(TemplateError::ZeroValue { .. })
+    ));
+}
+
+#[test]
+fn nonzero_collections_roundtrip_and_reject_zero_elements() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "ids={ids};set={set}")]
+    struct Ids {
+        ids: Vec<NonZeroU16>,
+        set: BTreeSet<NonZeroU8>,
+    }
+
+    let parsed = Ids::from_str("ids=1,2,3;set=5,4").unwrap();
+    assert_eq!(
+        parsed.ids,
+        vec![
+            NonZeroU16::new(1).unwrap(),
+            NonZeroU16::new(2).unwrap(),
+            NonZeroU16::new(3).unwrap()
+        ]
+    );
+    assert_eq!(parsed.render_string(), "ids=1,2,3;set=4,5");
+
+    let err = Ids::from_str("ids=1;set=1,0,3").expect_err("zero element must be rejected");
+    match err {
+        TemplateError::ZeroValue {
+            placeholder,
+            value,
+            type_name,
+        } => {
+            assert_eq!(placeholder, "set");
+            assert_eq!(value, "1,0,3");
+            assert_eq!(type_name, "BTreeSet<NonZeroU8>");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn nonzero_in_template_is_allowed_with_missing_placeholders() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "port={port}", allow_missing_placeholders)]
+    struct Server {
+        port: NonZeroU16,
+        name: String,
+    }
+
+    let server = Server::from_str("port=443").unwrap();
+    assert_eq!(server.port, NonZeroU16::new(443).unwrap());
+    assert_eq!(server.name, "");
+}
+
+#[test]
+fn missing_nonzero_takes_its_default_expression() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "host={host}", allow_missing_placeholders)]
+    struct Server {
+        host: String,
+        #[templatia(default = "NonZeroU16::new(8080).unwrap()")]
+        port: NonZeroU16,
+    }
+
+    let server = Server::from_str("host=example.com").unwrap();
+    assert_eq!(server.port, NonZeroU16::new(8080).unwrap());
+}
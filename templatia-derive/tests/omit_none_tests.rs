@@ -0,0 +1,101 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// omit_none: the template line of a None value is left out and may be absent when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host={host}\nuser={user}\nport={port}", omit_none)]
+struct Connection {
+    host: String,
+    user: Option<String>,
+    port: Option<u16>,
+}
+
+#[test]
+fn none_line_is_omitted_on_render() {
+    let connection = Connection {
+        host: "db".into(),
+        user: None,
+        port: Some(5432),
+    };
+    assert_eq!(connection.render_string(), "host=db\nport=5432");
+}
+
+#[test]
+fn none_last_line_takes_the_line_break_before_it() {
+    let connection = Connection {
+        host: "db".into(),
+        user: Some("admin".into()),
+        port: None,
+    };
+    assert_eq!(connection.render_string(), "host=db\nuser=admin");
+}
+
+#[test]
+fn some_values_render_every_line() {
+    let connection = Connection {
+        host: "db".into(),
+        user: Some("admin".into()),
+        port: Some(5432),
+    };
+    assert_eq!(connection.render_string(), "host=db\nuser=admin\nport=5432");
+}
+
+#[test]
+fn omitted_lines_parse_as_none() {
+    for connection in [
+        Connection {
+            host: "db".into(),
+            user: None,
+            port: None,
+        },
+        Connection {
+            host: "db".into(),
+            user: None,
+            port: Some(5432),
+        },
+        Connection {
+            host: "db".into(),
+            user: Some("admin".into()),
+            port: None,
+        },
+    ] {
+        let rendered = connection.render_string();
+        assert_eq!(Connection::from_str(&rendered).unwrap(), connection);
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name} nick={nick}\nnote={note}", omit_none)]
+struct Profile {
+    name: String,
+    nick: Option<String>,
+    note: Option<String>,
+}
+
+#[test]
+fn lines_with_required_fields_are_kept() {
+    let profile = Profile {
+        name: "alice".into(),
+        nick: None,
+        note: None,
+    };
+    assert_eq!(profile.render_string(), "name=alice nick=");
+    assert_eq!(Profile::from_str("name=alice nick=").unwrap(), profile);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "user={user}\nport={port}")]
+struct WithoutOmitNone {
+    user: Option<String>,
+    port: u16,
+}
+
+#[test]
+fn lines_are_kept_without_the_option() {
+    let value = WithoutOmitNone {
+        user: None,
+        port: 22,
+    };
+    assert_eq!(value.render_string(), "user=\nport=22");
+}
@@ -0,0 +1,61 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// on_duplicate: resolution of a placeholder repeated in the template.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{id}|{name}|{id}|{id}", on_duplicate = "first")]
+struct First {
+    id: u32,
+    name: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{id}|{name}|{id}|{id}", on_duplicate = "last")]
+struct Last {
+    id: u32,
+    name: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{id}|{name}|{id}", on_duplicate = "error")]
+struct Error {
+    id: u32,
+    name: String,
+}
+
+#[test]
+fn first_takes_the_first_occurrence() {
+    let parsed = First::from_str("1|a|2|3").unwrap();
+    assert_eq!(
+        parsed,
+        First {
+            id: 1,
+            name: "a".into()
+        }
+    );
+}
+
+#[test]
+fn last_takes_the_last_occurrence() {
+    let parsed = Last::from_str("1|a|2|3").unwrap();
+    assert_eq!(parsed.id, 3);
+}
+
+#[test]
+fn other_occurrences_must_still_parse() {
+    assert!(matches!(
+        First::from_str("1|a|x|3"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+}
+
+#[test]
+fn error_reports_inconsistent_values() {
+    assert!(matches!(
+        Error::from_str("1|a|2"),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+    assert_eq!(Error::from_str("1|a|1").unwrap().id, 1);
+}
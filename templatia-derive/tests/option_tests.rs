@@ -1,4 +1,5 @@
-use templatia::{Template, TemplateError};
+use templatia::TemplateError;
+use templatia::prelude::*;
 // Tests for Option<T> support - follows AGENTS.md policy
 
 /// Tests for basic Option<T> field behavior
@@ -0,0 +1,115 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Optional groups: a `[...]` section is omitted for None and may be absent when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host={host}[:{port}]")]
+struct Endpoint {
+    host: String,
+    port: Option<u16>,
+}
+
+#[test]
+fn group_renders_only_for_some() {
+    let with_port = Endpoint {
+        host: "localhost".into(),
+        port: Some(8080),
+    };
+    let without_port = Endpoint {
+        host: "localhost".into(),
+        port: None,
+    };
+    assert_eq!(with_port.render_string(), "host=localhost:8080");
+    assert_eq!(without_port.render_string(), "host=localhost");
+}
+
+#[test]
+fn group_is_optional_when_parsing() {
+    assert_eq!(
+        Endpoint::from_str("host=localhost:8080").unwrap(),
+        Endpoint {
+            host: "localhost".into(),
+            port: Some(8080),
+        }
+    );
+    assert_eq!(
+        Endpoint::from_str("host=localhost").unwrap(),
+        Endpoint {
+            host: "localhost".into(),
+            port: None,
+        }
+    );
+}
+
+#[test]
+fn invalid_group_value_fails() {
+    assert!(Endpoint::from_str("host=localhost:http").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name}[ ({major}.{minor})] [#{tag}]!")]
+struct Release {
+    name: String,
+    major: Option<u32>,
+    minor: Option<u32>,
+    tag: Option<String>,
+}
+
+#[test]
+fn group_with_several_placeholders_needs_all_of_them() {
+    let full = Release {
+        name: "app".into(),
+        major: Some(1),
+        minor: Some(2),
+        tag: Some("beta".into()),
+    };
+    assert_eq!(full.render_string(), "app (1.2) #beta!");
+    assert_eq!(Release::from_str("app (1.2) #beta!").unwrap(), full);
+
+    let partial = Release {
+        name: "app".into(),
+        major: Some(1),
+        minor: None,
+        tag: None,
+    };
+    assert_eq!(partial.render_string(), "app !");
+    let parsed = Release::from_str("app !").unwrap();
+    assert_eq!(parsed.major, None);
+    assert_eq!(parsed.tag, None);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "x={x}[ y={y}]")]
+struct Pair {
+    x: u8,
+    y: Option<String>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "x={x}[ y={y}]", empty_str_option_not_none)]
+struct KeptPair {
+    x: u8,
+    y: Option<String>,
+}
+
+#[test]
+fn empty_value_collapses_group_to_none() {
+    let pair = Pair {
+        x: 1,
+        y: Some(String::new()),
+    };
+    assert_eq!(pair.render_string(), "x=1");
+    assert_eq!(Pair::from_str("x=1").unwrap(), Pair { x: 1, y: None });
+    assert_eq!(Pair::from_str("x=1 y=").unwrap(), Pair { x: 1, y: None });
+}
+
+#[test]
+fn empty_value_keeps_group_when_empty_str_is_some() {
+    let pair = KeptPair {
+        x: 1,
+        y: Some(String::new()),
+    };
+    assert_eq!(pair.render_string(), "x=1 y=");
+    assert_eq!(KeptPair::from_str("x=1 y=").unwrap(), pair);
+}
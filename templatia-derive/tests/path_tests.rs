@@ -0,0 +1,65 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// PathBuf and OsString fields render lossily and parse from the captured text.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "root={root}\nname={name}")]
+struct Paths {
+    root: PathBuf,
+    name: OsString,
+}
+
+#[test]
+fn path_and_os_string_round_trip() {
+    let paths = Paths {
+        root: PathBuf::from("/etc/app"),
+        name: OsString::from("app.toml"),
+    };
+    let rendered = paths.render_string();
+    assert_eq!(rendered, "root=/etc/app\nname=app.toml");
+    assert_eq!(Paths::from_str(&rendered).unwrap(), paths);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "log={log};include={includes}", allow_missing_placeholders)]
+struct Collections {
+    log: Option<PathBuf>,
+    includes: Vec<PathBuf>,
+}
+
+#[test]
+fn option_and_collection_of_paths_round_trip() {
+    let value = Collections {
+        log: Some(PathBuf::from("/var/log/app.log")),
+        includes: vec![PathBuf::from("a.conf"), PathBuf::from("b.conf")],
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "log=/var/log/app.log;include=a.conf,b.conf");
+    assert_eq!(Collections::from_str(&rendered).unwrap(), value);
+}
+
+#[test]
+fn missing_option_path_parses_as_none() {
+    let value = Collections::from_str("log=;include=x").unwrap();
+    assert_eq!(value.log, None);
+    assert_eq!(value.includes, vec![PathBuf::from("x")]);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{file} in {dir} (from {dir})")]
+struct Duplicated {
+    dir: PathBuf,
+    file: String,
+}
+
+#[test]
+fn duplicate_path_placeholders_must_agree() {
+    let value = Duplicated::from_str("a.txt in /srv (from /srv)").unwrap();
+    assert_eq!(value.dir, PathBuf::from("/srv"));
+
+    let err = Duplicated::from_str("a.txt in /srv (from /tmp)").unwrap_err();
+    assert!(err.to_string().contains("/tmp"), "{err}");
+}
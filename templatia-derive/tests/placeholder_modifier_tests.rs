@@ -0,0 +1,81 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {field:modifier} sets per-field options like trim and width=N from the template itself.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{id:width=6}{code:u32,width=4}|{name:trim}")]
+struct Record {
+    id: u32,
+    code: u32,
+    name: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "level={level:uppercase} title={title:quoted} by {author}")]
+struct Entry {
+    level: String,
+    title: String,
+    author: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "port={port:1..=65535,trim}")]
+struct Port {
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{0:width=3}{1}")]
+struct Positional {
+    code: u8,
+    rest: String,
+}
+
+#[test]
+fn width_modifier_pads_and_consumes_fixed_width() {
+    let record = Record {
+        id: 42,
+        code: 7,
+        name: "Ann".into(),
+    };
+    assert_eq!(record.render_string(), "    42   7|Ann");
+    assert_eq!(Record::from_str("    42   7|Ann").unwrap(), record);
+}
+
+#[test]
+fn trim_modifier_trims_the_captured_text() {
+    let record = Record::from_str("000001   2|  Bo  ").unwrap();
+    assert_eq!(record.name, "Bo");
+}
+
+#[test]
+fn case_and_quoted_modifiers_work_like_the_attributes() {
+    let entry = Entry {
+        level: "warn".into(),
+        title: "Stand by me".into(),
+        author: "Ben".into(),
+    };
+    let rendered = entry.render_string();
+    assert_eq!(rendered, "level=WARN title=\"Stand by me\" by Ben");
+    let parsed = Entry::from_str(&rendered).unwrap();
+    assert_eq!(parsed.level, "WARN");
+    assert_eq!(parsed.title, "Stand by me");
+    assert_eq!(parsed.author, "Ben");
+}
+
+#[test]
+fn modifiers_follow_a_range() {
+    assert_eq!(Port::from_str("port= 8080 ").unwrap(), Port { port: 8080 });
+    assert!(Port::from_str("port=0").is_err());
+}
+
+#[test]
+fn modifiers_apply_to_positional_placeholders() {
+    let value = Positional {
+        code: 5,
+        rest: "x".into(),
+    };
+    assert_eq!(value.render_string(), "  5x");
+    assert_eq!(Positional::from_str("  5x").unwrap(), value);
+}
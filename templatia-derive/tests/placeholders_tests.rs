@@ -0,0 +1,76 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// The generated placeholders() lists the placeholder names of the template in order.
+
+use templatia::prelude::*;
+
+#[derive(Template, PartialEq)]
+#[templatia(template = "{port} on {host} (port {port})")]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template)]
+struct Defaults {
+    name: String,
+    retries: u32,
+}
+
+#[derive(Template)]
+#[templatia(template = "{user-name}[ <{email}>]")]
+struct Contact {
+    #[templatia(rename = "user-name")]
+    name: String,
+    email: Option<String>,
+}
+
+#[derive(Template)]
+#[templatia(template = "id={id}", allow_missing_placeholders)]
+struct Partial {
+    id: u32,
+    note: String,
+}
+
+#[derive(Template)]
+#[templatia(template = "{label}: {value}")]
+struct Pair<T> {
+    label: String,
+    value: T,
+}
+
+#[derive(Template)]
+#[templatia(template = "server={>server}")]
+struct Deployment {
+    server: Endpoint,
+}
+
+#[test]
+fn placeholders_follow_the_template_order_without_repeats() {
+    assert_eq!(Endpoint::placeholders(), ["port", "host"]);
+}
+
+#[test]
+fn default_template_lists_fields_in_declaration_order() {
+    assert_eq!(Defaults::placeholders(), ["name", "retries"]);
+}
+
+#[test]
+fn renamed_fields_and_optional_groups_are_listed_by_placeholder_name() {
+    assert_eq!(Contact::placeholders(), ["user-name", "email"]);
+}
+
+#[test]
+fn fields_missing_from_the_template_are_not_listed() {
+    assert_eq!(Partial::placeholders(), ["id"]);
+    assert!(Partial::from_str("id=7").unwrap().note.is_empty());
+}
+
+#[test]
+fn generic_structs_list_their_placeholders() {
+    assert_eq!(Pair::<u8>::placeholders(), ["label", "value"]);
+}
+
+#[test]
+fn includes_are_listed_by_their_field() {
+    assert_eq!(Deployment::placeholders(), ["server"]);
+}
@@ -0,0 +1,53 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Positional placeholders: {0}, {1} refer to the fields in declaration order.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{0}:{1}")]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn positions_render_the_fields_in_declaration_order() {
+    let endpoint = Endpoint {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(endpoint.render_string(), "localhost:8080");
+}
+
+#[test]
+fn positions_parse_into_the_fields() {
+    assert_eq!(
+        Endpoint::from_str("localhost:8080").unwrap(),
+        Endpoint {
+            host: "localhost".into(),
+            port: 8080,
+        }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{1} <- {name}[ ({2})]")]
+struct Mixed {
+    name: String,
+    target: u32,
+    note: Option<String>,
+}
+
+#[test]
+fn positions_mix_with_names_and_groups() {
+    let mixed = Mixed {
+        name: "a".into(),
+        target: 3,
+        note: None,
+    };
+    assert_eq!(mixed.render_string(), "3 <- a");
+    assert_eq!(
+        Mixed::from_str("3 <- a (hi)").unwrap().note.as_deref(),
+        Some("hi")
+    );
+}
@@ -0,0 +1,64 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// prefix/suffix: literal text rendered around every template and required when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(prefix = "# generated by app\n", suffix = "\n")]
+struct Config {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "name={name}",
+    alt_template = "{name}",
+    prefix = "[{banner}] ",
+    suffix = ";"
+)]
+struct Named {
+    name: String,
+}
+
+#[test]
+fn banner_is_rendered() {
+    let value = Config {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(
+        value.render_string(),
+        "# generated by app\nhost = localhost\nport = 8080\n"
+    );
+}
+
+#[test]
+fn banner_round_trips() {
+    let value = Config {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(Config::from_str(&value.render_string()).unwrap(), value);
+}
+
+#[test]
+fn banner_is_required_when_parsing() {
+    assert!(Config::from_str("host = localhost\nport = 8080\n").is_err());
+    assert!(Config::from_str("# generated by app\nhost = localhost\nport = 8080").is_err());
+}
+
+#[test]
+fn braces_and_brackets_stay_literal() {
+    let value = Named {
+        name: "alice".into(),
+    };
+    assert_eq!(value.render_string(), "[{banner}] name=alice;");
+    assert_eq!(Named::from_str("[{banner}] name=alice;").unwrap(), value);
+}
+
+#[test]
+fn banner_applies_to_alt_templates() {
+    let parsed = Named::from_str("[{banner}] bob;").unwrap();
+    assert_eq!(parsed.name, "bob");
+}
@@ -0,0 +1,77 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(quoted)]` renders values as quoted tokens and parses them by reading the quoted token.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "title={title} by {author}")]
+struct Book {
+    #[templatia(quoted)]
+    title: String,
+    author: String,
+}
+
+#[test]
+fn quoted_value_renders_with_escapes() {
+    let book = Book {
+        title: "Say \"hi\" by me".to_string(),
+        author: "Ann".to_string(),
+    };
+    assert_eq!(
+        book.render_string(),
+        "title=\"Say \\\"hi\\\" by me\" by Ann"
+    );
+}
+
+#[test]
+fn quoted_value_may_contain_following_literal() {
+    let book = Book::from_str("title=\"Stand by me\" by Ann").unwrap();
+    assert_eq!(book.title, "Stand by me");
+    assert_eq!(book.author, "Ann");
+}
+
+#[test]
+fn quoted_value_round_trips() {
+    let book = Book {
+        title: "a \\ b \" c by d".to_string(),
+        author: "Bo".to_string(),
+    };
+    assert_eq!(Book::from_str(&book.render_string()).unwrap(), book);
+}
+
+#[test]
+fn unquoted_input_is_an_error() {
+    assert!(Book::from_str("title=Stand by Ann").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{label}{count}")]
+struct Counter {
+    #[templatia(quoted)]
+    label: Option<String>,
+    count: u32,
+}
+
+#[test]
+fn quoted_option_distinguishes_none_and_empty() {
+    let none = Counter {
+        label: None,
+        count: 3,
+    };
+    assert_eq!(none.render_string(), "3");
+    assert_eq!(Counter::from_str("3").unwrap(), none);
+
+    let empty = Counter {
+        label: Some(String::new()),
+        count: 4,
+    };
+    assert_eq!(empty.render_string(), "\"\"4");
+    assert_eq!(Counter::from_str("\"\"4").unwrap(), empty);
+}
+
+#[test]
+fn quoted_placeholder_can_be_followed_by_placeholder() {
+    let counter = Counter::from_str("\"x1\"12").unwrap();
+    assert_eq!(counter.label.as_deref(), Some("x1"));
+    assert_eq!(counter.count, 12);
+}
@@ -0,0 +1,72 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(radix = N)]` renders and parses integers in binary, octal, or hexadecimal.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "color={color} mode={mode} flags={flags}")]
+struct Register {
+    #[templatia(radix = 16)]
+    color: u32,
+    #[templatia(radix = 8)]
+    mode: u16,
+    #[templatia(radix = 2)]
+    flags: u8,
+}
+
+#[test]
+fn radix_renders_in_base() {
+    let register = Register {
+        color: 0xff8800,
+        mode: 0o755,
+        flags: 0b101,
+    };
+    assert_eq!(register.render_string(), "color=ff8800 mode=755 flags=101");
+    assert_eq!(
+        Register::from_str(&register.render_string()).unwrap(),
+        register
+    );
+}
+
+#[test]
+fn radix_parses_with_and_without_prefix() {
+    let prefixed = Register::from_str("color=0xFF8800 mode=0o755 flags=0b101").unwrap();
+    let plain = Register::from_str("color=ff8800 mode=755 flags=101").unwrap();
+    assert_eq!(prefixed, plain);
+}
+
+#[test]
+fn invalid_digits_are_a_type_error() {
+    assert!(matches!(
+        Register::from_str("color=zz mode=7 flags=1"),
+        Err(templatia::TemplateError::ParseToType { .. })
+    ));
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "addr={addr} offset={offset} bytes={bytes}")]
+struct Memory {
+    #[templatia(radix = 16, format = "{:#06x}")]
+    addr: u16,
+    #[templatia(radix = 16)]
+    offset: Option<i32>,
+    #[templatia(radix = 16, separator = " ")]
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn radix_with_format_option_and_collection() {
+    let memory = Memory {
+        addr: 0x1f,
+        offset: Some(-16),
+        bytes: vec![0xde, 0xad],
+    };
+    assert_eq!(memory.render_string(), "addr=0x001f offset=-10 bytes=de ad");
+    assert_eq!(Memory::from_str(&memory.render_string()).unwrap(), memory);
+    assert_eq!(
+        Memory::from_str("addr=1f offset=-0x10 bytes=")
+            .unwrap()
+            .offset,
+        Some(-16)
+    );
+}
@@ -0,0 +1,89 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {field:min..max} ranges reject parsed numbers outside the range with `TemplateError::OutOfRange`.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "port={port:1..=65535} retries={retries:0..10} ratio={ratio:-1..=1}")]
+struct Settings {
+    port: u32,
+    retries: u8,
+    ratio: f64,
+}
+
+fn out_of_range(input: &str) -> (String, String, String) {
+    match Settings::from_str(input) {
+        Err(TemplateError::OutOfRange {
+            placeholder,
+            value,
+            constraint,
+        }) => (placeholder, value, constraint),
+        other => panic!("expected OutOfRange, got {:?}", other),
+    }
+}
+
+#[test]
+fn values_within_the_ranges_parse() {
+    let settings = Settings::from_str("port=65535 retries=9 ratio=-1").unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            port: 65535,
+            retries: 9,
+            ratio: -1.0,
+        }
+    );
+    assert_eq!(settings.render_string(), "port=65535 retries=9 ratio=-1");
+}
+
+#[test]
+fn inclusive_upper_bound_is_enforced() {
+    assert_eq!(
+        out_of_range("port=70000 retries=0 ratio=0"),
+        (
+            "port".into(),
+            "70000".into(),
+            "must be at most 65535".into()
+        )
+    );
+}
+
+#[test]
+fn lower_bound_is_enforced() {
+    assert_eq!(
+        out_of_range("port=0 retries=0 ratio=0"),
+        ("port".into(), "0".into(), "must be at least 1".into())
+    );
+}
+
+#[test]
+fn exclusive_upper_bound_is_enforced() {
+    assert_eq!(
+        out_of_range("port=80 retries=10 ratio=0"),
+        ("retries".into(), "10".into(), "must be less than 10".into())
+    );
+}
+
+#[test]
+fn float_fields_accept_integer_bounds() {
+    assert_eq!(
+        out_of_range("port=80 retries=0 ratio=1.5"),
+        ("ratio".into(), "1.5".into(), "must be at most 1".into())
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "level={level:3..}")]
+struct OpenEnded {
+    level: i64,
+}
+
+#[test]
+fn open_range_checks_only_its_bound() {
+    assert_eq!(OpenEnded::from_str("level=1000").unwrap().level, 1000);
+    assert!(matches!(
+        OpenEnded::from_str("level=2"),
+        Err(TemplateError::OutOfRange { .. })
+    ));
+}
@@ -0,0 +1,91 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `min`/`max` and `min_len`/`max_len` reject values outside the bounds with `TemplateError::OutOfRange`.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{user}@{host}:{port} ratio={ratio}")]
+struct Endpoint {
+    #[templatia(min_len = 1, max_len = 8)]
+    user: String,
+    host: String,
+    #[templatia(min = 1, max = 65535)]
+    port: u32,
+    #[templatia(min = -1.0, max = 1.0)]
+    ratio: f64,
+}
+
+#[test]
+fn values_within_bounds_parse() {
+    let endpoint = Endpoint::from_str("admin@localhost:65535 ratio=-1").unwrap();
+    assert_eq!(endpoint.port, 65535);
+    assert_eq!(endpoint.ratio, -1.0);
+    assert_eq!(
+        Endpoint::from_str(&endpoint.render_string()).unwrap(),
+        endpoint
+    );
+}
+
+#[test]
+fn number_above_max_is_out_of_range() {
+    match Endpoint::from_str("admin@localhost:70000 ratio=0") {
+        Err(TemplateError::OutOfRange {
+            placeholder,
+            value,
+            constraint,
+        }) => {
+            assert_eq!(placeholder, "port");
+            assert_eq!(value, "70000");
+            assert_eq!(constraint, "must be at most 65535");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn number_below_min_is_out_of_range() {
+    assert!(matches!(
+        Endpoint::from_str("admin@localhost:0 ratio=0"),
+        Err(TemplateError::OutOfRange { constraint, .. }) if constraint == "must be at least 1"
+    ));
+    assert!(matches!(
+        Endpoint::from_str("admin@localhost:80 ratio=1.5"),
+        Err(TemplateError::OutOfRange { placeholder, .. }) if placeholder == "ratio"
+    ));
+}
+
+#[test]
+fn string_length_is_counted_in_characters() {
+    assert!(Endpoint::from_str("ユーザー@localhost:80 ratio=0").is_ok());
+    assert!(matches!(
+        Endpoint::from_str("administrator@localhost:80 ratio=0"),
+        Err(TemplateError::OutOfRange { constraint, .. }) if constraint == "must have at most 8 characters"
+    ));
+    assert!(matches!(
+        Endpoint::from_str("@localhost:80 ratio=0"),
+        Err(TemplateError::OutOfRange { constraint, .. }) if constraint == "must have at least 1 characters"
+    ));
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "levels={levels} limit={limit}")]
+struct Levels {
+    #[templatia(max = 9)]
+    levels: Vec<u8>,
+    #[templatia(min = 10)]
+    limit: Option<u64>,
+}
+
+#[test]
+fn bounds_apply_to_each_value() {
+    assert!(Levels::from_str("levels=1,9 limit=").is_ok());
+    assert!(matches!(
+        Levels::from_str("levels=1,12,3 limit=10"),
+        Err(TemplateError::OutOfRange { value, .. }) if value == "12"
+    ));
+    assert!(matches!(
+        Levels::from_str("levels=1 limit=5"),
+        Err(TemplateError::OutOfRange { placeholder, .. }) if placeholder == "limit"
+    ));
+}
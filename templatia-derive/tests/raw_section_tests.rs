@@ -0,0 +1,44 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {% raw %}...{% endraw %}: the text of the section is a literal, with braces kept as written.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = r#"{% raw %}{"id": {% endraw %}{id}{% raw %}, "tags": [1, 2], "f": (x|y)}{% endraw %}"#
+)]
+struct Payload {
+    id: u32,
+}
+
+#[test]
+fn raw_text_renders_as_written() {
+    let payload = Payload { id: 7 };
+    assert_eq!(
+        payload.render_string(),
+        r#"{"id": 7, "tags": [1, 2], "f": (x|y)}"#
+    );
+}
+
+#[test]
+fn raw_text_is_matched_literally_when_parsing() {
+    assert_eq!(
+        Payload::from_str(r#"{"id": 42, "tags": [1, 2], "f": (x|y)}"#).unwrap(),
+        Payload { id: 42 }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "fn {name}() {%raw%}{ {placeholder} }{%endraw%}")]
+struct Code {
+    name: String,
+}
+
+#[test]
+fn tags_without_spaces_are_accepted() {
+    let code = Code {
+        name: "main".into(),
+    };
+    assert_eq!(code.render_string(), "fn main() { {placeholder} }");
+    assert_eq!(Code::from_str("fn main() { {placeholder} }").unwrap(), code);
+}
@@ -0,0 +1,106 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `Option<Box<Self>>` inside an optional group [...] allows recursive, linked-list style templates.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{value}[ -> {next}]")]
+struct Node {
+    value: u32,
+    next: Option<Box<Node>>,
+}
+
+fn list(values: &[u32]) -> Option<Box<Node>> {
+    values.split_first().map(|(value, rest)| {
+        Box::new(Node {
+            value: *value,
+            next: list(rest),
+        })
+    })
+}
+
+#[test]
+fn recursive_struct_renders_every_node() {
+    let head = list(&[1, 2, 3]).unwrap();
+    assert_eq!(head.render_string(), "1 -> 2 -> 3");
+}
+
+#[test]
+fn recursive_struct_renders_without_group_when_none() {
+    let single = Node {
+        value: 7,
+        next: None,
+    };
+    assert_eq!(single.render_string(), "7");
+}
+
+#[test]
+fn recursive_struct_parses_every_node() {
+    assert_eq!(
+        Node::from_str("1 -> 2 -> 3").unwrap(),
+        *list(&[1, 2, 3]).unwrap()
+    );
+    assert_eq!(Node::from_str("42").unwrap(), *list(&[42]).unwrap());
+}
+
+#[test]
+fn recursive_struct_reports_nested_errors() {
+    let err = Node::from_str("1 -> x").expect_err("nested value is not a number");
+    match err {
+        TemplateError::Include { placeholder, .. } => assert_eq!(placeholder, "next"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn recursive_struct_written_with_self() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{name}[/{child}]")]
+    struct Path {
+        name: String,
+        child: Option<Box<Self>>,
+    }
+
+    let path = Path::from_str("usr/local/bin").unwrap();
+    assert_eq!(path.name, "usr");
+    assert_eq!(path.child.as_ref().unwrap().name, "local");
+    assert_eq!(path.render_string(), "usr/local/bin");
+}
+
+#[test]
+fn optional_group_followed_by_literal() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{host}[:{port}]/index")]
+    struct Url {
+        host: String,
+        port: Option<u16>,
+    }
+
+    let with_port = Url {
+        host: "example.com".into(),
+        port: Some(8080),
+    };
+    assert_eq!(with_port.render_string(), "example.com:8080/index");
+    assert_eq!(Url::from_str("example.com:8080/index").unwrap(), with_port);
+
+    let without_port = Url {
+        host: "example.com".into(),
+        port: None,
+    };
+    assert_eq!(without_port.render_string(), "example.com/index");
+    assert_eq!(Url::from_str("example.com/index").unwrap(), without_port);
+}
+
+#[test]
+fn brackets_without_option_placeholders_stay_literal() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "[{id}]")]
+    struct Tag {
+        id: u8,
+    }
+
+    let tag = Tag { id: 3 };
+    assert_eq!(tag.render_string(), "[3]");
+    assert_eq!(Tag::from_str("[3]").unwrap(), tag);
+}
@@ -0,0 +1,65 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(rename_all = "...")]` on structs renames the keys of the default template.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(rename_all = "kebab-case")]
+struct Pool {
+    max_connections: u32,
+    idle_timeout_secs: u64,
+    #[templatia(rename = "name")]
+    pool_name: String,
+}
+
+#[test]
+fn default_template_keys_follow_the_rule() {
+    let pool = Pool {
+        max_connections: 16,
+        idle_timeout_secs: 30,
+        pool_name: "main".to_string(),
+    };
+    assert_eq!(
+        pool.render_string(),
+        "max-connections = 16\nidle-timeout-secs = 30\nname = main"
+    );
+    assert_eq!(Pool::from_str(&pool.render_string()).unwrap(), pool);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(rename_all = "camelCase")]
+struct Camel {
+    max_connections: u32,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(rename_all = "SCREAMING_SNAKE_CASE")]
+struct Screaming {
+    max_connections: u32,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(rename_all = "PascalCase")]
+struct Pascal {
+    max_connections: u32,
+}
+
+#[test]
+fn other_rules_rename_keys() {
+    assert_eq!(
+        Camel { max_connections: 1 }.render_string(),
+        "maxConnections = 1"
+    );
+    assert_eq!(
+        Screaming { max_connections: 1 }.render_string(),
+        "MAX_CONNECTIONS = 1"
+    );
+    assert_eq!(
+        Pascal { max_connections: 1 }.render_string(),
+        "MaxConnections = 1"
+    );
+    assert_eq!(
+        Camel::from_str("maxConnections = 2").unwrap(),
+        Camel { max_connections: 2 }
+    );
+}
@@ -0,0 +1,68 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(rename = "...")]` decouples placeholder names from field names.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{db}@{pool-size}")]
+struct Settings {
+    #[templatia(rename = "db")]
+    database_url: String,
+    #[templatia(rename = "pool-size")]
+    pool_size: u8,
+}
+
+#[test]
+fn renamed_placeholders_render_fields() {
+    let settings = Settings {
+        database_url: "postgres://localhost".into(),
+        pool_size: 4,
+    };
+    assert_eq!(settings.render_string(), "postgres://localhost@4");
+}
+
+#[test]
+fn renamed_placeholders_parse_fields() {
+    let settings = Settings::from_str("sqlite://memory@8").unwrap();
+    assert_eq!(settings.database_url, "sqlite://memory");
+    assert_eq!(settings.pool_size, 8);
+}
+
+#[test]
+fn errors_report_the_renamed_placeholder() {
+    match Settings::from_str("sqlite://memory@many").expect_err("pool size is not a number") {
+        TemplateError::ParseToType { placeholder, .. } => assert_eq!(placeholder, "pool-size"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn default_template_uses_renamed_placeholders() {
+    #[derive(Template, Debug, PartialEq)]
+    struct Defaulted {
+        #[templatia(rename = "url")]
+        database_url: String,
+    }
+
+    let value = Defaulted {
+        database_url: "x".into(),
+    };
+    assert_eq!(value.render_string(), "url = x");
+    assert_eq!(Defaulted::from_str("url = x").unwrap(), value);
+}
+
+#[test]
+fn duplicate_renamed_placeholders_report_the_renamed_name() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{id}/{id}")]
+    struct Twice {
+        #[templatia(rename = "id")]
+        identifier: u32,
+    }
+
+    match Twice::from_str("1/2").expect_err("values differ") {
+        TemplateError::InconsistentValues { placeholder, .. } => assert_eq!(placeholder, "id"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
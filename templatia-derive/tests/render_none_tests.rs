@@ -0,0 +1,56 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(render_none = "...")]` renders None as a token and parses the token back to None.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "port={port};host={host}")]
+struct Server {
+    #[templatia(render_none = "null")]
+    port: Option<u16>,
+    host: Option<String>,
+}
+
+#[test]
+fn field_token_renders_for_none() {
+    let server = Server {
+        port: None,
+        host: None,
+    };
+    assert_eq!(server.render_string(), "port=null;host=");
+    assert_eq!(Server::from_str("port=null;host=").unwrap(), server);
+}
+
+#[test]
+fn values_are_unaffected() {
+    let server = Server::from_str("port=80;host=a").unwrap();
+    assert_eq!(server.port, Some(80));
+    assert_eq!(server.host.as_deref(), Some("a"));
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "a={a} b={b} c={c}", render_none = "-")]
+struct Defaults {
+    a: Option<String>,
+    #[templatia(render_none = "none")]
+    b: Option<u8>,
+    c: Option<f64>,
+}
+
+#[test]
+fn struct_token_is_the_default() {
+    let defaults = Defaults {
+        a: None,
+        b: None,
+        c: Some(1.5),
+    };
+    assert_eq!(defaults.render_string(), "a=- b=none c=1.5");
+    assert_eq!(Defaults::from_str("a=- b=none c=1.5").unwrap(), defaults);
+}
+
+#[test]
+fn string_equal_to_token_parses_as_none() {
+    let parsed = Defaults::from_str("a=- b=1 c=-").unwrap();
+    assert_eq!(parsed.a, None);
+    assert_eq!(parsed.c, None);
+}
@@ -0,0 +1,96 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// render_to writes the same text as render_string into any fmt::Write sink.
+
+use std::fmt::Write;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{host}:{port}", impl_display)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+fn endpoint() -> Endpoint {
+    Endpoint {
+        host: "localhost".to_string(),
+        port: 8080,
+    }
+}
+
+#[test]
+fn render_to_appends_to_an_existing_buffer() {
+    let mut buffer = String::from("endpoint = ");
+    endpoint().render_to(&mut buffer).unwrap();
+    assert_eq!(buffer, "endpoint = localhost:8080");
+}
+
+#[test]
+fn render_to_matches_render_string() {
+    let mut buffer = String::new();
+    endpoint().render_to(&mut buffer).unwrap();
+    assert_eq!(buffer, endpoint().render_string());
+}
+
+#[test]
+fn render_to_accepts_a_trait_object() {
+    let mut buffer = String::new();
+    let sink: &mut dyn Write = &mut buffer;
+    endpoint().render_to(sink).unwrap();
+    assert_eq!(buffer, "localhost:8080");
+}
+
+#[test]
+fn display_writes_through_render_to() {
+    assert_eq!(format!("[{}]", endpoint()), "[localhost:8080]");
+}
+
+/// A fixed-capacity sink, which fails once the rendered text doesn't fit.
+struct Bounded {
+    text: String,
+    capacity: usize,
+}
+
+impl Write for Bounded {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if self.text.len() + s.len() > self.capacity {
+            return Err(std::fmt::Error);
+        }
+        self.text.push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn render_to_reports_a_failing_sink() {
+    let mut fits = Bounded {
+        text: String::new(),
+        capacity: 32,
+    };
+    endpoint().render_to(&mut fits).unwrap();
+    assert_eq!(fits.text, "localhost:8080");
+
+    let mut too_small = Bounded {
+        text: String::new(),
+        capacity: 4,
+    };
+    assert!(endpoint().render_to(&mut too_small).is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name}@{host}")]
+struct Address<'a> {
+    name: &'a str,
+    host: &'a str,
+}
+
+#[test]
+fn borrowed_templates_render_to_a_sink() {
+    let address = Address {
+        name: "alice",
+        host: "example.com",
+    };
+    let mut buffer = String::new();
+    address.render_to(&mut buffer).unwrap();
+    assert_eq!(buffer, "alice@example.com");
+}
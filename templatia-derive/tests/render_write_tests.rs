@@ -0,0 +1,83 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// render_write streams the rendered text into an io::Write sink.
+
+use std::io::Write;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[server]\nhost = {host}\nport = {port}\n")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+fn server() -> Server {
+    Server {
+        host: "localhost".to_string(),
+        port: 8080,
+    }
+}
+
+#[test]
+fn render_write_writes_the_rendered_bytes() {
+    let mut file = Vec::new();
+    server().render_write(&mut file).unwrap();
+    assert_eq!(file, server().render_string().into_bytes());
+}
+
+#[test]
+fn render_write_accepts_a_trait_object() {
+    let mut file = Vec::new();
+    let sink: &mut dyn Write = &mut file;
+    server().render_write(sink).unwrap();
+    assert_eq!(file, b"[server]\nhost = localhost\nport = 8080\n");
+}
+
+/// Records every write, so that the streaming into the sink is observable.
+#[derive(Default)]
+struct Recorder {
+    writes: Vec<Vec<u8>>,
+}
+
+impl Write for Recorder {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writes.push(buf.to_vec());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn render_write_streams_pieces_without_the_whole_document() {
+    let mut recorder = Recorder::default();
+    server().render_write(&mut recorder).unwrap();
+    assert!(recorder.writes.len() > 1);
+    assert_eq!(
+        recorder.writes.concat(),
+        server().render_string().into_bytes()
+    );
+}
+
+/// Fails every write with the given kind.
+struct Broken(std::io::ErrorKind);
+
+impl Write for Broken {
+    fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+        Err(self.0.into())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn render_write_returns_the_error_of_the_sink() {
+    let err = server()
+        .render_write(&mut Broken(std::io::ErrorKind::BrokenPipe))
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+}
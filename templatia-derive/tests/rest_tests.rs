@@ -0,0 +1,48 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(rest)]` makes the final placeholder capture all remaining input.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "header: {meta}\n{body}")]
+struct Document {
+    meta: String,
+    #[templatia(rest)]
+    body: String,
+}
+
+#[test]
+fn rest_captures_newlines_and_literals() {
+    let document = Document {
+        meta: "v1".to_string(),
+        body: "first line\nheader: nested\n\nlast line\n".to_string(),
+    };
+    let rendered = document.render_string();
+    assert_eq!(
+        rendered,
+        "header: v1\nfirst line\nheader: nested\n\nlast line\n"
+    );
+    assert_eq!(Document::from_str(&rendered).unwrap(), document);
+}
+
+#[test]
+fn rest_captures_empty_input() {
+    let document = Document::from_str("header: v2\n").unwrap();
+    assert_eq!(document.meta, "v2");
+    assert_eq!(document.body, "");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "cmd={cmd} args={args}")]
+struct Command {
+    cmd: String,
+    #[templatia(rest, separator = " ")]
+    args: Vec<String>,
+}
+
+#[test]
+fn rest_collection_keeps_every_element() {
+    let command = Command::from_str("cmd=grep args=-r args= .").unwrap();
+    assert_eq!(command.cmd, "grep");
+    assert_eq!(command.args, vec!["-r", "args=", "."]);
+}
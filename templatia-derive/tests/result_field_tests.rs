@@ -0,0 +1,84 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Result<T, E> fields render their value after an ok or err prefix and parse back into that variant.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "status={status} code={code}")]
+struct Response {
+    status: Result<u16, String>,
+    code: u8,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "outcome={outcome}")]
+struct Custom {
+    #[templatia(ok_prefix = "", err_prefix = "!")]
+    outcome: Result<u32, String>,
+}
+
+#[test]
+fn ok_and_err_render_with_default_prefixes() {
+    let ok = Response {
+        status: Ok(200),
+        code: 1,
+    };
+    let err = Response {
+        status: Err("timeout".into()),
+        code: 2,
+    };
+    assert_eq!(ok.render_string(), "status=ok:200 code=1");
+    assert_eq!(err.render_string(), "status=err:timeout code=2");
+    assert_eq!(Response::from_str(&ok.render_string()).unwrap(), ok);
+    assert_eq!(Response::from_str(&err.render_string()).unwrap(), err);
+}
+
+#[test]
+fn values_without_a_prefix_fail_to_parse() {
+    assert!(matches!(
+        Response::from_str("status=200 code=1"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+    assert!(matches!(
+        Response::from_str("status=ok:abc code=1"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+}
+
+#[test]
+fn custom_prefixes_try_the_longer_one_first() {
+    let ok = Custom { outcome: Ok(7) };
+    let err = Custom {
+        outcome: Err("boom".into()),
+    };
+    assert_eq!(ok.render_string(), "outcome=7");
+    assert_eq!(err.render_string(), "outcome=!boom");
+    assert_eq!(Custom::from_str("outcome=7").unwrap(), ok);
+    assert_eq!(Custom::from_str("outcome=!boom").unwrap(), err);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "r={r};")]
+struct Terminated {
+    r: Result<String, String>,
+}
+
+#[test]
+fn value_containing_the_next_literal_fails_as_unexpected_input() {
+    let value = Terminated {
+        r: Err(",;;\\".into()),
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, r"r=err:,;;\;");
+    match Terminated::from_str(&rendered) {
+        Err(TemplateError::UnexpectedInput {
+            expected_next_literal,
+            remaining_text,
+        }) => {
+            assert_eq!(expected_next_literal, "");
+            assert_eq!(remaining_text, r";\;");
+        }
+        other => panic!("expected UnexpectedInput, got {:?}", other),
+    }
+}
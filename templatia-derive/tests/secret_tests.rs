@@ -0,0 +1,58 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(secret)]` renders a mask instead of the value while parsing reads the real value.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "user={user} password={password} token={token}")]
+struct Credentials {
+    user: String,
+    #[templatia(secret)]
+    password: String,
+    #[templatia(secret = "<redacted>")]
+    token: u64,
+}
+
+#[test]
+fn secret_renders_default_and_custom_masks() {
+    let credentials = Credentials {
+        user: "admin".to_string(),
+        password: "hunter2".to_string(),
+        token: 1234,
+    };
+    assert_eq!(
+        credentials.render_string(),
+        "user=admin password=*** token=<redacted>"
+    );
+}
+
+#[test]
+fn secret_parses_real_value() {
+    let credentials = Credentials::from_str("user=admin password=hunter2 token=1234").unwrap();
+    assert_eq!(credentials.password, "hunter2");
+    assert_eq!(credentials.token, 1234);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "key={key}[ (backup {backup})]")]
+struct Keys {
+    #[templatia(secret)]
+    key: Option<String>,
+    #[templatia(secret)]
+    backup: Option<String>,
+}
+
+#[test]
+fn secret_option_renders_mask_only_when_present() {
+    let present = Keys {
+        key: Some("abc".to_string()),
+        backup: Some("def".to_string()),
+    };
+    assert_eq!(present.render_string(), "key=*** (backup ***)");
+
+    let absent = Keys {
+        key: None,
+        backup: None,
+    };
+    assert_eq!(absent.render_string(), "key=");
+}
@@ -0,0 +1,115 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// semver::Version fields are scanned up to the longest valid version before the next literal.
+
+use semver::Version;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "app v{version} built {date}")]
+struct Banner {
+    version: semver::Version,
+    date: String,
+}
+
+#[test]
+fn version_round_trips() {
+    let banner = Banner {
+        version: Version::parse("1.4.0-rc.1+build.5").unwrap(),
+        date: "2026-10-18".to_string(),
+    };
+    let rendered = banner.render_string();
+    assert_eq!(rendered, "app v1.4.0-rc.1+build.5 built 2026-10-18");
+    assert_eq!(Banner::from_str(&rendered).unwrap(), banner);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{version}.{extension}")]
+struct Archive {
+    version: semver::Version,
+    extension: String,
+}
+
+#[test]
+fn version_contains_the_literal_after_it() {
+    let archive = Archive::from_str("1.2.3.zip").unwrap();
+    assert_eq!(archive.version, Version::new(1, 2, 3));
+    assert_eq!(archive.extension, "zip");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "app-{version}-{os}")]
+struct Asset {
+    version: semver::Version,
+    os: String,
+}
+
+#[test]
+fn version_stops_where_the_next_literal_follows() {
+    let asset = Asset::from_str("app-1.2.3-linux").unwrap();
+    assert_eq!(asset.version, Version::new(1, 2, 3));
+    assert_eq!(asset.os, "linux");
+
+    let asset = Asset::from_str("app-2.0.0-beta.1-windows").unwrap();
+    assert_eq!(asset.version, Version::parse("2.0.0-beta.1").unwrap());
+    assert_eq!(asset.os, "windows");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "min={min};max={max}")]
+struct Range {
+    min: Option<semver::Version>,
+    max: Option<::semver::Version>,
+}
+
+#[test]
+fn optional_versions_round_trip() {
+    let range = Range {
+        min: Some(Version::new(1, 0, 0)),
+        max: None,
+    };
+    let rendered = range.render_string();
+    assert_eq!(rendered, "min=1.0.0;max=");
+    assert_eq!(Range::from_str(&rendered).unwrap(), range);
+}
+
+#[test]
+fn invalid_version_is_a_parse_error() {
+    assert!(Banner::from_str("app v1.x built today").is_err());
+}
+
+mod own {
+    use std::fmt;
+    use std::str::FromStr;
+    use templatia::Template;
+
+    /// A type of the user's own that is named `Version` too.
+    #[derive(Debug, PartialEq)]
+    pub struct Version(pub String);
+
+    impl fmt::Display for Version {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+
+    impl FromStr for Version {
+        type Err = std::convert::Infallible;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Ok(Version(s.to_string()))
+        }
+    }
+
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "release {version};")]
+    pub struct Release {
+        pub version: Version,
+    }
+}
+
+#[test]
+fn other_types_named_version_are_captured_up_to_the_next_literal() {
+    let release = own::Release::from_str("release spring 2026;").unwrap();
+    assert_eq!(release.version, own::Version("spring 2026".to_string()));
+    assert_eq!(release.render_string(), "release spring 2026;");
+}
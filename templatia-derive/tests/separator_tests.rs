@@ -0,0 +1,56 @@
+use std::collections::{BTreeSet, HashSet};
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(separator = "...")]` changes the delimiter of collection elements per field or per struct.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "hosts={hosts} ports={ports}")]
+struct Cluster {
+    #[templatia(separator = ";")]
+    hosts: Vec<String>,
+    ports: Vec<u16>,
+}
+
+#[test]
+fn field_separator_renders_and_parses() {
+    let cluster = Cluster {
+        hosts: vec!["a".to_string(), "b".to_string()],
+        ports: vec![80, 443],
+    };
+    assert_eq!(cluster.render_string(), "hosts=a;b ports=80,443");
+    assert_eq!(
+        Cluster::from_str("hosts=a;b ports=80,443").unwrap(),
+        cluster
+    );
+}
+
+#[test]
+fn field_separator_keeps_default_commas_in_elements() {
+    let cluster = Cluster::from_str("hosts=a,b;c ports=1").unwrap();
+    assert_eq!(cluster.hosts, vec!["a,b".to_string(), "c".to_string()]);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "tags={tags}&ids={ids}&ord={ord}", separator = " | ")]
+struct Labels {
+    tags: HashSet<String>,
+    #[templatia(separator = "+")]
+    ids: Vec<u32>,
+    ord: BTreeSet<i32>,
+}
+
+#[test]
+fn struct_separator_is_the_default_for_all_fields() {
+    let labels = Labels::from_str("tags=red | blue&ids=1+2&ord=3 | 1").unwrap();
+    assert_eq!(labels.tags.len(), 2);
+    assert_eq!(labels.ids, vec![1, 2]);
+    assert_eq!(labels.ord, BTreeSet::from([1, 3]));
+
+    let ordered = Labels {
+        tags: HashSet::new(),
+        ids: vec![5],
+        ord: BTreeSet::from([2, 1]),
+    };
+    assert_eq!(ordered.render_string(), "tags=&ids=5&ord=1 | 2");
+}
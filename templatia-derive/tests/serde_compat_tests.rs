@@ -0,0 +1,66 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// serde_compat: the default template keys follow serde's rename attributes.
+
+use serde::Serialize;
+use templatia::prelude::*;
+
+#[derive(Template, Serialize, Debug, PartialEq, Default)]
+#[templatia(serde_compat)]
+#[serde(rename_all = "kebab-case")]
+struct Server {
+    host_name: String,
+    #[serde(rename = "listen")]
+    port: u16,
+}
+
+#[test]
+fn default_template_uses_serde_names() {
+    let server = Server {
+        host_name: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(
+        server.render_string(),
+        "host-name = localhost\nlisten = 8080"
+    );
+    assert_eq!(
+        Server::from_str("host-name = localhost\nlisten = 8080").unwrap(),
+        server
+    );
+}
+
+#[derive(Template, Serialize, Debug, PartialEq)]
+#[templatia(serde_compat, rename_all = "UPPERCASE")]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Precedence {
+    #[templatia(rename = "first")]
+    #[serde(rename = "ignored")]
+    alpha_value: u8,
+    #[serde(rename(serialize = "out", deserialize = "in"), default)]
+    beta_value: u8,
+    gamma_value: u8,
+}
+
+#[test]
+fn templatia_names_take_precedence() {
+    let value = Precedence {
+        alpha_value: 1,
+        beta_value: 2,
+        gamma_value: 3,
+    };
+    assert_eq!(value.render_string(), "first = 1\nout = 2\nGAMMA_VALUE = 3");
+}
+
+#[derive(Template, Serialize, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+struct WithoutCompat {
+    host_name: String,
+}
+
+#[test]
+fn serde_attributes_are_ignored_without_the_option() {
+    let value = WithoutCompat {
+        host_name: "a".into(),
+    };
+    assert_eq!(value.render_string(), "host_name = a");
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+use std::str::FromStr;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(skip_render)]` and `#[templatia(skip_parse)]` limit a field to one direction.
+
+/// Parsed from text but has no `Display`.
+#[derive(Debug, PartialEq)]
+struct Token(String);
+
+impl FromStr for Token {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Token(s.to_string()))
+    }
+}
+
+/// Rendered to text but has no `FromStr`.
+#[derive(Debug, Default, PartialEq)]
+struct Stamp(u32);
+
+impl fmt::Display for Stamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "token={token} stamp={stamp} name={name}")]
+struct Message {
+    #[templatia(skip_render)]
+    token: Token,
+    #[templatia(skip_parse)]
+    stamp: Stamp,
+    name: String,
+}
+
+#[test]
+fn skip_render_placeholder_renders_nothing() {
+    let message = Message {
+        token: Token("abc".to_string()),
+        stamp: Stamp(7),
+        name: "hello".to_string(),
+    };
+    assert_eq!(message.render_string(), "token= stamp=#7 name=hello");
+}
+
+#[test]
+fn skip_parse_placeholder_is_consumed_and_defaulted() {
+    let message = Message::from_str("token=abc stamp=#42 name=hello").unwrap();
+    assert_eq!(
+        message,
+        Message {
+            token: Token("abc".to_string()),
+            stamp: Stamp::default(),
+            name: "hello".to_string(),
+        }
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "id={id} note={note} tags={tags}")]
+struct Record {
+    id: u32,
+    #[templatia(skip_parse, default = "Some(\"none\".to_string())")]
+    note: Option<String>,
+    #[templatia(skip_parse)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn skip_parse_uses_default_expression() {
+    let record = Record::from_str("id=3 note=anything tags=a,b").unwrap();
+    assert_eq!(
+        record,
+        Record {
+            id: 3,
+            note: Some("none".to_string()),
+            tags: Vec::new(),
+        }
+    );
+}
@@ -0,0 +1,45 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// With the `smallvec` and `arrayvec` features, SmallVec and ArrayVec fields are sequences like Vec.
+
+use arrayvec::ArrayVec;
+use smallvec::{SmallVec, smallvec};
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "small={small} fixed={fixed}")]
+struct Buffers {
+    small: SmallVec<[u8; 4]>,
+    #[templatia(separator = ";")]
+    fixed: ArrayVec<String, 2>,
+}
+
+#[test]
+fn sequences_round_trip() {
+    let mut fixed = ArrayVec::new();
+    fixed.push("a".to_string());
+    fixed.push("b".to_string());
+    let buffers = Buffers {
+        small: smallvec![1, 2, 3, 4, 5],
+        fixed,
+    };
+    let rendered = buffers.render_string();
+    assert_eq!(rendered, "small=1,2,3,4,5 fixed=a;b");
+    assert_eq!(Buffers::from_str(&rendered).unwrap(), buffers);
+}
+
+#[test]
+fn values_beyond_the_capacity_are_out_of_range() {
+    match Buffers::from_str("small= fixed=a;b;c") {
+        Err(TemplateError::OutOfRange {
+            placeholder,
+            value,
+            constraint,
+        }) => {
+            assert_eq!(placeholder, "fixed");
+            assert_eq!(value, "c");
+            assert_eq!(constraint, "must have at most 2 elements");
+        }
+        other => panic!("expected OutOfRange, got {:?}", other),
+    }
+}
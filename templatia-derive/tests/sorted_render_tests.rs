@@ -0,0 +1,87 @@
+use std::collections::{HashMap, HashSet};
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// HashSet and HashMap fields render in a sorted, deterministic order.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "tags={tags}")]
+struct Tags {
+    tags: HashSet<String>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "ids={ids}")]
+struct Ids {
+    #[templatia(separator = ";")]
+    ids: HashSet<u32>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "env={env}")]
+struct Env {
+    env: HashMap<String, u32>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{tags}|{tags}")]
+struct Twice {
+    tags: HashSet<String>,
+}
+
+#[test]
+fn hash_set_renders_sorted() {
+    let tags = Tags {
+        tags: ["zeta", "alpha", "mid", "beta"]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+    };
+    assert_eq!(tags.render_string(), "tags=alpha,beta,mid,zeta");
+}
+
+#[test]
+fn hash_set_sorts_by_rendered_text() {
+    let ids = Ids {
+        ids: HashSet::from([10, 9, 100]),
+    };
+    assert_eq!(ids.render_string(), "ids=10;100;9");
+}
+
+#[test]
+fn hash_set_render_is_stable_across_instances() {
+    let words = ["one", "two", "three", "four", "five", "six", "seven"];
+    let expected = Tags {
+        tags: words.iter().map(|w| w.to_string()).collect(),
+    }
+    .render_string();
+    for _ in 0..16 {
+        let tags = Tags {
+            tags: words.iter().rev().map(|w| w.to_string()).collect(),
+        };
+        assert_eq!(tags.render_string(), expected);
+    }
+}
+
+#[test]
+fn hash_map_renders_sorted() {
+    let env = Env {
+        env: HashMap::from([
+            ("b".to_string(), 2),
+            ("a".to_string(), 1),
+            ("c".to_string(), 3),
+        ]),
+    };
+    assert_eq!(env.render_string(), "env=a=1,b=2,c=3");
+}
+
+#[test]
+fn duplicate_hash_set_placeholders_round_trip() {
+    let twice = Twice {
+        tags: ["x", "y", "z"].into_iter().map(String::from).collect(),
+    };
+    let rendered = twice.render_string();
+    assert_eq!(rendered, "x,y,z|x,y,z");
+    assert_eq!(Twice::from_str(&rendered).unwrap(), twice);
+    assert_eq!(Twice::from_str("z,y,x|x,y,z").unwrap(), twice);
+}
@@ -0,0 +1,58 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Sequences of Option<T> render None as an empty slot between separators and parse it back to None.
+
+use std::collections::VecDeque;
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "values={values} names={names}")]
+struct Sparse {
+    values: Vec<Option<u32>>,
+    #[templatia(separator = ";")]
+    names: VecDeque<Option<String>>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "a={values} b={values}")]
+struct Repeated {
+    values: Vec<Option<i8>>,
+}
+
+#[test]
+fn none_elements_are_empty_slots() {
+    let value = Sparse {
+        values: vec![Some(1), None, Some(3)],
+        names: VecDeque::from([None, Some("x".to_string()), None]),
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "values=1,,3 names=;x;");
+    assert_eq!(Sparse::from_str(&rendered).unwrap(), value);
+}
+
+#[test]
+fn empty_segment_is_an_empty_collection() {
+    let value = Sparse::from_str("values= names=").unwrap();
+    assert!(value.values.is_empty());
+    assert!(value.names.is_empty());
+}
+
+#[test]
+fn present_elements_are_parsed() {
+    assert!(matches!(
+        Sparse::from_str("values=1,x names="),
+        Err(TemplateError::ParseToType { .. })
+    ));
+}
+
+#[test]
+fn repeated_sparse_placeholders_must_agree() {
+    assert_eq!(
+        Repeated::from_str("a=,1 b=,1").unwrap().values,
+        vec![None, Some(1)]
+    );
+    assert!(matches!(
+        Repeated::from_str("a=,1 b=1,"),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+}
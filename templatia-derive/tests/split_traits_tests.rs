@@ -0,0 +1,130 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// The derive bounds RenderTemplate and ParseTemplate separately, with render_only and parse_only.
+
+use templatia::prelude::*;
+
+/// Displays as its name but can't be parsed.
+#[derive(Debug, PartialEq)]
+struct Label(&'static str);
+
+impl std::fmt::Display for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Parses from a number but can't be displayed.
+#[derive(Debug, PartialEq)]
+struct Level(u8);
+
+impl std::str::FromStr for Level {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Level)
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{name}: {value}")]
+struct Entry<T> {
+    name: String,
+    value: T,
+}
+
+#[derive(Template)]
+#[templatia(render_only, template = "[{label}] {message}")]
+struct Report {
+    label: Label,
+    message: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(parse_only, template = "level={level} tag={tag}")]
+struct Threshold {
+    level: Level,
+    tag: String,
+}
+
+fn render_all<T: RenderTemplate>(items: &[T]) -> Vec<String> {
+    items.iter().map(RenderTemplate::render_string).collect()
+}
+
+#[test]
+fn generic_struct_renders_with_non_from_str_parameter() {
+    let entry = Entry {
+        name: "kind".to_string(),
+        value: Label("error"),
+    };
+    assert_eq!(entry.render_string(), "kind: error");
+}
+
+#[test]
+fn generic_struct_parses_with_non_display_parameter() {
+    let entry = <Entry<Level> as ParseTemplate>::from_str("retries: 3").unwrap();
+    assert_eq!(
+        entry,
+        Entry {
+            name: "retries".to_string(),
+            value: Level(3),
+        }
+    );
+}
+
+#[test]
+fn generic_struct_with_both_bounds_implements_template() {
+    fn round_trip<T: Template>(value: &T) -> Result<T, T::Error> {
+        T::from_str(&value.render_string())
+    }
+
+    let entry = Entry {
+        name: "port".to_string(),
+        value: 8080u16,
+    };
+    assert_eq!(round_trip(&entry).unwrap(), entry);
+}
+
+#[test]
+fn render_only_renders_fields_without_from_str() {
+    let report = Report {
+        label: Label("warn"),
+        message: "disk almost full".to_string(),
+    };
+    assert_eq!(report.render_string(), "[warn] disk almost full");
+}
+
+#[test]
+fn parse_only_parses_fields_without_display() {
+    let threshold = Threshold::from_str("level=4 tag=cpu").unwrap();
+    assert_eq!(
+        threshold,
+        Threshold {
+            level: Level(4),
+            tag: "cpu".to_string(),
+        }
+    );
+}
+
+#[test]
+fn parse_only_reports_parse_errors() {
+    let result = Threshold::from_str("level=high tag=cpu");
+    assert!(matches!(
+        result,
+        Err(templatia::TemplateError::ParseToType { .. })
+    ));
+}
+
+#[test]
+fn functions_can_be_bounded_by_render_template_alone() {
+    let reports = [
+        Report {
+            label: Label("info"),
+            message: "started".to_string(),
+        },
+        Report {
+            label: Label("info"),
+            message: "stopped".to_string(),
+        },
+    ];
+    assert_eq!(render_all(&reports), ["[info] started", "[info] stopped"]);
+}
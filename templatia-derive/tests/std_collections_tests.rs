@@ -0,0 +1,77 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// VecDeque<T>, LinkedList<T>, and BinaryHeap<T> fields are collections like Vec<T>.
+
+use std::collections::{BinaryHeap, LinkedList, VecDeque};
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "queue={queue} list={list}")]
+struct Sequences {
+    queue: VecDeque<u32>,
+    #[templatia(separator = ";")]
+    list: LinkedList<String>,
+}
+
+#[derive(Template, Debug)]
+#[templatia(template = "priorities={priorities}")]
+struct Heap {
+    #[templatia(max = 9)]
+    priorities: BinaryHeap<u8>,
+}
+
+#[derive(Template, Debug)]
+#[templatia(template = "a={values} b={values}")]
+struct RepeatedHeap {
+    values: BinaryHeap<i32>,
+}
+
+#[test]
+fn sequences_keep_their_order() {
+    let value = Sequences {
+        queue: VecDeque::from([3, 1, 2]),
+        list: LinkedList::from(["x".to_string(), "y".to_string()]),
+    };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "queue=3,1,2 list=x;y");
+    assert_eq!(Sequences::from_str(&rendered).unwrap(), value);
+}
+
+#[test]
+fn empty_segments_are_empty_collections() {
+    let value = Sequences::from_str("queue= list=").unwrap();
+    assert!(value.queue.is_empty());
+    assert!(value.list.is_empty());
+}
+
+#[test]
+fn binary_heap_renders_in_ascending_order() {
+    let heap = Heap {
+        priorities: BinaryHeap::from([5, 1, 9, 3]),
+    };
+    assert_eq!(heap.render_string(), "priorities=1,3,5,9");
+    let parsed = Heap::from_str("priorities=9,1,5").unwrap();
+    assert_eq!(parsed.priorities.into_sorted_vec(), vec![1, 5, 9]);
+}
+
+#[test]
+fn binary_heap_values_are_checked() {
+    assert!(matches!(
+        Heap::from_str("priorities=1,x"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+    assert!(matches!(
+        Heap::from_str("priorities=1,10"),
+        Err(TemplateError::OutOfRange { .. })
+    ));
+}
+
+#[test]
+fn repeated_heaps_compare_their_values() {
+    let parsed = RepeatedHeap::from_str("a=2,1 b=1,2").unwrap();
+    assert_eq!(parsed.values.into_sorted_vec(), vec![1, 2]);
+    assert!(matches!(
+        RepeatedHeap::from_str("a=1,2 b=1,3"),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+}
@@ -0,0 +1,62 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// impl_display/impl_from_str: std trait impls delegating to the template.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{host}:{port}", impl_display, impl_from_str)]
+struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{value}", impl_display)]
+struct Wrapper<T: std::fmt::Display + std::str::FromStr + PartialEq> {
+    value: T,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "user={name}", impl_display)]
+struct Borrowed<'a> {
+    name: &'a str,
+}
+
+#[test]
+fn display_matches_render_string() {
+    let endpoint = Endpoint {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(format!("{}", endpoint), endpoint.render_string());
+    assert_eq!(endpoint.to_string(), "localhost:8080");
+}
+
+#[test]
+fn parse_uses_the_template() {
+    let endpoint: Endpoint = "db:5432".parse().unwrap();
+    assert_eq!(
+        endpoint,
+        Endpoint {
+            host: "db".into(),
+            port: 5432
+        }
+    );
+}
+
+#[test]
+fn parse_reports_template_errors() {
+    let err = "db:port".parse::<Endpoint>().unwrap_err();
+    assert!(matches!(err, TemplateError::ParseToType { .. }));
+}
+
+#[test]
+fn display_works_with_generics() {
+    assert_eq!(Wrapper { value: 42u8 }.to_string(), "42");
+}
+
+#[test]
+fn display_works_for_borrowed_structs() {
+    assert_eq!(Borrowed { name: "alice" }.to_string(), "user=alice");
+}
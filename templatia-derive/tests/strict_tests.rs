@@ -0,0 +1,56 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// strict: exact matching without empty Option shortcuts.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "LEN={len};TAG={tag};NOTE={note}", strict)]
+struct Frame {
+    len: u32,
+    tag: Option<u8>,
+    note: Option<String>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "LEN={len};TAG={tag}", strict)]
+struct NoneFrame {
+    len: u32,
+    #[templatia(render_none = "-")]
+    tag: Option<u8>,
+}
+
+#[test]
+fn exact_input_parses() {
+    let parsed = Frame::from_str("LEN=3;TAG=7;NOTE=abc").unwrap();
+    assert_eq!(
+        parsed,
+        Frame {
+            len: 3,
+            tag: Some(7),
+            note: Some("abc".into())
+        }
+    );
+}
+
+#[test]
+fn empty_capture_is_not_none() {
+    assert!(Frame::from_str("LEN=3;TAG=;NOTE=abc").is_err());
+    let parsed = Frame::from_str("LEN=3;TAG=7;NOTE=").unwrap();
+    assert_eq!(parsed.note, Some(String::new()));
+}
+
+#[test]
+fn render_none_token_still_means_none() {
+    let value = NoneFrame { len: 1, tag: None };
+    assert_eq!(value.render_string(), "LEN=1;TAG=-");
+    assert_eq!(NoneFrame::from_str("LEN=1;TAG=-").unwrap(), value);
+}
+
+#[test]
+fn trailing_and_surrounding_input_is_rejected() {
+    assert!(
+        Frame::from_str("LEN=3;TAG=7;NOTE=abc\n").is_ok_and(|f| f.note.as_deref() == Some("abc\n"))
+    );
+    assert!(NoneFrame::from_str("LEN=1;TAG=-\n").is_err());
+    assert!(NoneFrame::from_str(" LEN=1;TAG=-").is_err());
+}
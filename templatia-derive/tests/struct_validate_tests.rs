@@ -0,0 +1,81 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Struct-level validate: a function checking the parsed value as a whole after from_str builds it.
+
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "{min}..{max}",
+    alt_template = "{min}-{max}",
+    validate = "Self::check",
+    lossy
+)]
+struct Bounds {
+    min: u32,
+    max: u32,
+}
+
+impl Bounds {
+    fn check(&self) -> Result<(), String> {
+        if self.min <= self.max {
+            Ok(())
+        } else {
+            Err(format!("min {} is greater than max {}", self.min, self.max))
+        }
+    }
+}
+
+fn non_empty(pair: &Pair) -> Result<(), &'static str> {
+    if pair.key.is_empty() {
+        Err("key must not be empty")
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{key}={value}", validate = "non_empty")]
+struct Pair {
+    key: String,
+    value: String,
+}
+
+#[test]
+fn valid_values_parse() {
+    assert_eq!(Bounds::from_str("1..5").unwrap(), Bounds { min: 1, max: 5 });
+}
+
+#[test]
+fn invalid_values_are_reported() {
+    match Bounds::from_str("5..1") {
+        Err(TemplateError::InvalidValue { message }) => {
+            assert_eq!(message, "min 5 is greater than max 1")
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn alt_templates_are_validated() {
+    assert!(matches!(
+        Bounds::from_str("5-1"),
+        Err(TemplateError::InvalidValue { .. })
+    ));
+}
+
+#[test]
+fn free_functions_can_validate() {
+    assert!(Pair::from_str("a=b").is_ok());
+    assert!(matches!(
+        Pair::from_str("=b"),
+        Err(TemplateError::InvalidValue { .. })
+    ));
+}
+
+#[test]
+fn lossy_parse_reports_invalid_values() {
+    let (bounds, errors) = Bounds::from_str_lossy("5..1");
+    assert_eq!(bounds, Bounds { min: 5, max: 1 });
+    assert!(matches!(errors[..], [TemplateError::InvalidValue { .. }]));
+}
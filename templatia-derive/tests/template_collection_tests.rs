@@ -0,0 +1,98 @@
+use std::collections::{LinkedList, VecDeque};
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `{>field}` on a sequence of Template elements renders and parses each element via its own template.
+
+#[derive(Template, Debug, PartialEq, Clone)]
+#[templatia(template = "{key}={value}")]
+struct Entry {
+    key: String,
+    value: u32,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "entries: {>entries}")]
+struct Config {
+    entries: Vec<Entry>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{>entries}] done")]
+struct Custom {
+    #[templatia(separator = "; ")]
+    entries: VecDeque<Entry>,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{>entries}")]
+struct Linked {
+    entries: LinkedList<Entry>,
+}
+
+fn entry(key: &str, value: u32) -> Entry {
+    Entry {
+        key: key.into(),
+        value,
+    }
+}
+
+#[test]
+fn vec_of_templates_renders_each_element() {
+    let config = Config {
+        entries: vec![entry("a", 1), entry("b", 2)],
+    };
+    assert_eq!(config.render_string(), "entries: a=1,b=2");
+}
+
+#[test]
+fn vec_of_templates_parses_each_element() {
+    let parsed = Config::from_str("entries: x=10,y=20,z=30").unwrap();
+    assert_eq!(
+        parsed.entries,
+        vec![entry("x", 10), entry("y", 20), entry("z", 30)]
+    );
+}
+
+#[test]
+fn vec_of_templates_round_trips() {
+    let config = Config {
+        entries: vec![entry("host", 80), entry("port", 443)],
+    };
+    assert_eq!(Config::from_str(&config.render_string()).unwrap(), config);
+}
+
+#[test]
+fn empty_vec_of_templates_renders_and_parses_empty() {
+    let config = Config { entries: vec![] };
+    assert_eq!(config.render_string(), "entries: ");
+    assert_eq!(Config::from_str("entries: ").unwrap(), config);
+}
+
+#[test]
+fn custom_separator_splits_elements() {
+    let custom = Custom {
+        entries: VecDeque::from([entry("a", 1), entry("b", 2)]),
+    };
+    assert_eq!(custom.render_string(), "[a=1; b=2] done");
+    assert_eq!(Custom::from_str("[a=1; b=2] done").unwrap(), custom);
+}
+
+#[test]
+fn linked_list_of_templates_round_trips() {
+    let linked = Linked {
+        entries: LinkedList::from([entry("k", 7)]),
+    };
+    assert_eq!(linked.render_string(), "k=7");
+    assert_eq!(Linked::from_str("k=7").unwrap(), linked);
+}
+
+#[test]
+fn invalid_element_reports_include_error() {
+    let err = Config::from_str("entries: a=1,b=oops").unwrap_err();
+    match err {
+        TemplateError::Include { placeholder, .. } => assert_eq!(placeholder, "entries"),
+        other => panic!("expected Include error, got {:?}", other),
+    }
+}
@@ -0,0 +1,44 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {#- ... -#}: template comments removed at compile time.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{#- connection settings -#}
+host={host}
+    {#- the port the server listens on -#}
+port={port}")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn comment_lines_are_removed() {
+    let server = Server {
+        host: "localhost".to_string(),
+        port: 8080,
+    };
+    let rendered = server.render_string();
+    assert_eq!(rendered, "host=localhost\nport=8080");
+    assert_eq!(Server::from_str(&rendered).unwrap(), server);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "{width}x{#- width by height -#}{height} {#- pixels, {not} a placeholder -#}px"
+)]
+struct Resolution {
+    width: u32,
+    height: u32,
+}
+
+#[test]
+fn inline_comments_join_the_text_around_them() {
+    let resolution = Resolution {
+        width: 1920,
+        height: 1080,
+    };
+    assert_eq!(resolution.render_string(), "1920x1080 px");
+    assert_eq!(Resolution::from_str("1920x1080 px").unwrap(), resolution);
+}
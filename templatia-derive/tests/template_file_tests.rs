@@ -0,0 +1,35 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// template_file reads the template relative to CARGO_MANIFEST_DIR at compile time.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template_file = "tests/templates/server.tpl")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn template_file_is_used_for_render() {
+    let server = Server {
+        host: "localhost".into(),
+        port: 8080,
+    };
+    assert_eq!(
+        server.render_string(),
+        "server:\n  host = localhost\n  port = 8080\n"
+    );
+}
+
+#[test]
+fn template_file_is_used_for_parse() {
+    let parsed = Server::from_str("server:\n  host = example.com\n  port = 443\n").unwrap();
+    assert_eq!(
+        parsed,
+        Server {
+            host: "example.com".into(),
+            port: 443,
+        }
+    );
+}
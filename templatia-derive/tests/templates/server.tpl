@@ -0,0 +1,3 @@
+server:
+  host = {host}
+  port = {port}
@@ -0,0 +1,83 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+use time::macros::{date, datetime, time};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// With the time feature, datetime_format takes a time format description for both directions.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "[{at}] {message}")]
+struct LogLine {
+    #[templatia(datetime_format = "[day]/[month]/[year] [hour]:[minute]:[second]")]
+    at: PrimitiveDateTime,
+    message: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "ts={ts}")]
+struct Stamp {
+    #[templatia(
+        datetime_format = "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+    )]
+    ts: OffsetDateTime,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "due={due}; slots={slots}")]
+struct Schedule {
+    #[templatia(datetime_format = "[year][month][day]")]
+    due: Option<Date>,
+    #[templatia(datetime_format = "[hour].[minute]", separator = " ")]
+    slots: Vec<Time>,
+}
+
+#[test]
+fn format_description_renders_and_parses_primitive_datetime() {
+    let line = LogLine {
+        at: datetime!(2026-10-18 09:30:05),
+        message: "started".into(),
+    };
+    assert_eq!(line.render_string(), "[18/10/2026 09:30:05] started");
+    assert_eq!(
+        LogLine::from_str("[18/10/2026 09:30:05] started").unwrap(),
+        line
+    );
+}
+
+#[test]
+fn offset_datetime_round_trips() {
+    let stamp = Stamp {
+        ts: datetime!(2026-10-18 21:15:00 +09:00),
+    };
+    assert_eq!(stamp.render_string(), "ts=2026-10-18T21:15:00+09:00");
+    assert_eq!(
+        Stamp::from_str("ts=2026-10-18T21:15:00+09:00").unwrap(),
+        stamp
+    );
+}
+
+#[test]
+fn option_and_collection_elements_use_the_format() {
+    let schedule = Schedule {
+        due: Some(date!(2026 - 03 - 07)),
+        slots: vec![time!(09:00), time!(13:30)],
+    };
+    let rendered = schedule.render_string();
+    assert_eq!(rendered, "due=20260307; slots=09.00 13.30");
+    assert_eq!(Schedule::from_str(&rendered).unwrap(), schedule);
+
+    let empty = Schedule::from_str("due=; slots=").unwrap();
+    assert_eq!(empty.due, None);
+    assert!(empty.slots.is_empty());
+}
+
+#[test]
+fn value_not_matching_the_format_is_a_type_error() {
+    let err = LogLine::from_str("[2026-10-18 09:30:05] started").unwrap_err();
+    assert!(
+        matches!(err, TemplateError::ParseToType { .. }),
+        "{:?}",
+        err
+    );
+}
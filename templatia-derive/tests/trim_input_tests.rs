@@ -0,0 +1,54 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// trim_input: whitespace and line breaks around the input are ignored when parsing.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(trim_input)]
+struct Config {
+    host: String,
+    port: u16,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name};", trim_input)]
+struct Named {
+    name: String,
+}
+
+#[test]
+fn final_newline_is_ignored() {
+    let parsed = Config::from_str("host = localhost\nport = 8080\n").unwrap();
+    assert_eq!(
+        parsed,
+        Config {
+            host: "localhost".into(),
+            port: 8080
+        }
+    );
+}
+
+#[test]
+fn final_crlf_is_ignored() {
+    let parsed = Config::from_str("host = localhost\nport = 8080\r\n").unwrap();
+    assert_eq!(parsed.port, 8080);
+}
+
+#[test]
+fn surrounding_blank_space_is_ignored() {
+    let parsed = Named::from_str("\n  name=alice;  \n\n").unwrap();
+    assert_eq!(parsed.name, "alice");
+}
+
+#[test]
+fn input_without_surrounding_whitespace_still_parses() {
+    let value = Named { name: "bob".into() };
+    let rendered = value.render_string();
+    assert_eq!(rendered, "name=bob;");
+    assert_eq!(Named::from_str(&rendered).unwrap(), value);
+}
+
+#[test]
+fn text_after_the_whitespace_is_rejected() {
+    assert!(Named::from_str("name=alice;\nextra").is_err());
+}
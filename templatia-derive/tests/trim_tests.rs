@@ -0,0 +1,68 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(trim)]` trims whitespace around captured values before parsing.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "port ={port};name ={name};tags ={tags}")]
+struct Padded {
+    #[templatia(trim)]
+    port: u16,
+    #[templatia(trim)]
+    name: String,
+    #[templatia(trim)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn trim_accepts_padded_values() {
+    let parsed = Padded::from_str("port =  8080 ;name = web ;tags = a, b ").unwrap();
+    assert_eq!(
+        parsed,
+        Padded {
+            port: 8080,
+            name: "web".to_string(),
+            tags: vec!["a".to_string(), "b".to_string()],
+        }
+    );
+}
+
+#[test]
+fn trim_does_not_change_rendering() {
+    let padded = Padded {
+        port: 80,
+        name: "web".to_string(),
+        tags: vec!["a".to_string()],
+    };
+    assert_eq!(padded.render_string(), "port =80;name =web;tags =a");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "port ={port}")]
+struct Untrimmed {
+    port: u16,
+}
+
+#[test]
+fn without_trim_padding_is_a_type_error() {
+    assert!(Untrimmed::from_str("port = 8080").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "level={level}")]
+struct OptionalLevel {
+    #[templatia(trim)]
+    level: Option<u8>,
+}
+
+#[test]
+fn trim_whitespace_only_option_is_none() {
+    assert_eq!(
+        OptionalLevel::from_str("level=   ").unwrap(),
+        OptionalLevel { level: None }
+    );
+    assert_eq!(
+        OptionalLevel::from_str("level= 3 ").unwrap(),
+        OptionalLevel { level: Some(3) }
+    );
+}
@@ -0,0 +1,26 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Inputs that end before the literals of the template are errors, never panics.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host={host}, port={port};")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn empty_input_is_an_error() {
+    assert!(matches!(
+        Server::from_str(""),
+        Err(TemplateError::UnexpectedInput { .. } | TemplateError::Parse(_))
+    ));
+}
+
+#[test]
+fn input_ending_before_a_later_literal_is_an_error() {
+    assert!(Server::from_str("host=").is_err());
+    assert!(Server::from_str("host=localhost, po").is_err());
+}
@@ -0,0 +1,81 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// {field:type}: type hints checked against the declared field types.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "host={host:String}\nport={port:u16}")]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn hinted_template_round_trips() {
+    let server = Server {
+        host: "localhost".to_string(),
+        port: 8080,
+    };
+    let rendered = server.render_string();
+    assert_eq!(rendered, "host=localhost\nport=8080");
+    assert_eq!(Server::from_str(&rendered).unwrap(), server);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{size:u32}{unit}")]
+struct Size {
+    size: u32,
+    unit: String,
+}
+
+#[test]
+fn integer_hint_allows_a_following_placeholder() {
+    let parsed = Size::from_str("512MiB").unwrap();
+    assert_eq!(
+        parsed,
+        Size {
+            size: 512,
+            unit: "MiB".to_string(),
+        }
+    );
+    assert_eq!(parsed.render_string(), "512MiB");
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{delta:i32}{unit}")]
+struct Offset {
+    delta: i32,
+    unit: char,
+}
+
+#[test]
+fn signed_hint_accepts_a_sign() {
+    assert_eq!(
+        Offset::from_str("-15m").unwrap(),
+        Offset {
+            delta: -15,
+            unit: 'm',
+        }
+    );
+    assert_eq!(Offset::from_str("+3h").unwrap().delta, 3);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "port={port:u16=8080};limit={limit: std::num::NonZeroU32}")]
+struct Limits {
+    port: Option<u16>,
+    limit: std::num::NonZeroU32,
+}
+
+#[test]
+fn hint_names_the_inner_type_and_combines_with_a_default() {
+    let parsed = Limits::from_str("port=;limit=10").unwrap();
+    assert_eq!(parsed.port, Some(8080));
+    assert_eq!(parsed.limit.get(), 10);
+}
+
+#[test]
+fn digits_capture_stops_before_other_characters() {
+    assert!(Size::from_str("MiB").is_err());
+    assert!(Server::from_str("host=a\nport=80x").is_err());
+}
@@ -0,0 +1,54 @@
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// unescape: \n, \t, \u{...} and the other escape sequences in templates become the characters.
+
+use templatia::prelude::*;
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = r"{key}\t{value}\n", unescape)]
+struct Row {
+    key: String,
+    value: u32,
+}
+
+#[test]
+fn escapes_become_control_characters() {
+    let row = Row {
+        key: "answer".into(),
+        value: 42,
+    };
+    assert_eq!(row.render_string(), "answer\t42\n");
+    assert_eq!(Row::from_str("answer\t42\n").unwrap(), row);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = r"\u{2192} {target} \\ {note}\u{0021}", unescape)]
+struct Arrow {
+    target: String,
+    note: String,
+}
+
+#[test]
+fn unicode_escapes_and_backslashes_are_resolved() {
+    let arrow = Arrow {
+        target: "home".into(),
+        note: "done".into(),
+    };
+    assert_eq!(arrow.render_string(), "\u{2192} home \\ done!");
+    assert_eq!(Arrow::from_str("\u{2192} home \\ done!").unwrap(), arrow);
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = r"a={a}\nb={b}")]
+struct Verbatim {
+    a: String,
+    b: String,
+}
+
+#[test]
+fn escapes_are_kept_without_the_option() {
+    let verbatim = Verbatim {
+        a: "1".into(),
+        b: "2".into(),
+    };
+    assert_eq!(verbatim.render_string(), r"a=1\nb=2");
+}
@@ -0,0 +1,50 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Unions parse into the field designated with `active` and render it through an unsafe method.
+
+#[derive(Template, Clone, Copy)]
+#[templatia(template = "bits={bits}", active = "bits", unsafe_union)]
+union Word {
+    bits: u32,
+    bytes: [u8; 4],
+}
+
+#[test]
+fn union_renders_active_field() {
+    let word = Word { bits: 0x0102_0304 };
+    // SAFETY: `bits` was initialized above.
+    assert_eq!(unsafe { word.render_string() }, "bits=16909060");
+}
+
+#[test]
+fn union_parses_into_active_field() {
+    let word = Word::from_str("bits=16909060").unwrap();
+    // SAFETY: Both fields are plain bytes of the same size.
+    assert_eq!(unsafe { word.bytes }, 0x0102_0304u32.to_ne_bytes());
+    assert_eq!(unsafe { word.bits }, 0x0102_0304);
+}
+
+#[test]
+fn union_parse_errors_are_reported() {
+    assert!(matches!(
+        Word::from_str("bits=abc"),
+        Err(TemplateError::ParseToType { ref placeholder, .. }) if placeholder == "bits"
+    ));
+}
+
+#[test]
+fn union_default_template_uses_active_field_only() {
+    #[derive(Template, Clone, Copy)]
+    #[templatia(active = "signed", unsafe_union)]
+    union Number {
+        signed: i64,
+        unsigned: u64,
+    }
+
+    let number = Number::from_str("signed = -1").unwrap();
+    // SAFETY: `from_str` initializes `signed`.
+    assert_eq!(unsafe { number.render_string() }, "signed = -1");
+    assert_eq!(unsafe { number.unsigned }, u64::MAX);
+}
@@ -0,0 +1,53 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(until_eol)]` ends the capture of a placeholder at the end of the current line.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name} id={id}\nuser={user}")]
+struct Entry {
+    #[templatia(until_eol)]
+    name: String,
+    id: u32,
+    user: String,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={name} id={id}\nuser={user}")]
+struct LazyEntry {
+    name: String,
+    id: u32,
+    user: String,
+}
+
+#[test]
+fn until_eol_round_trips() {
+    let entry = Entry {
+        name: "alice".to_string(),
+        id: 1,
+        user: "root".to_string(),
+    };
+    assert_eq!(entry.render_string(), "name=alice id=1\nuser=root");
+    assert_eq!(Entry::from_str(&entry.render_string()).unwrap(), entry);
+}
+
+#[test]
+fn until_eol_does_not_consume_following_lines() {
+    let input = "name=alice\nuser=root id=1\nuser=admin";
+    // Without the attribute the name spans the lines up to the first " id=".
+    assert_eq!(LazyEntry::from_str(input).unwrap().name, "alice\nuser=root");
+    assert!(Entry::from_str(input).is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{title}")]
+struct Title {
+    #[templatia(until_eol)]
+    title: String,
+}
+
+#[test]
+fn until_eol_rejects_line_breaks_in_last_placeholder() {
+    assert_eq!(Title::from_str("hello world").unwrap().title, "hello world");
+    assert!(Title::from_str("hello\nworld").is_err());
+}
@@ -0,0 +1,102 @@
+use templatia::prelude::*;
+use uuid::Uuid;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Uuid fields work as primitives, and uuid_format selects the rendered representation.
+
+const ID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+fn id() -> Uuid {
+    Uuid::parse_str(ID).unwrap()
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "id={id}")]
+struct Plain {
+    id: Uuid,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "simple={simple} braced={braced} urn={urn}")]
+struct Formats {
+    #[templatia(uuid_format = "simple")]
+    simple: Uuid,
+    #[templatia(uuid_format = "braced")]
+    braced: Uuid,
+    #[templatia(uuid_format = "urn")]
+    urn: Uuid,
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "owner={owner}; members={members}")]
+struct Team {
+    #[templatia(uuid_format = "simple")]
+    owner: Option<Uuid>,
+    #[templatia(uuid_format = "hyphenated")]
+    members: Vec<Uuid>,
+}
+
+#[test]
+fn uuid_renders_hyphenated_by_default() {
+    let plain = Plain { id: id() };
+    assert_eq!(plain.render_string(), format!("id={}", ID));
+    assert_eq!(Plain::from_str(&plain.render_string()).unwrap(), plain);
+}
+
+#[test]
+fn uuid_format_selects_representation() {
+    let formats = Formats {
+        simple: id(),
+        braced: id(),
+        urn: id(),
+    };
+    assert_eq!(
+        formats.render_string(),
+        format!(
+            "simple=67e5504410b1426f9247bb680e5fe0c8 braced={{{0}}} urn=urn:uuid:{0}",
+            ID
+        )
+    );
+    assert_eq!(
+        Formats::from_str(&formats.render_string()).unwrap(),
+        formats
+    );
+}
+
+#[test]
+fn parsing_accepts_any_representation() {
+    let input = format!("simple={0} braced={0} urn={0}", ID);
+    assert_eq!(
+        Formats::from_str(&input).unwrap(),
+        Formats {
+            simple: id(),
+            braced: id(),
+            urn: id(),
+        }
+    );
+}
+
+#[test]
+fn option_and_collection_elements_use_the_format() {
+    let team = Team {
+        owner: Some(id()),
+        members: vec![id(), Uuid::nil()],
+    };
+    let rendered = team.render_string();
+    assert_eq!(
+        rendered,
+        format!(
+            "owner=67e5504410b1426f9247bb680e5fe0c8; members={},00000000-0000-0000-0000-000000000000",
+            ID
+        )
+    );
+    assert_eq!(Team::from_str(&rendered).unwrap(), team);
+}
+
+#[test]
+fn invalid_uuid_is_a_type_error() {
+    assert!(matches!(
+        Plain::from_str("id=not-a-uuid").unwrap_err(),
+        templatia::TemplateError::ParseToType { .. }
+    ));
+}
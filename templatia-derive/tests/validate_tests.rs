@@ -0,0 +1,108 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(validate = "...")]` checks parsed values and reports rejections as `TemplateError::Validation`.
+
+fn valid_port(port: &u16) -> Result<(), String> {
+    if *port >= 1024 {
+        Ok(())
+    } else {
+        Err(format!("port {} is privileged", port))
+    }
+}
+
+mod checks {
+    pub fn not_blank(value: &str) -> Result<(), String> {
+        if value.trim().is_empty() {
+            Err("must not be blank".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{host}:{port}/db")]
+struct Server {
+    #[templatia(validate = "checks::not_blank")]
+    host: String,
+    #[templatia(validate = "valid_port")]
+    port: u16,
+}
+
+#[test]
+fn valid_values_parse() {
+    let server = Server::from_str("localhost:8080/db").unwrap();
+    assert_eq!(
+        server,
+        Server {
+            host: "localhost".to_string(),
+            port: 8080,
+        }
+    );
+}
+
+#[test]
+fn rejected_value_reports_validation_error() {
+    match Server::from_str("localhost:80/db") {
+        Err(TemplateError::Validation {
+            placeholder,
+            message,
+        }) => {
+            assert_eq!(placeholder, "port");
+            assert_eq!(message, "port 80 is privileged");
+        }
+        other => panic!("expected Validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn rejected_first_field_reports_its_name() {
+    match Server::from_str(" :8080/db") {
+        Err(TemplateError::Validation {
+            placeholder,
+            message,
+        }) => {
+            assert_eq!(placeholder, "host");
+            assert_eq!(message, "must not be blank");
+        }
+        other => panic!("expected Validation error, got {:?}", other),
+    }
+}
+
+#[test]
+fn type_errors_take_precedence_over_validation() {
+    assert!(matches!(
+        Server::from_str("localhost:abc/db"),
+        Err(TemplateError::ParseToType { .. })
+    ));
+}
+
+fn has_colon(value: &str) -> Result<(), String> {
+    if value.contains(':') {
+        Err("contains ':' which is not allowed".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "name={label}")]
+struct Named {
+    #[templatia(validate = "has_colon", rename = "label")]
+    name: String,
+}
+
+#[test]
+fn validation_message_keeps_colons_and_uses_placeholder_name() {
+    match Named::from_str("name=a:b") {
+        Err(err @ TemplateError::Validation { .. }) => {
+            assert_eq!(
+                err.to_string(),
+                "Validation failed for the placeholder 'label': contains ':' which is not allowed"
+            );
+        }
+        other => panic!("expected Validation error, got {:?}", other),
+    }
+}
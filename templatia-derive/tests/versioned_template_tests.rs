@@ -0,0 +1,80 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Legacy templates parse old serialized forms into the current struct, optionally migrating them.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "v3;host={host};port={port};tls={tls}", version = 3)]
+#[templatia(
+    legacy(v1 = "host={host}", v2 = "v2;host={host};port={port}"),
+    migrate(v1 = "Server::from_v1")
+)]
+struct Server {
+    host: String,
+    port: u16,
+    tls: bool,
+}
+
+impl Server {
+    fn from_v1(mut server: Server) -> Server {
+        server.port = 80;
+        server
+    }
+}
+
+#[test]
+fn current_version_parses_directly() {
+    let parsed = Server::from_str("v3;host=example.com;port=443;tls=true").unwrap();
+    assert_eq!(
+        parsed,
+        Server {
+            host: "example.com".into(),
+            port: 443,
+            tls: true,
+        }
+    );
+}
+
+#[test]
+fn legacy_version_without_migration_uses_defaults() {
+    let parsed = Server::from_str("v2;host=example.com;port=8080").unwrap();
+    assert_eq!(
+        parsed,
+        Server {
+            host: "example.com".into(),
+            port: 8080,
+            tls: false,
+        }
+    );
+}
+
+#[test]
+fn legacy_version_with_migration_is_migrated() {
+    let parsed = Server::from_str("host=example.com").unwrap();
+    assert_eq!(
+        parsed,
+        Server {
+            host: "example.com".into(),
+            port: 80,
+            tls: false,
+        }
+    );
+}
+
+#[test]
+fn render_always_uses_current_version() {
+    let parsed = Server::from_str("host=example.com").unwrap();
+    assert_eq!(
+        parsed.render_string(),
+        "v3;host=example.com;port=80;tls=false"
+    );
+}
+
+#[test]
+fn invalid_input_reports_current_template_error() {
+    let err = Server::from_str("v3;host=example.com;port=443;tls=maybe").expect_err("invalid tls");
+    assert!(matches!(
+        err,
+        templatia::TemplateError::ParseToType { ref placeholder, .. } if placeholder == "tls"
+    ));
+}
@@ -0,0 +1,204 @@
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// Wide structs with many placeholders must compile and round-trip like narrow ones.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(
+    template = "f0={f0};f1={f1};f2={f2};f3={f3};f4={f4};f5={f5};f6={f6};f7={f7};f8={f8};f9={f9};f10={f10};f11={f11};f12={f12};f13={f13};f14={f14};f15={f15};f16={f16};f17={f17};f18={f18};f19={f19};f20={f20};f21={f21};f22={f22};f23={f23};f24={f24};f25={f25};f26={f26};f27={f27};f28={f28};f29={f29};f30={f30};f31={f31};f32={f32};f33={f33};f34={f34};f35={f35};f36={f36};f37={f37};f38={f38};f39={f39};f40={f40};f41={f41};f42={f42};f43={f43};f44={f44};f45={f45};f46={f46};f47={f47};f48={f48};f49={f49};f50={f50};f51={f51};f52={f52};f53={f53};f54={f54};f55={f55};f56={f56};f57={f57};f58={f58};f59={f59}"
+)]
+struct Wide {
+    f0: u32,
+    f1: u32,
+    f2: u32,
+    f3: u32,
+    f4: u32,
+    f5: u32,
+    f6: u32,
+    f7: u32,
+    f8: u32,
+    f9: u32,
+    f10: u32,
+    f11: u32,
+    f12: u32,
+    f13: u32,
+    f14: u32,
+    f15: u32,
+    f16: u32,
+    f17: u32,
+    f18: u32,
+    f19: u32,
+    f20: u32,
+    f21: u32,
+    f22: u32,
+    f23: u32,
+    f24: u32,
+    f25: u32,
+    f26: u32,
+    f27: u32,
+    f28: u32,
+    f29: u32,
+    f30: u32,
+    f31: u32,
+    f32: u32,
+    f33: u32,
+    f34: u32,
+    f35: u32,
+    f36: u32,
+    f37: u32,
+    f38: u32,
+    f39: u32,
+    f40: u32,
+    f41: u32,
+    f42: u32,
+    f43: u32,
+    f44: u32,
+    f45: u32,
+    f46: u32,
+    f47: u32,
+    f48: u32,
+    f49: u32,
+    f50: u32,
+    f51: u32,
+    f52: u32,
+    f53: u32,
+    f54: u32,
+    f55: u32,
+    f56: u32,
+    f57: u32,
+    f58: u32,
+    f59: u32,
+}
+
+fn sample() -> Wide {
+    Wide {
+        f0: 0,
+        f1: 1,
+        f2: 2,
+        f3: 3,
+        f4: 4,
+        f5: 5,
+        f6: 6,
+        f7: 7,
+        f8: 8,
+        f9: 9,
+        f10: 10,
+        f11: 11,
+        f12: 12,
+        f13: 13,
+        f14: 14,
+        f15: 15,
+        f16: 16,
+        f17: 17,
+        f18: 18,
+        f19: 19,
+        f20: 20,
+        f21: 21,
+        f22: 22,
+        f23: 23,
+        f24: 24,
+        f25: 25,
+        f26: 26,
+        f27: 27,
+        f28: 28,
+        f29: 29,
+        f30: 30,
+        f31: 31,
+        f32: 32,
+        f33: 33,
+        f34: 34,
+        f35: 35,
+        f36: 36,
+        f37: 37,
+        f38: 38,
+        f39: 39,
+        f40: 40,
+        f41: 41,
+        f42: 42,
+        f43: 43,
+        f44: 44,
+        f45: 45,
+        f46: 46,
+        f47: 47,
+        f48: 48,
+        f49: 49,
+        f50: 50,
+        f51: 51,
+        f52: 52,
+        f53: 53,
+        f54: 54,
+        f55: 55,
+        f56: 56,
+        f57: 57,
+        f58: 58,
+        f59: 59,
+    }
+}
+
+fn sample_text() -> String {
+    (0..60)
+        .map(|i| format!("f{i}={i}"))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+#[test]
+fn wide_struct_renders_all_placeholders() {
+    assert_eq!(sample().render_string(), sample_text());
+}
+
+#[test]
+fn wide_struct_round_trips() {
+    let wide = sample();
+    assert_eq!(Wide::from_str(&wide.render_string()).unwrap(), wide);
+}
+
+#[test]
+fn wide_struct_reports_type_error_in_the_middle() {
+    let input = sample_text().replace("f30=30", "f30=abc");
+    match Wide::from_str(&input).expect_err("f30 is not a number") {
+        TemplateError::ParseToType {
+            placeholder, value, ..
+        } => {
+            assert_eq!(placeholder, "f30");
+            assert_eq!(value, "abc");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn wide_struct_with_duplicates_checks_consistency() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(
+        template = "a0={a0};a1={a1};a2={a2};a3={a3};a4={a4};a5={a5};a6={a6};a7={a7};a8={a8};a9={a9};a10={a10};a11={a11};again={a0}"
+    )]
+    struct WideDup {
+        a0: u8,
+        a1: u8,
+        a2: u8,
+        a3: u8,
+        a4: u8,
+        a5: u8,
+        a6: u8,
+        a7: u8,
+        a8: u8,
+        a9: u8,
+        a10: u8,
+        a11: u8,
+    }
+
+    let ok = (0..12)
+        .map(|i| format!("a{i}=1"))
+        .collect::<Vec<_>>()
+        .join(";")
+        + ";again=1";
+    assert!(WideDup::from_str(&ok).is_ok());
+
+    let conflicting = ok.replace("again=1", "again=2");
+    assert!(matches!(
+        WideDup::from_str(&conflicting),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+}
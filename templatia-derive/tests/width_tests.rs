@@ -0,0 +1,90 @@
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(width = N)]` consumes exactly N characters, allowing consecutive placeholders.
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{code}{name}{amount}")]
+struct Record {
+    #[templatia(width = 3)]
+    code: String,
+    #[templatia(width = 8)]
+    name: String,
+    amount: u32,
+}
+
+#[test]
+fn fixed_width_fields_parse_consecutively() {
+    let record = Record::from_str("ABCwidget  42").unwrap();
+    assert_eq!(
+        record,
+        Record {
+            code: "ABC".to_string(),
+            name: "widget".to_string(),
+            amount: 42,
+        }
+    );
+}
+
+#[test]
+fn fixed_width_fields_render_padded() {
+    let record = Record {
+        code: "X".to_string(),
+        name: "gear".to_string(),
+        amount: 7,
+    };
+    assert_eq!(record.render_string(), "X  gear    7");
+    assert_eq!(Record::from_str(&record.render_string()).unwrap(), record);
+}
+
+#[test]
+fn short_input_is_an_error() {
+    assert!(Record::from_str("AB").is_err());
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{year}{month}{day}|{note}")]
+struct Date {
+    #[templatia(width = 4)]
+    year: u16,
+    #[templatia(width = 2, format = "{:02}")]
+    month: u8,
+    #[templatia(width = 2, format = "{:02}")]
+    day: u8,
+    #[templatia(width = 5)]
+    note: Option<String>,
+}
+
+#[test]
+fn fixed_width_numbers_with_format() {
+    let date = Date {
+        year: 2024,
+        month: 3,
+        day: 9,
+        note: None,
+    };
+    assert_eq!(date.render_string(), "20240309|     ");
+    assert_eq!(Date::from_str("20240309|     ").unwrap(), date);
+    assert_eq!(
+        Date::from_str("20241231|hi   ").unwrap().note.as_deref(),
+        Some("hi")
+    );
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "{code}{name}")]
+struct Pair {
+    #[templatia(width = 3)]
+    code: String,
+    name: String,
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "longer than its width"))]
+fn longer_values_are_cut_to_the_width() {
+    let pair = Pair {
+        code: "abcd".to_string(),
+        name: "zz".to_string(),
+    };
+    assert_eq!(pair.render_string(), "abczz");
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+use templatia::TemplateError;
+use templatia::prelude::*;
+
+// Tests follow AGENTS.md policy. They express intended behavior from docs.
+// `#[templatia(with = "module")]` converts fields through custom render/parse functions.
+
+mod seconds {
+    use std::time::Duration;
+
+    pub fn render(value: &Duration) -> String {
+        format!("{}s", value.as_secs())
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, std::num::ParseIntError> {
+        s.trim_end_matches('s').parse().map(Duration::from_secs)
+    }
+}
+
+mod pipe_list {
+    pub fn render(values: &[String]) -> String {
+        values.join("|")
+    }
+
+    pub fn parse(s: &str) -> Result<Vec<String>, std::convert::Infallible> {
+        Ok(s.split('|').map(str::to_string).collect())
+    }
+}
+
+#[derive(Template, Debug, PartialEq)]
+#[templatia(template = "timeout={timeout};hosts={hosts}")]
+struct Client {
+    #[templatia(with = "seconds")]
+    timeout: Duration,
+    #[templatia(with = "pipe_list")]
+    hosts: Vec<String>,
+}
+
+#[test]
+fn with_module_renders_fields() {
+    let client = Client {
+        timeout: Duration::from_secs(30),
+        hosts: vec!["a".into(), "b".into()],
+    };
+    assert_eq!(client.render_string(), "timeout=30s;hosts=a|b");
+}
+
+#[test]
+fn with_module_parses_fields() {
+    let client = Client::from_str("timeout=5s;hosts=x|y|z").unwrap();
+    assert_eq!(client.timeout, Duration::from_secs(5));
+    assert_eq!(client.hosts, vec!["x", "y", "z"]);
+}
+
+#[test]
+fn with_module_parse_errors_are_type_errors() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "timeout={timeout}")]
+    struct Timeout {
+        #[templatia(with = "seconds")]
+        timeout: Duration,
+    }
+
+    match Timeout::from_str("timeout=soon").expect_err("not a duration") {
+        TemplateError::ParseToType {
+            placeholder, value, ..
+        } => {
+            assert_eq!(placeholder, "timeout");
+            assert_eq!(value, "soon");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn with_module_duplicates_compare_rendered_values() {
+    #[derive(Template, Debug, PartialEq)]
+    #[templatia(template = "{timeout}/{timeout}")]
+    struct Twice {
+        #[templatia(with = "seconds")]
+        timeout: Duration,
+    }
+
+    assert!(Twice::from_str("3s/3").is_ok());
+    assert!(matches!(
+        Twice::from_str("3s/4s"),
+        Err(TemplateError::InconsistentValues { .. })
+    ));
+}
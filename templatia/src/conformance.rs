@@ -0,0 +1,387 @@
+//! Conformance checks for `Template` implementations.
+//!
+//! This module provides a one-call suite runner that generates arbitrary values of a type and
+//! verifies that its `Template` implementation behaves consistently:
+//!
+//! - Round-trip: `T::from_str(&value.render_string())` reproduces `value`.
+//! - Idempotent re-render: rendering the parsed value produces the same text again.
+//! - Duplicate consistency: a round-trip failure reported as `TemplateError::InconsistentValues`
+//!   is recorded under its own check. The runner only sees rendered text, so it doesn't build
+//!   inputs with conflicting duplicate values itself.
+//! - Error classification: mutated inputs never panic, and failures are reported through a
+//!   specific `TemplateError` variant rather than the catch-all `TemplateError::Parse`. The
+//!   mutations add a NUL before or after the text, drop its first or last character, and drop or
+//!   double each of the first punctuation characters, which are where the literals of a template
+//!   usually meet its values.
+//!
+//! Values are generated through the [`Arbitrary`] trait from the `arbitrary` crate, which is
+//! re-exported here for convenience.
+//!
+//! # Examples
+//! ```rust
+//! use templatia::conformance::{Arbitrary, ArbitraryResult, ConformanceConfig, Unstructured};
+//! use templatia::{ParseTemplate, RenderTemplate, TemplateError};
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Port(u16);
+//!
+//! impl RenderTemplate for Port {
+//!     fn render_string(&self) -> String {
+//!         format!("port={}", self.0)
+//!     }
+//! }
+//!
+//! impl ParseTemplate for Port {
+//!     type Error = TemplateError;
+//!
+//!     fn from_str(s: &str) -> Result<Self, Self::Error> {
+//!         let value = s.strip_prefix("port=").ok_or_else(|| TemplateError::UnexpectedInput {
+//!             expected_next_literal: "port=".to_string(),
+//!             remaining_text: s.to_string(),
+//!         })?;
+//!         value.parse().map(Port).map_err(|_| TemplateError::ParseToType {
+//!             placeholder: "port".to_string(),
+//!             value: value.to_string(),
+//!             type_name: "u16".to_string(),
+//!         })
+//!     }
+//! }
+//!
+//! impl<'a> Arbitrary<'a> for Port {
+//!     fn arbitrary(u: &mut Unstructured<'a>) -> ArbitraryResult<Self> {
+//!         Ok(Port(u.arbitrary()?))
+//!     }
+//! }
+//!
+//! let report = ConformanceConfig::new().with_cases(64).run::<Port>();
+//! report.assert_success();
+//! assert_eq!(report.cases, 64);
+//! ```
+
+use crate::{Template, TemplateError};
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+pub use arbitrary::{Arbitrary, Result as ArbitraryResult, Unstructured};
+
+/// The kind of check that produced a conformance failure.
+///
+/// # Fields
+/// - RoundTrip: Parsing the rendered output failed or produced a different value.
+/// - IdempotentRender: Re-rendering the parsed value produced different text.
+/// - DuplicateConsistency: Rendered output was rejected as having inconsistent duplicate placeholders.
+///   It's only reported for the rendered output, never for inputs built with conflicting values.
+/// - ErrorClassification: A mutated input panicked or failed with an unclassified error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConformanceCheck {
+    RoundTrip,
+    IdempotentRender,
+    DuplicateConsistency,
+    ErrorClassification,
+}
+
+impl Display for ConformanceCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ConformanceCheck::RoundTrip => "round-trip",
+            ConformanceCheck::IdempotentRender => "idempotent re-render",
+            ConformanceCheck::DuplicateConsistency => "duplicate consistency",
+            ConformanceCheck::ErrorClassification => "error classification",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single failed check recorded by the conformance runner.
+///
+/// # Fields
+/// - check: The check that failed.
+/// - input: The text that was parsed when the failure happened.
+/// - message: A human-readable description of the failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConformanceFailure {
+    pub check: ConformanceCheck,
+    pub input: String,
+    pub message: String,
+}
+
+impl Display for ConformanceFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] {} (input: {:?})",
+            self.check, self.message, self.input
+        )
+    }
+}
+
+/// The result of a conformance run.
+///
+/// # Fields
+/// - cases: The number of generated values that were checked.
+/// - failures: All failures found during the run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConformanceReport {
+    pub cases: usize,
+    pub failures: Vec<ConformanceFailure>,
+}
+
+impl ConformanceReport {
+    /// Returns true when no check failed.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Asserts that no check failed.
+    ///
+    /// # Panics
+    /// Panics with the full report when at least one check failed.
+    pub fn assert_success(&self) {
+        if !self.is_success() {
+            panic!("{}", self);
+        }
+    }
+}
+
+impl Display for ConformanceReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "conformance: {} cases, {} failures",
+            self.cases,
+            self.failures.len()
+        )?;
+        for failure in &self.failures {
+            write!(f, "\n  {}", failure)?;
+        }
+        Ok(())
+    }
+}
+
+/// Configuration of a conformance run.
+///
+/// # Fields
+/// - cases: The number of values to generate.
+/// - seed: The seed of the pseudo-random byte stream fed into `Arbitrary`.
+/// - max_failures: The number of failures after which the run stops early.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConformanceConfig {
+    cases: usize,
+    seed: u64,
+    max_failures: usize,
+}
+
+impl Default for ConformanceConfig {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: 0x7e3d_1a2b_5c4f_9e01,
+            max_failures: 16,
+        }
+    }
+}
+
+impl ConformanceConfig {
+    /// Creates a configuration with 256 cases and a fixed seed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of generated values.
+    ///
+    /// # Parameters
+    /// - cases: The number of values to generate.
+    ///
+    /// # Returns
+    /// A cloned instance with the updated field(s).
+    pub fn with_cases(&self, cases: usize) -> Self {
+        Self {
+            cases,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the seed of the generated byte stream.
+    ///
+    /// # Parameters
+    /// - seed: The seed. The same seed always produces the same values.
+    ///
+    /// # Returns
+    /// A cloned instance with the updated field(s).
+    pub fn with_seed(&self, seed: u64) -> Self {
+        Self {
+            seed,
+            ..self.clone()
+        }
+    }
+
+    /// Sets the number of failures after which the run stops.
+    ///
+    /// # Parameters
+    /// - max_failures: The failure limit.
+    ///
+    /// # Returns
+    /// A cloned instance with the updated field(s).
+    pub fn with_max_failures(&self, max_failures: usize) -> Self {
+        Self {
+            max_failures,
+            ..self.clone()
+        }
+    }
+
+    /// Runs every conformance check against generated values of `T`.
+    ///
+    /// # Returns
+    /// A report containing the number of checked values and all failures.
+    ///
+    /// # Notes
+    /// - Values for which `Arbitrary` runs out of data are skipped and not counted.
+    /// - Error classification only inspects errors of type `TemplateError`; other error types
+    ///   are only checked for panics.
+    pub fn run<T>(&self) -> ConformanceReport
+    where
+        T: Template + for<'a> Arbitrary<'a> + PartialEq + Debug,
+        T::Error: Debug + 'static,
+    {
+        let mut report = ConformanceReport::default();
+        let mut rng = XorShift(self.seed.max(1));
+        let mut buffer = vec![0u8; 1024];
+
+        for _ in 0..self.cases {
+            if report.failures.len() >= self.max_failures {
+                break;
+            }
+
+            buffer.iter_mut().for_each(|byte| *byte = rng.next_byte());
+            let value = match T::arbitrary(&mut Unstructured::new(&buffer)) {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            report.cases += 1;
+
+            check_value(&value, &mut report.failures);
+        }
+
+        report.failures.truncate(self.max_failures);
+        report
+    }
+}
+
+/// Runs the conformance checks with the default configuration.
+///
+/// # Returns
+/// A report containing the number of checked values and all failures.
+pub fn check<T>() -> ConformanceReport
+where
+    T: Template + for<'a> Arbitrary<'a> + PartialEq + Debug,
+    T::Error: Debug + 'static,
+{
+    ConformanceConfig::new().run::<T>()
+}
+
+fn check_value<T>(value: &T, failures: &mut Vec<ConformanceFailure>)
+where
+    T: Template + PartialEq + Debug,
+    T::Error: Debug + 'static,
+{
+    let rendered = value.render_string();
+
+    match T::from_str(&rendered) {
+        Ok(parsed) => {
+            if &parsed != value {
+                failures.push(ConformanceFailure {
+                    check: ConformanceCheck::RoundTrip,
+                    input: rendered.clone(),
+                    message: format!("parsed {:?}, expected {:?}", parsed, value),
+                });
+            }
+
+            let rerendered = parsed.render_string();
+            if rerendered != rendered {
+                failures.push(ConformanceFailure {
+                    check: ConformanceCheck::IdempotentRender,
+                    input: rendered.clone(),
+                    message: format!("re-rendered as {:?}", rerendered),
+                });
+            }
+        }
+        Err(err) => {
+            let check = match as_template_error(&err) {
+                Some(TemplateError::InconsistentValues { .. }) => {
+                    ConformanceCheck::DuplicateConsistency
+                }
+                _ => ConformanceCheck::RoundTrip,
+            };
+            failures.push(ConformanceFailure {
+                check,
+                input: rendered.clone(),
+                message: format!("failed to parse rendered output: {:?}", err),
+            });
+        }
+    }
+
+    for mutated in mutations(&rendered) {
+        match catch_unwind(AssertUnwindSafe(|| T::from_str(&mutated))) {
+            Ok(Ok(_)) => {}
+            Ok(Err(err)) => {
+                if let Some(TemplateError::Parse(msg)) = as_template_error(&err) {
+                    failures.push(ConformanceFailure {
+                        check: ConformanceCheck::ErrorClassification,
+                        input: mutated,
+                        message: format!("unclassified parse error: {}", msg),
+                    });
+                }
+            }
+            Err(_) => failures.push(ConformanceFailure {
+                check: ConformanceCheck::ErrorClassification,
+                input: mutated,
+                message: "from_str panicked".to_string(),
+            }),
+        }
+    }
+}
+
+fn as_template_error<E: 'static>(err: &E) -> Option<&TemplateError> {
+    (err as &dyn Any).downcast_ref::<TemplateError>()
+}
+
+/// The number of punctuation characters whose dropped and doubled forms are tried.
+const BOUNDARY_MUTATIONS: usize = 8;
+
+/// Derives malformed inputs from a valid rendered string.
+fn mutations(rendered: &str) -> Vec<String> {
+    let mut mutations = vec![format!("{}\u{0}", rendered), format!("\u{0}{}", rendered)];
+
+    if let Some((last, _)) = rendered.char_indices().last() {
+        mutations.push(rendered[..last].to_string());
+    }
+    if let Some(first) = rendered.chars().next() {
+        mutations.push(rendered[first.len_utf8()..].to_string());
+    }
+
+    // Literals are usually made of punctuation, so the edits around it hit their boundaries.
+    for (index, c) in rendered
+        .char_indices()
+        .filter(|(_, c)| c.is_ascii_punctuation())
+        .take(BOUNDARY_MUTATIONS)
+    {
+        let (before, after) = rendered.split_at(index);
+        mutations.push(format!("{}{}", before, &after[c.len_utf8()..]));
+        mutations.push(format!("{}{}{}", before, c, after));
+    }
+
+    mutations
+}
+
+/// A small deterministic generator so runs are reproducible without extra dependencies.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_byte(&mut self) -> u8 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 24) as u8
+    }
+}
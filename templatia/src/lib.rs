@@ -30,7 +30,7 @@
 //! The easiest way to use templatia is with the derive macro for named structs:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! struct DatabaseConfig {
@@ -60,7 +60,7 @@
 //! Define your own template format using the `templatia` attribute:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "postgresql://{host}:{port}/{database}")]
@@ -88,7 +88,7 @@
 //! Templatia supports duplicate placeholders as long as they have consistent values:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "Welcome {name}! Your name is {name}.")]
@@ -108,7 +108,7 @@
 //! Fields with `Option<T>` type automatically default to `None` when the placeholder is not in the template:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "host={host}:{port}", allow_missing_placeholders)]
@@ -129,7 +129,7 @@
 //! By default, empty strings in `Option<String>` are parsed as `None`:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "value={value}")]
@@ -144,7 +144,7 @@
 //! To treat empty strings as `Some("")`, use the `empty_str_option_not_none` attribute:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "value={value}", empty_str_option_not_none)]
@@ -160,7 +160,7 @@
 //! Use `allow_missing_placeholders` to allow fields not in the template:
 //!
 //! ```rust
-//! use templatia::Template;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "id={id}", allow_missing_placeholders)]
@@ -179,20 +179,23 @@
 //! ### Manual Implementation (Advanced)
 //!
 //! While the derive macro only supports named structs currently, you can manually implement
-//! the `Template` trait for other types like tuple structs, enums, or complex custom logic:
+//! the `RenderTemplate` and `ParseTemplate` halves of the `Template` trait for other types like
+//! tuple structs, enums, or complex custom logic:
 //!
 //! ```rust
-//! use templatia::{Template, TemplateError};
+//! use templatia::{ParseTemplate, RenderTemplate, TemplateError};
 //!
 //! // Example: Tuple struct (derive doesn't support this yet)
 //! struct Point(i32, i32);
 //!
-//! impl Template for Point {
-//!     type Error = TemplateError;
-//!
+//! impl RenderTemplate for Point {
 //!     fn render_string(&self) -> String {
 //!         format!("({}, {})", self.0, self.1)
 //!     }
+//! }
+//!
+//! impl ParseTemplate for Point {
+//!     type Error = TemplateError;
 //!
 //!     fn from_str(s: &str) -> Result<Self, Self::Error> {
 //!         if !s.starts_with('(') || !s.ends_with(')') {
@@ -245,8 +248,9 @@
 //!
 //! ### 🌟 Version 0.0.5
 //! - Tuple struct support (derive macro)
-//! - Union struct support  
-//! - Enum support
+//! - Data-carrying enum support
+//!
+//! Unions and fieldless enums are already supported by the derive macro.
 //!
 //! ## Type Requirements
 //!
@@ -257,12 +261,19 @@
 //!
 //! Most common types (String, integers, floats, bool) implement these automatically.
 //!
+//! The derive implements [`RenderTemplate`] and [`ParseTemplate`] separately, each bounded only by
+//! what it uses, and [`Template`] follows for types implementing both. A generic struct renders
+//! with a type parameter that lacks `FromStr`, and parses with one that lacks `Display`, as long as
+//! the parser doesn't write the value into an error, which it does for repeated placeholders and
+//! range checks.
+//!
 //! ## Error Handling
 //!
 //! Templatia provides clear error types for different failure scenarios:
 //!
 //! ```rust
-//! use templatia::{Template, TemplateError};
+//! use templatia::TemplateError;
+//! use templatia::prelude::*;
 //!
 //! #[derive(Template)]
 //! #[templatia(template = "port={port}")]
@@ -293,7 +304,7 @@
 //! ### `derive`
 //!
 //! The `derive` feature enables the `#[derive(Template)]` procedural macro for automatic
-//! `Template` trait implementations on named structs.
+//! `Template` trait implementations on named structs, fieldless enums, and unions.
 //!
 //! When enabled, you can use:
 //! ```toml
@@ -307,20 +318,141 @@
 //! - Compile-time validation of templates and field references
 //! - Zero-cost abstractions with full type safety
 //!
-//! **Limitations:** Currently only supports named structs. Tuple structs, unit structs,
-//! and enums require manual `Template` trait implementation.
+//! **Limitations:** Supports named structs, fieldless enums, and unions. Tuple structs, unit
+//! structs, and enums with data-carrying variants require manual `Template` trait implementation.
 //!
 //! For detailed usage examples, see the sections above.
+//!
+//! ### `conformance`
+//!
+//! The `conformance` feature enables the `templatia::conformance` module, a suite runner
+//! that checks round-trip and idempotent re-render, and the error classification of inputs
+//! mutated around their punctuation, for any `T: Template + Arbitrary + PartialEq`. It is intended
+//! to be called from tests:
+//! ```toml
+//! [dev-dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "conformance"] }
+//! ```
+//!
+//! ### `indexmap`
+//!
+//! The `indexmap` feature lets `#[derive(Template)]` accept `IndexMap<K, V>` and `IndexSet<T>`
+//! fields of the [`indexmap`](https://docs.rs/indexmap) crate, which keep the insertion order of
+//! their entries across render and parse. The types are used from your own `indexmap` 2 dependency:
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "indexmap"] }
+//! indexmap = "2"
+//! ```
+//!
+//! ### `chrono`
+//!
+//! The `chrono` feature lets `#[derive(Template)]` accept `DateTime<Utc>` and
+//! `DateTime<FixedOffset>` fields of the [`chrono`](https://docs.rs/chrono) crate 0.4, and adds the
+//! `#[templatia(datetime_format = "...")]` field attribute, which renders and parses `DateTime`,
+//! `NaiveDate`, `NaiveTime`, and `NaiveDateTime` values with a `strftime`-like format instead of
+//! their `Display` and `FromStr`:
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "chrono"] }
+//! chrono = "0.4"
+//! ```
+//!
+//! ### `time`
+//!
+//! The `time` feature makes `#[templatia(datetime_format = "...")]` accept a format description
+//! of the [`time`](https://docs.rs/time) crate 0.3, such as `"[year]-[month]-[day]"`, to render
+//! and parse `Date`, `Time`, `PrimitiveDateTime`, and `OffsetDateTime` fields:
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "time"] }
+//! time = "0.3"
+//! ```
+//!
+//! ### `uuid`
+//!
+//! `Uuid` fields of the [`uuid`](https://docs.rs/uuid) crate work like other primitive-like values
+//! through their `Display` and `FromStr`. The `uuid` feature adds
+//! `#[templatia(uuid_format = "simple")]` to render them in the `hyphenated`, `simple`, `braced`,
+//! or `urn` form, while parsing accepts any form:
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "uuid"] }
+//! uuid = "1"
+//! ```
+//!
+//! ### `url`
+//!
+//! `Url` fields of the [`url`](https://docs.rs/url) crate render their percent-encoded
+//! serialization and parse through `Url::parse`. The `url` feature percent-encodes the collection
+//! separator inside each element of `Vec<url::Url>` and other collections, so that a URL like
+//! `https://example.com/?tags=a,b` survives the default `,` separator. The element type is
+//! recognized only when written with its full path, `url::Url`:
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "url"] }
+//! url = "2"
+//! ```
+//!
+//! ### `semver`
+//!
+//! `Version` fields of the [`semver`](https://docs.rs/semver) crate work through their `Display`
+//! and `FromStr`. The `semver` feature captures fields written as `semver::Version` up to the
+//! longest valid version instead of the next literal, so templates like `"app-{version}-{os}"`
+//! read pre-release versions whole:
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "semver"] }
+//! semver = "1"
+//! ```
+//!
+//! ### `smallvec` and `arrayvec`
+//!
+//! The `smallvec` and `arrayvec` features let `#[derive(Template)]` accept `SmallVec<[T; N]>` of
+//! the [`smallvec`](https://docs.rs/smallvec) crate 1 and `ArrayVec<T, N>` of the
+//! [`arrayvec`](https://docs.rs/arrayvec) crate 0.7, which are handled like `Vec<T>`.
+//! ```toml
+//! [dependencies]
+//! templatia = { version = "0.0.3", features = ["derive", "smallvec", "arrayvec"] }
+//! smallvec = "1"
+//! arrayvec = "0.7"
+//! ```
 
 #[cfg(feature = "derive")]
 #[doc(inline)]
 pub use templatia_derive::Template;
 
+#[cfg(feature = "conformance")]
+pub mod conformance;
+
+/// The traits needed to derive, render, and parse templates, for a glob import.
+///
+/// Methods like `render_string` and `from_str` are declared on [`RenderTemplate`] and
+/// [`ParseTemplate`], which have to be in scope to call them on a concrete type:
+///
+/// ```rust
+/// use templatia::prelude::*;
+///
+/// #[derive(Template)]
+/// #[templatia(template = "{host}:{port}")]
+/// struct Endpoint {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let endpoint = Endpoint::from_str("localhost:8080").unwrap();
+/// assert_eq!(endpoint.render_string(), "localhost:8080");
+/// ```
+pub mod prelude {
+    pub use crate::{BorrowedTemplate, ParseTemplate, RenderTemplate, Template};
+}
+
 /// A trait for converting between a struct and its string template form.
 ///
 /// This trait enables bidirectional conversion between Rust data structures and their
 /// string template representations. It's the core abstraction that powers templatia's
-/// serialization and deserialization capabilities.
+/// serialization and deserialization capabilities. It combines [`RenderTemplate`] and
+/// [`ParseTemplate`], and every type implementing both implements it.
 ///
 /// # Design Philosophy
 ///
@@ -332,12 +464,12 @@ pub use templatia_derive::Template;
 ///
 /// # Associated Types
 ///
-/// - `Error`: The concrete error type returned by parsing operations. Should implement
-///   `std::error::Error + std::fmt::Display` for best integration with error handling.
+/// - `Error`: The concrete error type returned by parsing operations, declared on [`ParseTemplate`].
+///   Should implement `std::error::Error + std::fmt::Display` for best integration with error handling.
 ///
 /// # Implementation Guidelines
 ///
-/// When manually implementing this trait:
+/// When manually implementing the two halves:
 ///
 /// 1. **Consistency**: Ensure `from_str(x.render_string())` equals `x` for valid data
 ///    - In `empty_str_option_not_none` mode, we have the known limitation that
@@ -351,19 +483,21 @@ pub use templatia_derive::Template;
 /// ## Basic Implementation
 ///
 /// ```rust
-/// use templatia::{Template, TemplateError};
+/// use templatia::{ParseTemplate, RenderTemplate, TemplateError};
 ///
 /// struct ServerConfig {
 ///     name: String,
 ///     port: u16,
 /// }
 ///
-/// impl Template for ServerConfig {
-///     type Error = TemplateError;
-///
+/// impl RenderTemplate for ServerConfig {
 ///     fn render_string(&self) -> String {
 ///         format!("server={},port={}", self.name, self.port)
 ///     }
+/// }
+///
+/// impl ParseTemplate for ServerConfig {
+///     type Error = TemplateError;
 ///
 ///     fn from_str(s: &str) -> Result<Self, Self::Error> {
 ///         let parts: Vec<&str> = s.split(',').collect();
@@ -397,7 +531,7 @@ pub use templatia_derive::Template;
 /// ## Generic Implementation
 ///
 /// ```rust
-/// use templatia::{Template, TemplateError};
+/// use templatia::{ParseTemplate, RenderTemplate, TemplateError};
 /// use std::fmt::Display;
 /// use std::str::FromStr;
 ///
@@ -406,17 +540,20 @@ pub use templatia_derive::Template;
 ///     value: T,
 /// }
 ///
-/// impl<T> Template for KeyValue<T>
+/// // Rendering needs only `Display`, so `KeyValue<T>` renders for any displayable `T`.
+/// impl<T: Display> RenderTemplate for KeyValue<T> {
+///     fn render_string(&self) -> String {
+///         format!("{}={}", self.key, self.value)
+///     }
+/// }
+///
+/// impl<T> ParseTemplate for KeyValue<T>
 /// where
-///     T: Display + FromStr + Clone,
+///     T: FromStr,
 ///     T::Err: Display,
 /// {
 ///     type Error = TemplateError;
 ///
-///     fn render_string(&self) -> String {
-///         format!("{}={}", self.key, self.value)
-///     }
-///
 ///     fn from_str(s: &str) -> Result<Self, Self::Error> {
 ///         let parts: Vec<&str> = s.splitn(2, '=').collect();
 ///         if parts.len() != 2 {
@@ -437,17 +574,16 @@ pub use templatia_derive::Template;
 /// let parsed = KeyValue::<u32>::from_str("retry=5").unwrap();
 /// assert_eq!(parsed.value, 5);
 /// ```
-pub trait Template
-where
-    Self: Sized,
-{
-    /// The concrete error type for template parsing or formatting failures.
-    ///
-    /// This should typically be `TemplateError` for most implementations, but custom
-    /// error types are supported for specialized use cases. The error type should
-    /// implement `std::error::Error` for best integration with Rust's error ecosystem.
-    type Error;
+pub trait Template: RenderTemplate + ParseTemplate {}
 
+impl<T: RenderTemplate + ParseTemplate> Template for T {}
+
+/// The rendering half of [`Template`], converting a value into its template string.
+///
+/// Types that are only ever rendered implement this trait alone, so their fields don't need
+/// `FromStr`. `#[derive(Template)]` implements it with `Display` bounds on the fields only, and
+/// `#[templatia(render_only)]` leaves [`ParseTemplate`] out.
+pub trait RenderTemplate {
     /// Converts the value into its template string representation.
     ///
     /// This method serializes the struct into a string format according to the
@@ -461,7 +597,7 @@ where
     /// # Examples
     ///
     /// ```rust
-    /// use templatia::Template;
+    /// use templatia::prelude::*;
     ///
     /// #[derive(Template)]
     /// struct AppConfig {
@@ -481,6 +617,101 @@ where
     /// ```
     fn render_string(&self) -> String;
 
+    /// Writes the template string representation into `w`.
+    ///
+    /// The output is the same as `render_string`, but it goes straight into an existing buffer,
+    /// a `Formatter`, or a fixed-capacity string. The default implementation writes the result of
+    /// `render_string`, and `#[derive(Template)]` overrides it to write without the intermediate
+    /// `String`.
+    ///
+    /// # Parameters
+    ///
+    /// - w: The sink the rendered text is appended to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `std::fmt::Error` when the sink or the `Display` implementation of a value fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templatia::prelude::*;
+    ///
+    /// #[derive(Template)]
+    /// #[templatia(template = "{host}:{port}")]
+    /// struct Endpoint {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let mut buffer = String::from("endpoint = ");
+    /// Endpoint { host: "localhost".to_string(), port: 8080 }
+    ///     .render_to(&mut buffer)
+    ///     .unwrap();
+    /// assert_eq!(buffer, "endpoint = localhost:8080");
+    /// ```
+    fn render_to(&self, w: &mut (impl std::fmt::Write + ?Sized)) -> std::fmt::Result {
+        w.write_str(&self.render_string())
+    }
+
+    /// Writes the template string representation into the I/O sink `w`, such as a file or a socket.
+    ///
+    /// The text is passed to `w` piece by piece through `render_to`, so a large document isn't
+    /// materialized in memory first with derived implementations. Wrap an unbuffered sink in a
+    /// `std::io::BufWriter` to avoid a system call per piece.
+    ///
+    /// # Parameters
+    ///
+    /// - w: The sink the rendered text is written to as UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first `std::io::Error` of `w`, or an error of kind `Other` when the `Display`
+    /// implementation of a value fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templatia::prelude::*;
+    ///
+    /// #[derive(Template)]
+    /// #[templatia(template = "{host}:{port}")]
+    /// struct Endpoint {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let mut file = Vec::new();
+    /// Endpoint { host: "localhost".to_string(), port: 8080 }
+    ///     .render_write(&mut file)
+    ///     .unwrap();
+    /// assert_eq!(file, b"localhost:8080");
+    /// ```
+    fn render_write(&self, w: &mut (impl std::io::Write + ?Sized)) -> std::io::Result<()> {
+        let mut adapter = IoAdapter {
+            inner: w,
+            error: None,
+        };
+        adapter_result(self.render_to(&mut adapter), adapter.error)
+    }
+}
+
+/// The parsing half of [`Template`], constructing a value from its template string.
+///
+/// Types that are only ever parsed implement this trait alone, so their fields don't need
+/// `Display`. `#[derive(Template)]` implements it with `FromStr` bounds on the fields, and
+/// `#[templatia(parse_only)]` leaves [`RenderTemplate`] out.
+pub trait ParseTemplate
+where
+    Self: Sized,
+{
+    /// The concrete error type for template parsing or formatting failures.
+    ///
+    /// This should typically be `TemplateError` for most implementations, but custom
+    /// error types are supported for specialized use cases. The error type should
+    /// implement `std::error::Error` for best integration with Rust's error ecosystem.
+    type Error;
+
     /// Parses an instance from a template string.
     ///
     /// This method deserializes a string into the target struct type according to
@@ -500,13 +731,20 @@ where
     /// Returns `Self::Error` when:
     /// - Inconsistent duplicate placeholders are found (`TemplateError::InconsistentValues`).
     /// - A field value fails to parse into its target type (`TemplateError::ParseToType`).
+    /// - A `NonZero*` field receives a zero value (`TemplateError::ZeroValue`).
+    /// - A section included with `{>field}` fails to parse (`TemplateError::Include`).
+    /// - A parsed value is rejected by its validation function (`TemplateError::Validation`).
+    /// - A parsed value is outside the bounds of its `min`/`max`/`min_len`/`max_len` attribute or its
+    ///   `{field:min..max}` range (`TemplateError::OutOfRange`).
+    /// - The parsed value is rejected by the validation function of the type (`TemplateError::InvalidValue`).
     /// - The next expected literal in the template does not match the input (`TemplateError::UnexpectedInput`).
     /// - Other parser failures occur and are aggregated into a single message (`TemplateError::Parse`).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use templatia::{Template, TemplateError};
+    /// use templatia::TemplateError;
+    /// use templatia::prelude::*;
     ///
     /// #[derive(Template, PartialEq, Debug)]
     /// #[templatia(template = "host={host}:{port}")]
@@ -529,6 +767,133 @@ where
     /// }
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Error>;
+
+    /// Parses an instance from the text read out of `r`, such as a socket or a file.
+    ///
+    /// # Parameters
+    ///
+    /// - r: The reader of the source text, which must be valid UTF-8.
+    ///
+    /// # Returns
+    ///
+    /// The parsed instance, as `from_str` returns for the whole text of `r`.
+    ///
+    /// # Errors
+    ///
+    /// - `ReadError::Io`: Reading from `r` failed or the text isn't valid UTF-8.
+    /// - `ReadError::Template`: `from_str` rejected the text.
+    ///
+    /// # Notes
+    ///
+    /// - The generated parsers backtrack over the whole input and check that nothing is left after
+    ///   the template, so the text is read to the end before parsing starts rather than fed
+    ///   incrementally. A reader that never ends, like an open socket, has to be bounded first,
+    ///   e.g., with `std::io::Read::take`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use templatia::prelude::*;
+    ///
+    /// #[derive(Template, Debug, PartialEq)]
+    /// #[templatia(template = "{host}:{port}")]
+    /// struct Endpoint {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let endpoint = Endpoint::from_reader("localhost:8080".as_bytes()).unwrap();
+    /// assert_eq!(endpoint, Endpoint { host: "localhost".to_string(), port: 8080 });
+    /// ```
+    fn from_reader(mut r: impl std::io::BufRead) -> Result<Self, ReadError<Self::Error>> {
+        let mut input = String::new();
+        r.read_to_string(&mut input)?;
+        Self::from_str(&input).map_err(ReadError::Template)
+    }
+}
+
+/// A template whose parsed value borrows from the input string.
+///
+/// `#[derive(Template)]` implements this trait instead of [`ParseTemplate`] when a field referenced in the
+/// template is `&str` or `Option<&str>`, and [`RenderTemplate`] as usual. `ParseTemplate::from_str` accepts an input of any lifetime, so it
+/// can't return a value that borrows from it; `from_str` here requires the input to outlive `Self`.
+///
+/// # Examples
+///
+/// ```rust
+/// use templatia::prelude::*;
+///
+/// #[derive(Template, Debug, PartialEq)]
+/// #[templatia(template = "{name}@{host}")]
+/// struct Address<'a> {
+///     name: &'a str,
+///     host: &'a str,
+/// }
+///
+/// let input = String::from("alice@example.com");
+/// let address = Address::from_str(&input).unwrap();
+/// assert_eq!(address, Address { name: "alice", host: "example.com" });
+/// assert_eq!(address.render_string(), "alice@example.com");
+/// ```
+pub trait BorrowedTemplate<'a>
+where
+    Self: Sized,
+{
+    /// The concrete error type for template parsing failures.
+    type Error;
+
+    /// Parses an instance that borrows from the template string.
+    ///
+    /// # Parameters
+    ///
+    /// - s: The source string to parse. Borrowed fields point into this string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::Error` in the same cases as [`ParseTemplate::from_str`].
+    fn from_str(s: &'a str) -> Result<Self, Self::Error>;
+}
+
+/// Errors of [`ParseTemplate::from_reader`], which reads the input before parsing it.
+///
+/// # Fields
+/// - Io: Reading the input failed, or the input isn't valid UTF-8.
+/// - Template: The input was read but failed to parse with the error of the template.
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError<E> {
+    /// Reading the input failed, including input that isn't valid UTF-8.
+    #[error("Failed to read the template input: {0}")]
+    Io(#[from] std::io::Error),
+    /// The template rejected the input that was read.
+    #[error("{0}")]
+    Template(E),
+}
+
+/// Passes the text written through `std::fmt::Write` on to an I/O sink for `render_write`,
+/// keeping the I/O error that `std::fmt::Error` can't carry.
+struct IoAdapter<'a, W: std::io::Write + ?Sized> {
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+impl<W: std::io::Write + ?Sized> std::fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            std::fmt::Error
+        })
+    }
+}
+
+/// Converts the outcome of rendering through an [`IoAdapter`] into the result of `render_write`.
+fn adapter_result(result: std::fmt::Result, error: Option<std::io::Error>) -> std::io::Result<()> {
+    match (result, error) {
+        (Ok(()), _) => Ok(()),
+        (Err(_), Some(error)) => Err(error),
+        (Err(_), None) => Err(std::io::Error::other(
+            "a Display implementation returned an error",
+        )),
+    }
 }
 
 /// Errors produced by templatia operations.
@@ -536,11 +901,17 @@ where
 /// # Fields
 /// - InconsistentValues: The same placeholder appears multiple times with conflicting values.
 /// - ParseToType: A captured value cannot be parsed into the target field type.
+/// - ZeroValue: A captured value for a `NonZero*` field type is zero.
+/// - Include: A section included with `{>field}` failed to parse with the field's own template.
+/// - Validation: A parsed value was rejected by the field's `#[templatia(validate = "...")]` function.
+/// - OutOfRange: A parsed value was outside the bounds of the field's `min`, `max`, `min_len`, or `max_len` attribute,
+///   or of the range written in its placeholder.
+/// - InvalidValue: The parsed value was rejected by the type's `#[templatia(validate = "...")]` function.
 /// - UnexpectedInput: The remaining input does not match the next expected literal from the template.
 /// - Parse: Other parser failures aggregated into a single message string.
 ///
 /// # Notes
-/// - These errors are produced at runtime when parsing strings with `Template::from_str`.
+/// - These errors are produced at runtime when parsing strings with `ParseTemplate::from_str`.
 /// - With the `derive` feature, the procedural macro maps internal parser errors to these variants.
 ///
 #[derive(Debug, thiserror::Error)]
@@ -573,10 +944,63 @@ pub enum TemplateError {
         value: String,
         type_name: String,
     },
+    /// A value for a `NonZero*` placeholder was zero.
+    ///
+    /// # Parameters
+    /// - placeholder: The placeholder name.
+    /// - value: The raw text captured from the input.
+    /// - type_name: The destination type name.
+    #[error(
+        "Cannot parse the placeholder '{placeholder}' with value '{value}' to type '{type_name}', the value must be non-zero"
+    )]
+    ZeroValue {
+        placeholder: String,
+        value: String,
+        type_name: String,
+    },
+    /// A section included with `{>field}` failed to parse with the included type's template.
+    ///
+    /// # Parameters
+    /// - placeholder: The included field name.
+    /// - message: The error message reported by the included type.
+    #[error("Cannot parse the included template '{placeholder}': {message}")]
+    Include {
+        placeholder: String,
+        message: String,
+    },
+    /// A parsed value was rejected by the validation function of the field.
+    ///
+    /// # Parameters
+    /// - placeholder: The placeholder name of the field.
+    /// - message: The error message returned by the validation function.
+    #[error("Validation failed for the placeholder '{placeholder}': {message}")]
+    Validation {
+        placeholder: String,
+        message: String,
+    },
+    /// A parsed value was outside the bounds of the field's `min`, `max`, `min_len`, or `max_len` attribute.
+    ///
+    /// # Parameters
+    /// - placeholder: The placeholder name of the field.
+    /// - value: The rejected value.
+    /// - constraint: The violated bound (e.g., "must be at most 65535").
+    #[error("Value '{value}' for the placeholder '{placeholder}' is out of range: {constraint}")]
+    OutOfRange {
+        placeholder: String,
+        value: String,
+        constraint: String,
+    },
+    /// The parsed value was rejected by the validation function of the type.
+    ///
+    /// # Parameters
+    /// - message: The error message returned by the validation function.
+    #[error("Validation failed: {message}")]
+    InvalidValue { message: String },
     /// The next expected literal segment from the template was not found in the input.
     ///
     /// # Parameters
-    /// - expected_next_literal: The literal text that should have appeared next.
+    /// - expected_next_literal: The literal text that should have appeared next, or an empty string
+    ///   when the input should have ended.
     /// - remaining_text: The remaining input that failed to match the expected literal.
     #[error("Template defines '{expected_next_literal}' but not found it in '{remaining_text}'")]
     UnexpectedInput {
@@ -591,5 +1015,435 @@ pub enum TemplateError {
 #[cfg(feature = "derive")]
 #[doc(hidden)]
 pub mod __private {
+    #[cfg(feature = "arrayvec")]
+    pub use arrayvec;
     pub use chumsky;
+    #[cfg(feature = "indexmap")]
+    pub use indexmap;
+    #[cfg(feature = "smallvec")]
+    pub use smallvec;
+
+    /// Cuts a value rendered into a `#[templatia(width = N)]` placeholder to its width.
+    ///
+    /// The parser reads exactly `width` characters, so a longer value would shift the fields after
+    /// it and parse back as different values without an error.
+    ///
+    /// # Parameters
+    /// - value: The rendered value, already padded.
+    /// - width: The width of the placeholder in characters.
+    ///
+    /// # Returns
+    /// The value, cut after its first `width` characters.
+    ///
+    /// # Panics
+    /// In debug builds, when the value is longer than the width.
+    pub fn fit_width(value: String, width: usize) -> String {
+        let Some((end, _)) = value.char_indices().nth(width) else {
+            return value;
+        };
+        if cfg!(debug_assertions) {
+            panic!(
+                "the rendered value {:?} is longer than its width of {} characters",
+                value, width
+            );
+        }
+        value[..end].to_string()
+    }
+
+    /// Renders and parses date and time values with a format string of `chrono` or a format
+    /// description of `time`, for fields with `#[templatia(datetime_format = "...")]`.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub trait DateTimeFormat: Sized {
+        /// The error of a value that doesn't parse with the format.
+        type Error;
+
+        /// Renders the value with the format. A format that can't render the value falls back to `Display`.
+        fn format_datetime(&self, format: &str) -> String;
+
+        /// Parses a value written in the format.
+        ///
+        /// # Errors
+        /// - Self::Error: The value doesn't match the format, or the format itself is invalid.
+        fn parse_datetime(value: &str, format: &str) -> Result<Self, Self::Error>;
+    }
+
+    /// Writes the formatted value, falling back to `Display` instead of panicking on an invalid format.
+    #[cfg(feature = "chrono")]
+    fn write_datetime<'a, V, I>(value: &V, formatted: chrono::format::DelayedFormat<I>) -> String
+    where
+        V: core::fmt::Display,
+        I: Iterator<Item = chrono::format::Item<'a>> + Clone,
+    {
+        use core::fmt::Write;
+        let mut rendered = String::new();
+        match write!(rendered, "{}", formatted) {
+            Ok(()) => rendered,
+            Err(_) => value.to_string(),
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    macro_rules! naive_datetime_format {
+        ($($ty:ty),*) => {$(
+            impl DateTimeFormat for $ty {
+                type Error = chrono::ParseError;
+
+                fn format_datetime(&self, format: &str) -> String {
+                    write_datetime(self, self.format(format))
+                }
+
+                fn parse_datetime(value: &str, format: &str) -> Result<Self, Self::Error> {
+                    <$ty>::parse_from_str(value, format)
+                }
+            }
+        )*};
+    }
+
+    #[cfg(feature = "chrono")]
+    naive_datetime_format!(chrono::NaiveDate, chrono::NaiveTime, chrono::NaiveDateTime);
+
+    #[cfg(feature = "chrono")]
+    impl DateTimeFormat for chrono::DateTime<chrono::FixedOffset> {
+        type Error = chrono::ParseError;
+
+        fn format_datetime(&self, format: &str) -> String {
+            write_datetime(self, self.format(format))
+        }
+
+        fn parse_datetime(value: &str, format: &str) -> Result<Self, Self::Error> {
+            chrono::DateTime::parse_from_str(value, format)
+        }
+    }
+
+    /// A format without an offset reads the value as UTC.
+    #[cfg(feature = "chrono")]
+    impl DateTimeFormat for chrono::DateTime<chrono::Utc> {
+        type Error = chrono::ParseError;
+
+        fn format_datetime(&self, format: &str) -> String {
+            write_datetime(self, self.format(format))
+        }
+
+        fn parse_datetime(value: &str, format: &str) -> Result<Self, Self::Error> {
+            chrono::DateTime::parse_from_str(value, format)
+                .map(|datetime| datetime.with_timezone(&chrono::Utc))
+                .or_else(|e| {
+                    chrono::NaiveDateTime::parse_from_str(value, format)
+                        .map(|datetime| datetime.and_utc())
+                        .map_err(|_| e)
+                })
+        }
+    }
+
+    #[cfg(feature = "time")]
+    macro_rules! time_datetime_format {
+        ($($ty:ty),*) => {$(
+            impl DateTimeFormat for $ty {
+                type Error = time::Error;
+
+                fn format_datetime(&self, format: &str) -> String {
+                    time::format_description::parse(format)
+                        .ok()
+                        .and_then(|items| self.format(&items).ok())
+                        .unwrap_or_else(|| self.to_string())
+                }
+
+                fn parse_datetime(value: &str, format: &str) -> Result<Self, Self::Error> {
+                    let items = time::format_description::parse(format)?;
+                    Ok(<$ty>::parse(value, &items)?)
+                }
+            }
+        )*};
+    }
+
+    #[cfg(feature = "time")]
+    time_datetime_format!(
+        time::Date,
+        time::Time,
+        time::PrimitiveDateTime,
+        time::OffsetDateTime
+    );
+
+    /// Units of `#[templatia(bytesize)]` values with their sizes in bytes, the largest first.
+    const BYTE_UNITS: [(&str, u64); 12] = [
+        ("EiB", 1 << 60),
+        ("PiB", 1 << 50),
+        ("TiB", 1 << 40),
+        ("GiB", 1 << 30),
+        ("MiB", 1 << 20),
+        ("KiB", 1 << 10),
+        ("EB", 1_000_000_000_000_000_000),
+        ("PB", 1_000_000_000_000_000),
+        ("TB", 1_000_000_000_000),
+        ("GB", 1_000_000_000),
+        ("MB", 1_000_000),
+        ("KB", 1_000),
+    ];
+
+    /// Renders a number of bytes with the largest unit dividing it exactly, preferring the binary
+    /// units (e.g., `512KiB` for 524288 and `2MB` for 2000000), or in bytes like `100B` otherwise.
+    pub fn render_bytesize<T: core::borrow::Borrow<u64>>(bytes: T) -> String {
+        let bytes = *bytes.borrow();
+        BYTE_UNITS
+            .iter()
+            .find(|(_, size)| bytes != 0 && bytes % size == 0)
+            .map(|(unit, size)| format!("{}{}", bytes / size, unit))
+            .unwrap_or_else(|| format!("{}B", bytes))
+    }
+
+    /// Parses a byte size like `512KiB`, `2 MB`, `1.5GiB`, or `100` into a number of bytes.
+    /// The units are case-insensitive, and `KB` is 1000 bytes while `KiB` is 1024 bytes.
+    /// Returns `None` when the text isn't a byte size, isn't a whole number of bytes, or overflows `u64`.
+    pub fn parse_bytesize(value: &str) -> Option<u64> {
+        let digits = value
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(digits);
+        let unit = unit.trim_start();
+        let size = match unit {
+            "" => 1,
+            _ if unit.eq_ignore_ascii_case("b") => 1,
+            _ => BYTE_UNITS
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+                .map(|(_, size)| *size)?,
+        };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return None;
+        }
+        let whole = match whole {
+            "" => 0,
+            _ => whole.parse::<u128>().ok()?,
+        };
+        let mut bytes = whole.checked_mul(u128::from(size))?;
+        if !fraction.is_empty() {
+            let scale = 10u128.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+            let scaled = fraction
+                .parse::<u128>()
+                .ok()?
+                .checked_mul(u128::from(size))?;
+            if scaled % scale != 0 {
+                return None;
+            }
+            bytes += scaled / scale;
+        }
+        u64::try_from(bytes).ok()
+    }
+
+    /// Escapes the escape character and every occurrence of the literals in a rendered value.
+    pub fn escape(value: &str, escape: char, literals: &[&str]) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(c) = rest.chars().next() {
+            if c == escape {
+                escaped.push(escape);
+                escaped.push(escape);
+                rest = &rest[c.len_utf8()..];
+            } else if let Some(literal) = literals
+                .iter()
+                .find(|literal| !literal.is_empty() && rest.starts_with(**literal))
+            {
+                escaped.push(escape);
+                escaped.push_str(literal);
+                rest = &rest[literal.len()..];
+            } else {
+                escaped.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        escaped
+    }
+
+    /// Parses a field of a repeated block element, whose type is inferred from the struct expression.
+    ///
+    /// # Errors
+    /// - The name of the type without its module path when the value doesn't parse.
+    pub fn parse_inferred<T: core::str::FromStr>(value: &str) -> Result<T, &'static str> {
+        value.parse().map_err(|_| {
+            let type_name = core::any::type_name::<T>();
+            let base = type_name.split('<').next().unwrap_or(type_name);
+            base.rsplit("::").next().unwrap_or(base)
+        })
+    }
+
+    /// Wraps a rendered value in double quotes, escaping quotes and backslashes with a backslash.
+    pub fn quote(value: &str) -> String {
+        let mut quoted = String::with_capacity(value.len() + 2);
+        quoted.push('"');
+        for c in value.chars() {
+            if c == '"' || c == '\\' {
+                quoted.push('\\');
+            }
+            quoted.push(c);
+        }
+        quoted.push('"');
+        quoted
+    }
+
+    /// Removes the surrounding double quotes and the backslash escapes from a captured quoted token.
+    pub fn unquote(value: &str) -> String {
+        let inner = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        unescape(inner, '\\')
+    }
+
+    /// Removes the `0x`, `0o`, or `0b` prefix matching the radix from a captured integer, keeping its sign.
+    pub fn strip_radix_prefix(value: &str, radix: u32) -> String {
+        let (sign, digits) = match value.strip_prefix(['-', '+']) {
+            Some(digits) => (&value[..1], digits),
+            None => ("", value),
+        };
+        let prefixes: &[&str] = match radix {
+            2 => &["0b", "0B"],
+            8 => &["0o", "0O"],
+            16 => &["0x", "0X"],
+            _ => &[],
+        };
+        let digits = prefixes
+            .iter()
+            .find_map(|prefix| digits.strip_prefix(prefix))
+            .unwrap_or(digits);
+        format!("{}{}", sign, digits)
+    }
+
+    /// Removes the escape characters from a captured value, keeping the characters they escape.
+    pub fn unescape(value: &str, escape: char) -> String {
+        let mut unescaped = String::with_capacity(value.len());
+        let mut chars = value.chars();
+        while let Some(c) = chars.next() {
+            if c == escape {
+                if let Some(escaped) = chars.next() {
+                    unescaped.push(escaped);
+                }
+            } else {
+                unescaped.push(c);
+            }
+        }
+        unescaped
+    }
+
+    /// Splits a captured collection on the separators that aren't escaped, keeping the escape
+    /// characters in the elements so that each one is unescaped when it is parsed.
+    pub fn split_escaped<'a>(value: &'a str, separator: &str, escape: char) -> Vec<&'a str> {
+        let mut elements = Vec::new();
+        let mut start = 0;
+        let mut rest = value;
+        while let Some(c) = rest.chars().next() {
+            let offset = value.len() - rest.len();
+            if c == escape {
+                let mut chars = rest.chars();
+                chars.next();
+                chars.next();
+                rest = chars.as_str();
+            } else if !separator.is_empty() && rest.starts_with(separator) {
+                elements.push(&value[start..offset]);
+                start = offset + separator.len();
+                rest = &rest[separator.len()..];
+            } else {
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        elements.push(&value[start..]);
+        elements
+    }
+
+    /// Splits a captured map entry at its first key-value delimiter that isn't escaped, keeping the
+    /// escape characters in the key and the value so that they are unescaped when they are parsed.
+    pub fn split_once_escaped<'a>(
+        value: &'a str,
+        separator: &str,
+        escape: char,
+    ) -> Option<(&'a str, &'a str)> {
+        let key = split_escaped(value, separator, escape).into_iter().next()?;
+        (key.len() < value.len()).then(|| (key, &value[key.len() + separator.len()..]))
+    }
+
+    /// Percent-encodes every byte of a rendered value except the unreserved characters of RFC 3986.
+    pub fn url_encode(value: &str) -> String {
+        let mut encoded = String::with_capacity(value.len());
+        for byte in value.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+        encoded
+    }
+
+    /// Percent-encodes the ASCII characters of `separator` in a rendered URL element, so that the
+    /// element can't be split by them. A `%` starting a sequence that [`percent_decode_separator`]
+    /// would decode is encoded as well, so any URL decodes back to itself.
+    pub fn percent_encode_separator(value: &str, separator: &str) -> String {
+        let mut encoded = String::with_capacity(value.len());
+        for (i, c) in value.char_indices() {
+            let ambiguous = c == '%' && decoded_separator_byte(&value[i..], separator).is_some();
+            if (c.is_ascii() && separator.contains(c)) || ambiguous {
+                encoded.push_str(&format!("%{:02X}", c as u8));
+            } else {
+                encoded.push(c);
+            }
+        }
+        encoded
+    }
+
+    /// Decodes the sequences [`percent_encode_separator`] encodes in a captured URL element, keeping
+    /// any other percent-encoding as is.
+    pub fn percent_decode_separator(value: &str, separator: &str) -> String {
+        let mut decoded = String::with_capacity(value.len());
+        let mut rest = value;
+        while let Some(c) = rest.chars().next() {
+            match decoded_separator_byte(rest, separator) {
+                Some(byte) => {
+                    decoded.push(byte as char);
+                    rest = &rest[3..];
+                }
+                None => {
+                    decoded.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        decoded
+    }
+
+    /// Returns the byte of the percent-encoded sequence at the start of `value` when it's `%` or an
+    /// ASCII character of `separator`.
+    fn decoded_separator_byte(value: &str, separator: &str) -> Option<u8> {
+        let hex = value
+            .strip_prefix('%')?
+            .get(..2)
+            .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))?;
+        let byte = u8::from_str_radix(hex, 16).ok()?;
+        (byte == b'%' || (byte.is_ascii() && separator.contains(byte as char))).then_some(byte)
+    }
+
+    /// Decodes the percent-encoded bytes of a captured value. Sequences that aren't valid
+    /// percent-encoding are kept as is, and invalid UTF-8 is replaced with U+FFFD.
+    pub fn url_decode(value: &str) -> String {
+        let bytes = value.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                .and_then(|hex| core::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            match (bytes[i], hex) {
+                (b'%', Some(byte)) => {
+                    decoded.push(byte);
+                    i += 3;
+                }
+                (byte, _) => {
+                    decoded.push(byte);
+                    i += 1;
+                }
+            }
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
 }
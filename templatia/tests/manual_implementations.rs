@@ -1,4 +1,5 @@
-use templatia::{Template, TemplateError};
+use templatia::TemplateError;
+use templatia::prelude::*;
 // Tests follow AGENTS.md policy. They express intended behavior from docs.
 
 /// Tests for manual Template implementations as shown in documentation
@@ -8,12 +9,14 @@ mod manual_implementation_tests {
     /// Point tuple struct manual implementation from docs
     struct Point(i32, i32);
 
-    impl Template for Point {
-        type Error = TemplateError;
-
+    impl RenderTemplate for Point {
         fn render_string(&self) -> String {
             format!("({}, {})", self.0, self.1)
         }
+    }
+
+    impl ParseTemplate for Point {
+        type Error = TemplateError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             if !s.starts_with('(') || !s.ends_with(')') {
@@ -80,12 +83,14 @@ mod manual_implementation_tests {
         port: u16,
     }
 
-    impl Template for ServerConfig {
-        type Error = TemplateError;
-
+    impl RenderTemplate for ServerConfig {
         fn render_string(&self) -> String {
             format!("server={},port={}", self.name, self.port)
         }
+    }
+
+    impl ParseTemplate for ServerConfig {
+        type Error = TemplateError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             let parts: Vec<&str> = s.split(',').collect();
@@ -151,16 +156,22 @@ mod manual_implementation_tests {
         value: T,
     }
 
-    impl<T> Template for KeyValue<T>
+    impl<T> RenderTemplate for KeyValue<T>
     where
         T: std::fmt::Display + std::str::FromStr + Clone,
         T::Err: std::fmt::Display,
     {
-        type Error = TemplateError;
-
         fn render_string(&self) -> String {
             format!("{}={}", self.key, self.value)
         }
+    }
+
+    impl<T> ParseTemplate for KeyValue<T>
+    where
+        T: std::fmt::Display + std::str::FromStr + Clone,
+        T::Err: std::fmt::Display,
+    {
+        type Error = TemplateError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             let parts: Vec<&str> = s.splitn(2, '=').collect();
@@ -246,12 +257,14 @@ mod manual_implementation_tests {
         value: String,
     }
 
-    impl Template for CustomConfig {
-        type Error = CustomError;
-
+    impl RenderTemplate for CustomConfig {
         fn render_string(&self) -> String {
             format!("custom:{}", self.value)
         }
+    }
+
+    impl ParseTemplate for CustomConfig {
+        type Error = CustomError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             if let Some(value) = s.strip_prefix("custom:") {
@@ -290,12 +303,14 @@ mod manual_implementation_edge_cases {
     /// Unit struct implementation
     struct Unit;
 
-    impl Template for Unit {
-        type Error = TemplateError;
-
+    impl RenderTemplate for Unit {
         fn render_string(&self) -> String {
             "unit".to_string()
         }
+    }
+
+    impl ParseTemplate for Unit {
+        type Error = TemplateError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             if s == "unit" {
@@ -323,12 +338,14 @@ mod manual_implementation_edge_cases {
         _phantom: std::marker::PhantomData<()>,
     }
 
-    impl Template for Empty {
-        type Error = TemplateError;
-
+    impl RenderTemplate for Empty {
         fn render_string(&self) -> String {
             "".to_string()
         }
+    }
+
+    impl ParseTemplate for Empty {
+        type Error = TemplateError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             if s.is_empty() {
@@ -361,12 +378,14 @@ mod manual_implementation_edge_cases {
         inner: Vec<String>,
     }
 
-    impl Template for ComplexNested {
-        type Error = TemplateError;
-
+    impl RenderTemplate for ComplexNested {
         fn render_string(&self) -> String {
             format!("{}:[{}]", self.outer, self.inner.join(","))
         }
+    }
+
+    impl ParseTemplate for ComplexNested {
+        type Error = TemplateError;
 
         fn from_str(s: &str) -> Result<Self, Self::Error> {
             if let Some((outer_part, inner_part)) = s.split_once(':') {
@@ -431,3 +450,34 @@ mod manual_implementation_edge_cases {
         assert!(matches!(result, Err(TemplateError::Parse(_))));
     }
 }
+
+/// Tests for the provided `render_to` of manual implementations
+mod default_render_to_tests {
+    use super::*;
+
+    struct Flag(bool);
+
+    impl RenderTemplate for Flag {
+        fn render_string(&self) -> String {
+            format!("flag={}", self.0)
+        }
+    }
+
+    impl ParseTemplate for Flag {
+        type Error = TemplateError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Error> {
+            s.strip_prefix("flag=")
+                .and_then(|value| value.parse().ok())
+                .map(Flag)
+                .ok_or_else(|| TemplateError::Parse("Expected flag=<bool>".to_string()))
+        }
+    }
+
+    #[test]
+    fn render_to_writes_render_string_by_default() {
+        let mut buffer = String::from("> ");
+        Flag(true).render_to(&mut buffer).unwrap();
+        assert_eq!(buffer, "> flag=true");
+    }
+}